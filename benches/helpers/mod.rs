@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use ark_ff::FftField;
+use rand::{CryptoRng, RngCore};
+
+use pcs::common::polynomial::Polynomial;
+
+/// log2 of the degrees benchmarked for the commitment schemes, 2^8..2^18
+pub const LOG_DEGREES: [usize; 6] = [8, 10, 12, 14, 16, 18];
+
+/// Polynomials f_i vanishing at random points omega_i, with z(X) = Prod{i} (X - omega_i) and
+/// z_i(X) = z(X) / (X - omega_i), so that Sum{i} rho_i * f_i * z_i is divisible by z as `ipa::commit::batch_commit`
+/// expects. Returns (f_i, z, z_i)
+#[allow(clippy::type_complexity)]
+pub fn batched_polynomials<F: FftField>(
+    n_polys: usize,
+    degree: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> (Vec<Polynomial<F>>, Polynomial<F>, Vec<Polynomial<F>>) {
+    let omegas: Vec<F> = (0..n_polys).map(|_| F::rand(rng)).collect();
+    let polys = omegas
+        .iter()
+        .map(|omega| {
+            Polynomial::from_random_coefficients_with_rng(degree - 1, rng)
+                * Polynomial::monomial_from_coefficient(*omega)
+        })
+        .collect();
+    let z_poly = Polynomial::from_monomial_coefficients(omegas.clone());
+    let zi_polys = omegas
+        .iter()
+        .map(|omega| {
+            Polynomial::div_polynomials(
+                z_poly.clone(),
+                &Polynomial::monomial_from_coefficient(*omega),
+            )
+            .unwrap()
+            .0
+        })
+        .collect();
+    (polys, z_poly, zi_polys)
+}
@@ -0,0 +1,129 @@
+use ark_bn254::{Fr, G1Projective};
+use ark_ff::Zero;
+use ark_std::UniformRand;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, SeedableRng};
+
+use pcs::common::polynomial::Polynomial;
+use pcs::ipa::{
+    commit::{batch_commit_with_rng, commit},
+    open::{batch_evaluation_proof_with_rng, evaluation_proof_with_rng},
+    setup::GlobalIpaParams,
+    verify::{batch_verify, verify},
+};
+
+mod helpers;
+use helpers::{batched_polynomials, LOG_DEGREES};
+
+const N_BATCH_POLYS: usize = 8;
+
+fn bench_ipa(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let mut group = c.benchmark_group("ipa_setup");
+    group.sample_size(10);
+    for log_degree in LOG_DEGREES {
+        let degree = (1 << log_degree) - 1;
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &degree, |b, degree| {
+            b.iter(|| GlobalIpaParams::<G1Projective>::new(*degree))
+        });
+    }
+    group.finish();
+
+    for log_degree in LOG_DEGREES {
+        let degree = (1 << log_degree) - 1;
+        let global_params = GlobalIpaParams::<G1Projective>::new_with_rng(degree, &mut rng);
+        let poly = Polynomial::<Fr>::from_random_coefficients_with_rng(degree, &mut rng);
+        let point_x = Fr::rand(&mut rng);
+        let (polys, z_poly, zi_polys) = batched_polynomials::<Fr>(N_BATCH_POLYS, degree, &mut rng);
+
+        let commitment = commit(&global_params, &poly).unwrap();
+        let (a_0, _, l_r_group, f_x, u_values, u_group) =
+            evaluation_proof_with_rng(&global_params, &poly, &point_x, &mut rng).unwrap();
+        let commitments: Vec<G1Projective> = polys
+            .iter()
+            .map(|p| commit(&global_params, p).unwrap())
+            .collect();
+        let (commitment_q, q_poly, rho) =
+            batch_commit_with_rng(&global_params, &polys, &z_poly, &zi_polys, &mut rng).unwrap();
+        let (batch_a_0, _, batch_l_r_group, batch_u_values, batch_u_group) =
+            batch_evaluation_proof_with_rng(
+                &global_params,
+                &polys,
+                &q_poly,
+                &z_poly,
+                &zi_polys,
+                &rho,
+                &point_x,
+                &mut rng,
+            )
+            .unwrap();
+
+        let mut group = c.benchmark_group(format!("ipa_{}", degree));
+        group.sample_size(10);
+        group.bench_function("commit", |b| {
+            b.iter(|| commit(&global_params, &poly).unwrap())
+        });
+        group.bench_function("open", |b| {
+            b.iter(|| evaluation_proof_with_rng(&global_params, &poly, &point_x, &mut rng).unwrap())
+        });
+        group.bench_function("batch_commit", |b| {
+            b.iter(|| {
+                batch_commit_with_rng(&global_params, &polys, &z_poly, &zi_polys, &mut rng).unwrap()
+            })
+        });
+        group.bench_function("batch_open", |b| {
+            b.iter(|| {
+                batch_evaluation_proof_with_rng(
+                    &global_params,
+                    &polys,
+                    &q_poly,
+                    &z_poly,
+                    &zi_polys,
+                    &rho,
+                    &point_x,
+                    &mut rng,
+                )
+                .unwrap()
+            })
+        });
+        group.bench_function("verify", |b| {
+            b.iter(|| {
+                verify(
+                    &global_params,
+                    &commitment,
+                    &f_x,
+                    &point_x,
+                    &l_r_group,
+                    &a_0,
+                    &None,
+                    &u_values,
+                    &u_group,
+                )
+            })
+        });
+        group.bench_function("batch_verify", |b| {
+            b.iter(|| {
+                batch_verify(
+                    &global_params,
+                    &commitments,
+                    &commitment_q,
+                    &z_poly,
+                    &zi_polys,
+                    &point_x,
+                    &Fr::zero(),
+                    &batch_l_r_group,
+                    &batch_a_0,
+                    &None,
+                    &batch_u_values,
+                    &rho,
+                    &batch_u_group,
+                )
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_ipa);
+criterion_main!(benches);
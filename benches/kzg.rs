@@ -0,0 +1,75 @@
+use ark_bn254::{Bn254, Fr};
+use ark_std::UniformRand;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, SeedableRng};
+
+use pcs::common::polynomial::Polynomial;
+use pcs::kzg::{
+    commit::commit,
+    open::{batch_evaluation_proof, evaluation_proof},
+    setup::GlobalKzgParams,
+    verify::{batch_verify, verify},
+};
+
+mod helpers;
+use helpers::LOG_DEGREES;
+
+const N_BATCH_POINTS: usize = 8;
+
+fn bench_kzg(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let mut group = c.benchmark_group("kzg_setup");
+    group.sample_size(10);
+    for log_degree in LOG_DEGREES {
+        let degree = (1 << log_degree) - 1;
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &degree, |b, degree| {
+            b.iter(|| GlobalKzgParams::<Bn254>::new(*degree))
+        });
+    }
+    group.finish();
+
+    for log_degree in LOG_DEGREES {
+        let degree = (1 << log_degree) - 1;
+        let global_params = GlobalKzgParams::<Bn254>::new_with_rng(degree, &mut rng);
+        let poly = Polynomial::<Fr>::from_random_coefficients_with_rng(degree, &mut rng);
+        let point_u = Fr::rand(&mut rng);
+        let points_u: Vec<Fr> = (0..N_BATCH_POINTS).map(|_| Fr::rand(&mut rng)).collect();
+
+        let commitment = commit(&global_params, &poly).unwrap();
+        let (proof, value) = evaluation_proof(&global_params, &poly, &point_u).unwrap();
+        let (batch_proof, r_poly) =
+            batch_evaluation_proof(&global_params, &poly, &points_u).unwrap();
+
+        let mut group = c.benchmark_group(format!("kzg_{}", degree));
+        group.sample_size(10);
+        group.bench_function("commit", |b| {
+            b.iter(|| commit(&global_params, &poly).unwrap())
+        });
+        group.bench_function("open", |b| {
+            b.iter(|| evaluation_proof(&global_params, &poly, &point_u).unwrap())
+        });
+        group.bench_function("batch_open", |b| {
+            b.iter(|| batch_evaluation_proof(&global_params, &poly, &points_u).unwrap())
+        });
+        group.bench_function("verify", |b| {
+            b.iter(|| verify(&global_params, &commitment, &proof, &point_u, &value))
+        });
+        group.bench_function("batch_verify", |b| {
+            b.iter(|| {
+                batch_verify(
+                    &global_params,
+                    &commitment,
+                    &batch_proof,
+                    &points_u,
+                    &r_poly,
+                )
+                .unwrap()
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_kzg);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+//! Prover paths parallelized by the `parallel` feature. Compare
+//! `cargo bench --bench parallel` with `cargo bench --bench parallel --features parallel`
+use ark_bn254::{Bn254, Fr, G1Projective};
+use ark_std::UniformRand;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::thread_rng;
+
+use pcs::common::polynomial::Polynomial;
+use pcs::ipa::{commit::batch_commit, open::evaluation_proof, setup::GlobalIpaParams};
+use pcs::kzg::setup::GlobalKzgParams;
+
+mod helpers;
+use helpers::batched_polynomials;
+
+fn bench_setup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("setup");
+    group.sample_size(10);
+    for log_degree in [10, 12] {
+        let degree = (1 << log_degree) - 1;
+        group.bench_with_input(BenchmarkId::new("kzg", degree), &degree, |b, degree| {
+            b.iter(|| GlobalKzgParams::<Bn254>::new(*degree))
+        });
+        group.bench_with_input(BenchmarkId::new("ipa", degree), &degree, |b, degree| {
+            b.iter(|| GlobalIpaParams::<G1Projective>::new(*degree))
+        });
+    }
+    group.finish();
+}
+
+fn bench_ipa_open(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let mut group = c.benchmark_group("ipa_open");
+    group.sample_size(10);
+    for log_degree in [8, 10, 12] {
+        let degree = (1 << log_degree) - 1;
+        let global_params = GlobalIpaParams::<G1Projective>::new(degree);
+        let polynomial = Polynomial::<Fr>::from_random_coefficients(degree);
+        let x_value = Fr::rand(&mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &degree, |b, _| {
+            b.iter(|| evaluation_proof(&global_params, &polynomial, &x_value).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_ipa_batch_commit(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let mut group = c.benchmark_group("ipa_batch_commit");
+    group.sample_size(10);
+    let degree = 255;
+    for n_polys in [16, 64] {
+        let global_params = GlobalIpaParams::<G1Projective>::new(degree + n_polys);
+        let (polys, z_poly, zi_polys) = batched_polynomials::<Fr>(n_polys, degree, &mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(n_polys), &n_polys, |b, _| {
+            b.iter(|| batch_commit(&global_params, &polys, &z_poly, &zi_polys).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_setup, bench_ipa_open, bench_ipa_batch_commit);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+use ark_bn254::Fr;
+use ark_std::UniformRand;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, SeedableRng};
+
+use pcs::common::polynomial::Polynomial;
+use pcs::ipa::verify::compute_s;
+
+mod helpers;
+use helpers::LOG_DEGREES;
+
+/// Degree of the divisor, of the order of the multi-point opening quotients Prod{i} (X - u_i)
+const DIVISOR_DEGREE: usize = 32;
+
+/// `lagrange_interpolation` multiplies one monomial at a time for every point, so it's benchmarked on smaller sizes
+const LOG_INTERPOLATION_SIZES: [usize; 4] = [4, 6, 8, 10];
+
+fn bench_polynomial(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let mut group = c.benchmark_group("polynomial_mul");
+    group.sample_size(10);
+    for log_degree in LOG_DEGREES {
+        let degree = (1 << log_degree) - 1;
+        let a = Polynomial::<Fr>::from_random_coefficients_with_rng(degree, &mut rng);
+        let b = Polynomial::<Fr>::from_random_coefficients_with_rng(degree, &mut rng);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(degree),
+            &degree,
+            |bencher, _| bencher.iter(|| a.clone() * b.clone()),
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("polynomial_div");
+    group.sample_size(10);
+    for log_degree in LOG_DEGREES {
+        let degree = (1 << log_degree) - 1;
+        let a = Polynomial::<Fr>::from_random_coefficients_with_rng(degree, &mut rng);
+        let b = Polynomial::<Fr>::from_random_coefficients_with_rng(DIVISOR_DEGREE, &mut rng);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(degree),
+            &degree,
+            |bencher, _| bencher.iter(|| Polynomial::div_polynomials(a.clone(), &b).unwrap()),
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("lagrange_interpolation");
+    group.sample_size(10);
+    for log_size in LOG_INTERPOLATION_SIZES {
+        let size = 1 << log_size;
+        let points: Vec<Fr> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+        let evals: Vec<Fr> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |bencher, _| {
+            bencher.iter(|| Polynomial::lagrange_interpolation(&points, &evals))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("compute_s");
+    group.sample_size(10);
+    for log_degree in LOG_DEGREES {
+        let n = 1 << log_degree;
+        let u_values: Vec<Fr> = (0..log_degree).map(|_| Fr::rand(&mut rng)).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |bencher, n| {
+            bencher.iter(|| compute_s(&u_values, *n))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_polynomial);
+criterion_main!(benches);
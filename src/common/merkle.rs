@@ -0,0 +1,444 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use ark_ff::{BigInteger, PrimeField};
+use blake2::Blake2s256;
+use sha2::{Digest as _, Sha256};
+
+use super::poseidon::PoseidonParams;
+
+pub type Digest = [u8; 32];
+
+/// Authentication path of a leaf, the arity - 1 siblings of each node from the leaf up to the root
+pub type MerklePath<D = Digest> = Vec<D>;
+
+/// Siblings needed to authenticate several leaves at once. Nodes shared by several paths, or computable from the
+/// opened leaves, appear only once
+pub type MerkleMultiProof<D = Digest> = Vec<D>;
+
+/// Hash function of a Merkle tree over field element leaves
+pub trait MerkleHasher<F: PrimeField> {
+    type Digest: Copy + Debug + PartialEq;
+
+    fn hash_leaf(&self, leaf: &F) -> Self::Digest;
+    fn hash_children(&self, children: &[Self::Digest]) -> Self::Digest;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Sha256Hasher;
+
+impl<F: PrimeField> MerkleHasher<F> for Sha256Hasher {
+    type Digest = Digest;
+
+    fn hash_leaf(&self, leaf: &F) -> Digest {
+        Sha256::digest(leaf.into_bigint().to_bytes_le()).into()
+    }
+
+    fn hash_children(&self, children: &[Digest]) -> Digest {
+        let mut hasher = Sha256::new();
+        for child in children {
+            hasher.update(child);
+        }
+        hasher.finalize().into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Blake2sHasher;
+
+impl<F: PrimeField> MerkleHasher<F> for Blake2sHasher {
+    type Digest = Digest;
+
+    fn hash_leaf(&self, leaf: &F) -> Digest {
+        Blake2s256::digest(leaf.into_bigint().to_bytes_le()).into()
+    }
+
+    fn hash_children(&self, children: &[Digest]) -> Digest {
+        let mut hasher = Blake2s256::new();
+        for child in children {
+            hasher.update(child);
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// Arithmetic-friendly hasher. Children are compressed as the first element of Poseidon([0, c_1, ..., c_arity]) and
+/// leaves as Poseidon([1, leaf, 0, ..., 0]), the first state element separating both domains
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoseidonHasher<F: PrimeField> {
+    params: PoseidonParams<F>,
+}
+
+impl<F: PrimeField> PoseidonHasher<F> {
+    pub fn new(arity: usize) -> Result<Self, String> {
+        Ok(Self {
+            params: PoseidonParams::new(arity + 1)?,
+        })
+    }
+
+    pub fn arity(&self) -> usize {
+        self.params.width - 1
+    }
+
+    fn compress(&self, tag: F, inputs: &[F]) -> F {
+        let mut state = vec![F::zero(); self.params.width];
+        state[0] = tag;
+        for (s, input) in state[1..].iter_mut().zip(inputs) {
+            *s = *input;
+        }
+        self.params
+            .permute(&mut state)
+            .expect("State has the Poseidon width");
+        state[0]
+    }
+}
+
+impl<F: PrimeField> MerkleHasher<F> for PoseidonHasher<F> {
+    type Digest = F;
+
+    fn hash_leaf(&self, leaf: &F) -> F {
+        self.compress(F::one(), &[*leaf])
+    }
+
+    fn hash_children(&self, children: &[F]) -> F {
+        self.compress(F::zero(), children)
+    }
+}
+
+/// Merkle tree over field element leaves with configurable arity and hash function. Internal nodes are
+/// H(child_1, ..., child_arity) and the number of leaves must be a power of the arity. `MerkleTree::new` builds the
+/// binary SHA-256 tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MerkleTree<F: PrimeField, H: MerkleHasher<F> = Sha256Hasher> {
+    hasher: H,
+    arity: usize,
+    // nodes[0] are the leaf hashes and nodes[depth] = [root]
+    nodes: Vec<Vec<H::Digest>>,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> MerkleTree<F> {
+    pub fn new(leaves: &[F]) -> Result<Self, String> {
+        Self::with_hasher(Sha256Hasher, 2, leaves)
+    }
+
+    pub fn verify(root: &Digest, index: usize, leaf: &F, path: &[Digest]) -> bool {
+        Self::verify_path(&Sha256Hasher, 2, root, index, leaf, path)
+    }
+}
+
+impl<F: PrimeField, H: MerkleHasher<F>> MerkleTree<F, H> {
+    pub fn with_hasher(hasher: H, arity: usize, leaves: &[F]) -> Result<Self, String> {
+        if arity < 2 {
+            return Err("Arity must be at least two".to_string());
+        }
+        if leaves.is_empty() || !is_power_of(leaves.len(), arity) {
+            return Err("Number of leaves must be a power of the arity".to_string());
+        }
+        let mut nodes = vec![leaves
+            .iter()
+            .map(|l| hasher.hash_leaf(l))
+            .collect::<Vec<_>>()];
+        while nodes.last().unwrap().len() > 1 {
+            let level = nodes
+                .last()
+                .unwrap()
+                .chunks(arity)
+                .map(|children| hasher.hash_children(children))
+                .collect();
+            nodes.push(level);
+        }
+        Ok(Self {
+            hasher,
+            arity,
+            nodes,
+            _field: PhantomData,
+        })
+    }
+
+    pub fn root(&self) -> H::Digest {
+        self.nodes.last().unwrap()[0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes[0].is_empty()
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn depth(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    pub fn open(&self, index: usize) -> Result<MerklePath<H::Digest>, String> {
+        if index >= self.len() {
+            return Err("Leaf index out of range".to_string());
+        }
+        let mut path = Vec::with_capacity(self.depth() * (self.arity - 1));
+        let mut index = index;
+        for level in &self.nodes[..self.depth()] {
+            let first = index - index % self.arity;
+            for (sibling, node) in level[first..first + self.arity].iter().enumerate() {
+                if first + sibling != index {
+                    path.push(*node);
+                }
+            }
+            index /= self.arity;
+        }
+        Ok(path)
+    }
+
+    /// Opens several leaves at once. Level by level, the proof contains the siblings of the known nodes that are
+    /// neither known nor already included, in increasing index order
+    pub fn open_batch(&self, indices: &[usize]) -> Result<MerkleMultiProof<H::Digest>, String> {
+        if indices.iter().any(|i| *i >= self.len()) {
+            return Err("Leaf index out of range".to_string());
+        }
+        let mut known: Vec<usize> = indices.to_vec();
+        known.sort_unstable();
+        known.dedup();
+
+        let mut proof = vec![];
+        for level in &self.nodes[..self.depth()] {
+            let mut parents = Vec::with_capacity(known.len());
+            for &index in &known {
+                let parent = index / self.arity;
+                if parents.last() == Some(&parent) {
+                    continue;
+                }
+                parents.push(parent);
+                let first = parent * self.arity;
+                for (sibling, node) in level[first..first + self.arity].iter().enumerate() {
+                    if known.binary_search(&(first + sibling)).is_err() {
+                        proof.push(*node);
+                    }
+                }
+            }
+            known = parents;
+        }
+        Ok(proof)
+    }
+
+    pub fn verify_path(
+        hasher: &H,
+        arity: usize,
+        root: &H::Digest,
+        index: usize,
+        leaf: &F,
+        path: &[H::Digest],
+    ) -> bool {
+        if arity < 2 || path.len() % (arity - 1) != 0 {
+            return false;
+        }
+        let mut node = hasher.hash_leaf(leaf);
+        let mut index = index;
+        let mut children = Vec::with_capacity(arity);
+        for siblings in path.chunks(arity - 1) {
+            let position = index % arity;
+            children.clear();
+            children.extend_from_slice(&siblings[..position]);
+            children.push(node);
+            children.extend_from_slice(&siblings[position..]);
+            node = hasher.hash_children(&children);
+            index /= arity;
+        }
+        index == 0 && &node == root
+    }
+
+    /// Verifies a multi-proof for `leaves[i]` at `indices[i]` in a tree of the given depth
+    pub fn verify_batch(
+        hasher: &H,
+        arity: usize,
+        depth: usize,
+        root: &H::Digest,
+        indices: &[usize],
+        leaves: &[F],
+        proof: &[H::Digest],
+    ) -> bool {
+        if arity < 2 || indices.len() != leaves.len() || indices.is_empty() {
+            return false;
+        }
+        let n_leaves = match arity.checked_pow(depth as u32) {
+            Some(n) => n,
+            None => return false,
+        };
+
+        let mut known = BTreeMap::new();
+        for (index, leaf) in indices.iter().zip(leaves) {
+            if *index >= n_leaves {
+                return false;
+            }
+            let digest = hasher.hash_leaf(leaf);
+            if let Some(previous) = known.insert(*index, digest) {
+                if previous != digest {
+                    return false;
+                }
+            }
+        }
+
+        let mut proof = proof.iter();
+        for _ in 0..depth {
+            let mut parents = BTreeMap::new();
+            for &index in known.keys() {
+                let parent = index / arity;
+                if parents.contains_key(&parent) {
+                    continue;
+                }
+                let mut children = Vec::with_capacity(arity);
+                for sibling in parent * arity..(parent + 1) * arity {
+                    match known.get(&sibling).or_else(|| proof.next()) {
+                        Some(node) => children.push(*node),
+                        None => return false,
+                    }
+                }
+                parents.insert(parent, hasher.hash_children(&children));
+            }
+            known = parents;
+        }
+
+        proof.next().is_none() && known.len() == 1 && known.get(&0) == Some(root)
+    }
+}
+
+/// Hashes a vector of field elements, such as a column of an encoded matrix, into a single field element leaf
+pub fn hash_to_field<F: PrimeField>(values: &[F]) -> F {
+    let mut hasher = Sha256::new();
+    for value in values {
+        hasher.update(value.into_bigint().to_bytes_le());
+    }
+    F::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+fn is_power_of(n: usize, base: usize) -> bool {
+    let mut n = n;
+    while n.is_multiple_of(base) {
+        n /= base;
+    }
+    n == 1
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::{One, UniformRand};
+    use rand::thread_rng;
+
+    use super::*;
+
+    #[test]
+    fn test_merkle_open_verify() {
+        let mut rng = thread_rng();
+        let leaves: Vec<Fr> = (0..16).map(|_| Fr::rand(&mut rng)).collect();
+        let tree = MerkleTree::new(&leaves).unwrap();
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let path = tree.open(i).unwrap();
+            assert!(MerkleTree::verify(&tree.root(), i, leaf, &path));
+            assert!(!MerkleTree::verify(
+                &tree.root(),
+                i,
+                &(*leaf + Fr::one()),
+                &path
+            ));
+        }
+    }
+
+    fn check_tree<H: MerkleHasher<Fr> + Clone>(hasher: H, arity: usize, n_leaves: usize) {
+        let mut rng = thread_rng();
+        let leaves: Vec<Fr> = (0..n_leaves).map(|_| Fr::rand(&mut rng)).collect();
+        let tree = MerkleTree::with_hasher(hasher.clone(), arity, &leaves).unwrap();
+
+        for i in [0, n_leaves / 3, n_leaves - 1] {
+            let path = tree.open(i).unwrap();
+            assert_eq!(path.len(), tree.depth() * (arity - 1));
+            assert!(MerkleTree::verify_path(
+                &hasher,
+                arity,
+                &tree.root(),
+                i,
+                &leaves[i],
+                &path
+            ));
+            assert!(!MerkleTree::verify_path(
+                &hasher,
+                arity,
+                &tree.root(),
+                (i + 1) % n_leaves,
+                &leaves[i],
+                &path
+            ));
+        }
+
+        let indices = vec![1, 2, n_leaves - 1, 2, n_leaves / 2];
+        let opened: Vec<Fr> = indices.iter().map(|i| leaves[*i]).collect();
+        let proof = tree.open_batch(&indices).unwrap();
+        assert!(MerkleTree::verify_batch(
+            &hasher,
+            arity,
+            tree.depth(),
+            &tree.root(),
+            &indices,
+            &opened,
+            &proof
+        ));
+
+        let mut tampered = opened.clone();
+        tampered[2] += Fr::one();
+        assert!(!MerkleTree::verify_batch(
+            &hasher,
+            arity,
+            tree.depth(),
+            &tree.root(),
+            &indices,
+            &tampered,
+            &proof
+        ));
+    }
+
+    #[test]
+    fn test_merkle_sha256_binary() {
+        check_tree(Sha256Hasher, 2, 64);
+    }
+
+    #[test]
+    fn test_merkle_blake2s_quaternary() {
+        check_tree(Blake2sHasher, 4, 64);
+    }
+
+    #[test]
+    fn test_merkle_poseidon_arity_8() {
+        check_tree(PoseidonHasher::<Fr>::new(8).unwrap(), 8, 64);
+    }
+
+    #[test]
+    fn test_merkle_batch_deduplicates_siblings() {
+        let mut rng = thread_rng();
+        let leaves: Vec<Fr> = (0..32).map(|_| Fr::rand(&mut rng)).collect();
+        let tree = MerkleTree::new(&leaves).unwrap();
+
+        // Leaves 4 and 5 are siblings and share every node above them
+        let proof = tree.open_batch(&[4, 5]).unwrap();
+        assert_eq!(proof.len(), tree.depth() - 1);
+
+        let proof = tree.open_batch(&[0, 31]).unwrap();
+        assert!(proof.len() < 2 * tree.depth());
+    }
+
+    #[test]
+    fn test_merkle_invalid_number_of_leaves() {
+        let leaves = vec![Fr::one(); 12];
+        assert!(MerkleTree::new(&leaves).is_err());
+        assert!(MerkleTree::with_hasher(Sha256Hasher, 3, &leaves[..9]).is_ok());
+    }
+}
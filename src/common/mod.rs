@@ -1,2 +1,7 @@
+pub mod merkle;
+pub mod multilinear;
+pub mod pcs;
 pub mod polynomial;
+pub mod poseidon;
+pub mod transcript;
 pub mod utils;
@@ -0,0 +1,276 @@
+use std::ops::{Add, Mul, Sub};
+
+use ark_ff::Field;
+use rand::{thread_rng, CryptoRng, RngCore};
+
+use super::polynomial::Polynomial;
+
+/// Multilinear polynomial in n variables stored as its 2^n evaluations over the boolean hypercube {0,1}^n, with the
+/// first variable as the least significant bit of the index, as in `mlkzg`, `hyrax` and `zeromorph`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultilinearPolynomial<F: Field> {
+    evaluations: Vec<F>,
+    num_vars: usize,
+}
+
+impl<F: Field> MultilinearPolynomial<F> {
+    pub fn from_evaluations(evaluations: Vec<F>) -> Result<Self, String> {
+        if !evaluations.len().is_power_of_two() {
+            return Err("Number of evaluations must be a power of two".to_string());
+        }
+        let num_vars = evaluations.len().trailing_zeros() as usize;
+        Ok(MultilinearPolynomial {
+            evaluations,
+            num_vars,
+        })
+    }
+
+    pub fn from_random_evaluations(num_vars: usize) -> Self {
+        Self::from_random_evaluations_with_rng(num_vars, &mut thread_rng())
+    }
+
+    pub fn from_random_evaluations_with_rng(
+        num_vars: usize,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Self {
+        let evaluations = (0..1 << num_vars).map(|_| F::rand(rng)).collect();
+        MultilinearPolynomial {
+            evaluations,
+            num_vars,
+        }
+    }
+
+    /// eq(x, r) = Prod{i} (x_i * r_i + (1 - x_i) * (1 - r_i)), which is one at x = r over the hypercube and zero
+    /// elsewhere, so f(r) = Sum{b} f(b) * eq(b, r). The table is built in O(2^n) doubling it once per variable
+    pub fn eq(point: &[F]) -> Self {
+        let mut evaluations = Vec::with_capacity(1 << point.len());
+        evaluations.push(F::one());
+        for r_i in point {
+            let high: Vec<F> = evaluations.iter().map(|e| *e * r_i).collect();
+            for (low, high) in evaluations.iter_mut().zip(&high) {
+                *low -= high;
+            }
+            evaluations.extend(high);
+        }
+        MultilinearPolynomial {
+            evaluations,
+            num_vars: point.len(),
+        }
+    }
+
+    /// Multilinear polynomial whose hypercube evaluations are the coefficients of the univariate polynomial, padded
+    /// with zeros to 2^num_vars. This is the isomorphism U_n of `zeromorph`
+    pub fn from_univariate(polynomial: &Polynomial<F>, num_vars: usize) -> Result<Self, String> {
+        let mut evaluations = polynomial.coeffs().to_vec();
+        if evaluations.len() > 1 << num_vars {
+            return Err("Polynomial degree is higher than the number of evaluations".to_string());
+        }
+        evaluations.resize(1 << num_vars, F::zero());
+        Ok(MultilinearPolynomial {
+            evaluations,
+            num_vars,
+        })
+    }
+
+    /// Univariate polynomial with the hypercube evaluations as coefficients
+    pub fn to_univariate(&self) -> Polynomial<F> {
+        Polynomial::from_vector_coefficients(self.evaluations.clone())
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    pub fn len(&self) -> usize {
+        self.evaluations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.evaluations.is_empty()
+    }
+
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Fixes the first variable, f'(x_1, ..., x_{n-1}) = f(r, x_1, ..., x_{n-1}) = f(0, ..) + r * (f(1, ..) - f(0, ..))
+    pub fn fix_variable(&self, r: &F) -> Self {
+        if self.num_vars == 0 {
+            return self.clone();
+        }
+        let evaluations = self
+            .evaluations
+            .chunks(2)
+            .map(|pair| pair[0] + *r * (pair[1] - pair[0]))
+            .collect();
+        MultilinearPolynomial {
+            evaluations,
+            num_vars: self.num_vars - 1,
+        }
+    }
+
+    /// Partial evaluation at the first point.len() variables
+    pub fn fix_variables(&self, point: &[F]) -> Result<Self, String> {
+        if point.len() > self.num_vars {
+            return Err("Point dimension is higher than the number of variables".to_string());
+        }
+        let mut polynomial = self.clone();
+        for r in point {
+            polynomial = polynomial.fix_variable(r);
+        }
+        Ok(polynomial)
+    }
+
+    /// Evaluates f(r) in O(2^n) fixing one variable at a time
+    pub fn evaluate(&self, point: &[F]) -> Result<F, String> {
+        if point.len() != self.num_vars {
+            return Err("Point dimension doesnt match the number of variables".to_string());
+        }
+        Ok(self.fix_variables(point)?.evaluations[0])
+    }
+
+    /// The same polynomial in `num_vars` variables, not depending on the extra ones. With the first variable as the
+    /// least significant bit, its evaluations repeat every 2^n entries
+    pub fn extend(&self, num_vars: usize) -> Self {
+        if num_vars <= self.num_vars {
+            return self.clone();
+        }
+        let evaluations = self
+            .evaluations
+            .iter()
+            .cycle()
+            .take(1 << num_vars)
+            .copied()
+            .collect();
+        MultilinearPolynomial {
+            evaluations,
+            num_vars,
+        }
+    }
+}
+
+/// Polynomials in a different number of variables are added as polynomials in the larger number of variables
+impl<F: Field> Add for MultilinearPolynomial<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let num_vars = self.num_vars.max(rhs.num_vars);
+        let mut result = self.extend(num_vars);
+        for (a, b) in result
+            .evaluations
+            .iter_mut()
+            .zip(rhs.extend(num_vars).evaluations)
+        {
+            *a += b;
+        }
+        result
+    }
+}
+
+impl<F: Field> Sub for MultilinearPolynomial<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs * -F::one()
+    }
+}
+
+impl<F: Field> Mul<F> for MultilinearPolynomial<F> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: F) -> Self::Output {
+        for e in self.evaluations.iter_mut() {
+            *e *= rhs;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::{One, UniformRand, Zero};
+    use rand::thread_rng;
+
+    use super::MultilinearPolynomial;
+    use crate::common::polynomial::Polynomial;
+
+    fn random_point(num_vars: usize) -> Vec<Fr> {
+        let mut rng = thread_rng();
+        (0..num_vars).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_evaluate_matches_eq_sum() {
+        let poly = MultilinearPolynomial::<Fr>::from_random_evaluations(6);
+        let point = random_point(6);
+        let eq = MultilinearPolynomial::eq(&point);
+
+        let expected: Fr = poly
+            .evaluations()
+            .iter()
+            .zip(eq.evaluations())
+            .map(|(f, e)| *f * e)
+            .sum();
+
+        assert_eq!(poly.evaluate(&point).unwrap(), expected);
+        assert_eq!(eq.evaluations().iter().sum::<Fr>(), Fr::one());
+        assert!(poly.evaluate(&point[1..]).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_on_hypercube() {
+        let poly = MultilinearPolynomial::<Fr>::from_random_evaluations(4);
+        for b in 0..16 {
+            let point: Vec<Fr> = (0..4).map(|i| Fr::from(((b >> i) & 1) as u64)).collect();
+            assert_eq!(poly.evaluate(&point).unwrap(), poly.evaluations()[b]);
+            assert_eq!(
+                MultilinearPolynomial::eq(&point).evaluations()[b],
+                Fr::one()
+            );
+        }
+    }
+
+    #[test]
+    fn test_fix_variables() {
+        let poly = MultilinearPolynomial::<Fr>::from_random_evaluations(5);
+        let point = random_point(5);
+
+        let partial = poly.fix_variables(&point[..2]).unwrap();
+
+        assert_eq!(partial.num_vars(), 3);
+        assert_eq!(
+            partial.evaluate(&point[2..]).unwrap(),
+            poly.evaluate(&point).unwrap()
+        );
+        assert!(poly.fix_variables(&random_point(6)).is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = MultilinearPolynomial::<Fr>::from_random_evaluations(4);
+        let b = MultilinearPolynomial::<Fr>::from_random_evaluations(2);
+        let scalar = Fr::from(7_u64);
+        let point = random_point(4);
+
+        let sum = a.clone() + b.clone() * scalar;
+        assert_eq!(
+            sum.evaluate(&point).unwrap(),
+            a.evaluate(&point).unwrap() + scalar * b.evaluate(&point[..2]).unwrap()
+        );
+        assert!((sum - a).evaluate(&point).unwrap() == scalar * b.evaluate(&point[..2]).unwrap());
+        assert!(MultilinearPolynomial::from_evaluations(vec![Fr::zero(); 3]).is_err());
+    }
+
+    #[test]
+    fn test_univariate_conversion() {
+        let poly = Polynomial::<Fr>::from_random_coefficients(12);
+
+        let multilinear = MultilinearPolynomial::from_univariate(&poly, 4).unwrap();
+
+        assert_eq!(multilinear.len(), 16);
+        assert_eq!(&multilinear.evaluations()[..13], poly.coeffs());
+        assert_eq!(multilinear.to_univariate(), poly);
+        assert!(MultilinearPolynomial::from_univariate(&poly, 3).is_err());
+    }
+}
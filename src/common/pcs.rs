@@ -0,0 +1,37 @@
+use std::fmt::Debug;
+
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+
+use super::polynomial::Polynomial;
+
+/// Univariate polynomial commitment scheme with single point openings, implemented by the backends (`kzg::Kzg`,
+/// `ipa::Ipa`) so protocols built on top of commitments, like the `gadgets`, are written once for all of them
+pub trait PolynomialCommitment<F: PrimeField>: Clone + Debug + PartialEq {
+    type Params;
+    type Commitment: Clone + Debug + PartialEq + CanonicalSerialize;
+    type Proof: Clone + Debug + PartialEq;
+
+    /// Maximum degree of a polynomial that can be committed with `params`
+    fn max_degree(params: &Self::Params) -> usize;
+
+    fn commit(
+        params: &Self::Params,
+        polynomial: &Polynomial<F>,
+    ) -> Result<Self::Commitment, String>;
+
+    /// Proves the evaluation of the polynomial at `point`, returning the proof and the evaluation
+    fn open(
+        params: &Self::Params,
+        polynomial: &Polynomial<F>,
+        point: &F,
+    ) -> Result<(Self::Proof, F), String>;
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        proof: &Self::Proof,
+        point: &F,
+        value: &F,
+    ) -> bool;
+}
@@ -1,7 +1,7 @@
 use ark_ff::{FftField, Field};
 use ark_poly::univariate::DenseOrSparsePolynomial;
 use ark_poly::{univariate::DensePolynomial, Polynomial as ArkPolynomial};
-use rand::thread_rng;
+use rand::{thread_rng, CryptoRng, RngCore};
 use std::ops::{Add, Mul, Sub};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -25,16 +25,22 @@ impl<F: Field> Polynomial<F> {
     pub fn monomial_vector_from_coefficients(coeffs: &[F]) -> Vec<Polynomial<F>> {
         let monomials: Vec<Polynomial<_>> = coeffs
             .iter()
-            .map(|r| Polynomial::from_vector_coefficients(vec![F::zero() - F::from(*r), F::one()]))
+            .map(|r| Polynomial::from_vector_coefficients(vec![F::zero() - *r, F::one()]))
             .collect();
         monomials
     }
 
     pub fn from_random_coefficients(degree: usize) -> Self {
-        let mut rng = thread_rng();
+        Self::from_random_coefficients_with_rng(degree, &mut thread_rng())
+    }
+
+    pub fn from_random_coefficients_with_rng(
+        degree: usize,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Self {
         let mut coeffs = Vec::with_capacity(degree + 1);
         for _ in 0..=degree {
-            coeffs.push(F::rand(&mut rng))
+            coeffs.push(F::rand(rng))
         }
         Polynomial::from_vector_coefficients(coeffs)
     }
@@ -47,7 +53,7 @@ impl<F: Field> Polynomial<F> {
     }
 
     pub fn is_zero(&self) -> bool {
-        self.0.coeffs.len() == 0
+        self.0.coeffs.is_empty()
     }
 
     pub fn set_constant_coeff(&mut self, coeff: F) {
@@ -67,7 +73,7 @@ impl<F: Field> Polynomial<F> {
     }
 
     pub fn evaluate(&self, point: &F) -> F {
-        self.0.evaluate(&point)
+        self.0.evaluate(point)
     }
 
     pub fn sub_polynomials(a: &Self, b: &Self) -> Self {
@@ -88,6 +94,51 @@ impl<F: Field> Polynomial<F> {
         }
     }
 
+    /// Multiplies by a sparse polynomial in O(deg(a) * t) for t terms, adding the shifted copies c_k * X^k * a(X)
+    pub fn mul_sparse(a: &Self, b: &SparsePolynomial<F>) -> Self {
+        if a.is_zero() || b.is_zero() {
+            return Self::from_vector_coefficients(vec![]);
+        }
+        let mut coeffs = vec![F::zero(); a.degree() + b.degree() + 1];
+        for (k, c_k) in b.terms() {
+            for (i, a_i) in a.coeffs().iter().enumerate() {
+                coeffs[i + k] += *a_i * c_k;
+            }
+        }
+        Self::from_vector_coefficients(coeffs)
+    }
+
+    /// Long division by a sparse divisor in O(deg(a) * t) for t terms, e.g. by Z_H(X) = X^n - 1 or X - u. Returns
+    /// None if the divisor is zero
+    pub fn div_sparse(a: Self, b: &SparsePolynomial<F>) -> Option<(Self, Self)> {
+        let (degree, leading) = *b.terms().last()?;
+        let leading_inverse = leading.inverse()?;
+        let mut remainder = a.0.coeffs;
+        if remainder.len() <= degree {
+            return Some((
+                Self::from_vector_coefficients(vec![]),
+                Self::from_vector_coefficients(remainder),
+            ));
+        }
+
+        let mut quotient = vec![F::zero(); remainder.len() - degree];
+        for i in (degree..remainder.len()).rev() {
+            let coeff = remainder[i] * leading_inverse;
+            if coeff.is_zero() {
+                continue;
+            }
+            quotient[i - degree] = coeff;
+            for (k, c_k) in b.terms() {
+                remainder[i - degree + k] -= coeff * c_k;
+            }
+        }
+        remainder.truncate(degree);
+        Some((
+            Self::from_vector_coefficients(quotient),
+            Self::from_vector_coefficients(remainder),
+        ))
+    }
+
     pub fn inner_product(&self, other: &Polynomial<F>) -> Result<F, String> {
         if self.degree() != other.degree() {
             return Err("Polynomials don't have same degree".to_string());
@@ -116,7 +167,7 @@ impl<F: FftField> Polynomial<F> {
     }
 
     pub fn from_polys(polys: Vec<Polynomial<F>>) -> Self {
-        if polys.len() == 0 {
+        if polys.is_empty() {
             return Polynomial::from_vector_coefficients(vec![]);
         }
         let mut polys = polys.clone();
@@ -155,6 +206,60 @@ impl<F: FftField> Polynomial<F> {
     }
 }
 
+/// Polynomial stored as its nonzero terms (i, c_i) in increasing order of i, for polynomials with few terms and a
+/// large degree like X^n - 1, which take O(t) memory and O(t * log(n)) to evaluate
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparsePolynomial<F: Field>(Vec<(usize, F)>);
+
+impl<F: Field> SparsePolynomial<F> {
+    /// Terms can be in any order, terms of the same degree are added
+    pub fn from_terms(mut terms: Vec<(usize, F)>) -> Self {
+        terms.sort_by_key(|(i, _)| *i);
+        let mut merged: Vec<(usize, F)> = Vec::with_capacity(terms.len());
+        for (i, c) in terms {
+            match merged.last_mut() {
+                Some((j, d)) if *j == i => *d += c,
+                _ => merged.push((i, c)),
+            }
+        }
+        merged.retain(|(_, c)| !c.is_zero());
+        Self(merged)
+    }
+
+    /// Vanishing polynomial of the n-th roots of unity, X^n - 1
+    pub fn vanishing(n: usize) -> Self {
+        Self::from_terms(vec![(0, -F::one()), (n, F::one())])
+    }
+
+    /// X - u
+    pub fn monomial_from_coefficient(u: F) -> Self {
+        Self::from_terms(vec![(0, -u), (1, F::one())])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn degree(&self) -> usize {
+        self.0.last().map_or(0, |(i, _)| *i)
+    }
+
+    pub fn terms(&self) -> &[(usize, F)] {
+        &self.0
+    }
+
+    pub fn evaluate(&self, point: &F) -> F {
+        self.0
+            .iter()
+            .map(|(i, c)| *c * point.pow([*i as u64]))
+            .sum()
+    }
+
+    pub fn to_dense(&self) -> Polynomial<F> {
+        Polynomial::mul_sparse(&Polynomial::from_vector_coefficients(vec![F::one()]), self)
+    }
+}
+
 impl<F: Field> Sub for Polynomial<F> {
     type Output = Self;
 
@@ -179,11 +284,21 @@ impl<F: FftField> Mul for Polynomial<F> {
     }
 }
 
+impl<F: Field> Mul<SparsePolynomial<F>> for Polynomial<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: SparsePolynomial<F>) -> Self::Output {
+        Polynomial::mul_sparse(&self, &rhs)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ark_bn254::Fr;
+    use ark_std::UniformRand;
+    use rand::thread_rng;
 
-    use super::Polynomial;
+    use super::{Polynomial, SparsePolynomial};
 
     #[test]
     fn test_new_from_vector() {
@@ -220,4 +335,48 @@ mod test {
 
         assert_eq!(p, poly)
     }
+
+    #[test]
+    fn test_sparse_matches_dense() {
+        let mut rng = thread_rng();
+        let sparse = SparsePolynomial::from_terms(vec![
+            (64, Fr::from(3_u64)),
+            (0, Fr::from(5_u64)),
+            (17, Fr::from(2_u64)),
+            (64, Fr::from(4_u64)),
+        ]);
+        let dense = sparse.to_dense();
+        let point = Fr::rand(&mut rng);
+
+        assert_eq!(sparse.terms().len(), 3);
+        assert_eq!(sparse.degree(), 64);
+        assert_eq!(dense.get_coeff(64), Some(&Fr::from(7_u64)));
+        assert_eq!(sparse.evaluate(&point), dense.evaluate(&point));
+
+        let poly = Polynomial::<Fr>::from_random_coefficients(30);
+        assert_eq!(poly.clone() * sparse, poly * dense);
+    }
+
+    #[test]
+    fn test_div_sparse() {
+        let vanishing = SparsePolynomial::<Fr>::vanishing(16);
+        let q_poly = Polynomial::<Fr>::from_random_coefficients(40);
+        let r_poly = Polynomial::<Fr>::from_random_coefficients(15);
+        let poly = q_poly.clone() * vanishing.clone() + r_poly.clone();
+
+        let (q, r) = Polynomial::div_sparse(poly.clone(), &vanishing).unwrap();
+        assert_eq!((q.clone(), r.clone()), (q_poly, r_poly));
+        assert_eq!(
+            Polynomial::div_polynomials(poly, &vanishing.to_dense()),
+            Some((q, r))
+        );
+
+        let small_poly = Polynomial::<Fr>::from_random_coefficients(10);
+        let (q, r) = Polynomial::div_sparse(small_poly.clone(), &vanishing).unwrap();
+        assert!(q.is_zero());
+        assert_eq!(r, small_poly);
+        assert!(
+            Polynomial::div_sparse(small_poly, &SparsePolynomial::from_terms(vec![])).is_none()
+        );
+    }
 }
@@ -0,0 +1,304 @@
+use ark_ff::{BigInteger, PrimeField};
+
+/// Number of partial rounds for widths 2..=17 with x^5 S-boxes, 8 full rounds and 128 bits of security over ~254 bit
+/// fields, as in the reference implementation of the Poseidon paper
+const PARTIAL_ROUNDS: [usize; 16] = [
+    56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+];
+const FULL_ROUNDS: usize = 8;
+const ALPHA: u64 = 5;
+
+/// Poseidon permutation parameters. Round constants and MDS matrix are derived with the Grain LFSR of the reference
+/// implementation, so for BN254 `Fr` they match the standard constants (e.g. circomlib).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoseidonParams<F: PrimeField> {
+    pub width: usize,
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+    pub alpha: u64,
+    pub round_constants: Vec<F>,
+    pub mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> PoseidonParams<F> {
+    pub fn new(width: usize) -> Result<Self, String> {
+        if !(2..=PARTIAL_ROUNDS.len() + 1).contains(&width) {
+            return Err("Poseidon width must be between 2 and 17".to_string());
+        }
+        // x^5 is a permutation only if gcd(5, p - 1) = 1. Since 2^64 = 1 mod 5, p - 1 mod 5 is the sum of its limbs mod 5
+        let mut modulus_minus_one = F::MODULUS;
+        modulus_minus_one.sub_with_borrow(&F::BigInt::from(1u64));
+        let residue = modulus_minus_one
+            .as_ref()
+            .iter()
+            .fold(0u64, |acc, limb| (acc + limb % ALPHA) % ALPHA);
+        if residue == 0 {
+            return Err("x^5 is not a permutation of the field".to_string());
+        }
+
+        let partial_rounds = PARTIAL_ROUNDS[width - 2];
+        let n_bits = F::MODULUS_BIT_SIZE as usize;
+        let mut grain = Grain::new(n_bits, width, FULL_ROUNDS, partial_rounds);
+
+        let round_constants = (0..(FULL_ROUNDS + partial_rounds) * width)
+            .map(|_| loop {
+                if let Some(c) = F::from_bigint(F::BigInt::from_bits_be(&grain.bits(n_bits))) {
+                    break c;
+                }
+            })
+            .collect();
+
+        // Cauchy matrix M_ij = 1 / (x_i + y_j) over 2 * width distinct sampled elements
+        let mds = loop {
+            let elements: Vec<F> = (0..2 * width)
+                .map(|_| {
+                    let bits = grain.bits(n_bits);
+                    F::from_be_bytes_mod_order(&F::BigInt::from_bits_be(&bits).to_bytes_be())
+                })
+                .collect();
+            let distinct = elements
+                .iter()
+                .enumerate()
+                .all(|(i, e)| !elements[i + 1..].contains(e));
+            if !distinct {
+                continue;
+            }
+            let (xs, ys) = elements.split_at(width);
+            let matrix: Option<Vec<Vec<F>>> = xs
+                .iter()
+                .map(|x| ys.iter().map(|y| (*x + y).inverse()).collect())
+                .collect();
+            if let Some(matrix) = matrix {
+                break matrix;
+            }
+        };
+
+        Ok(PoseidonParams {
+            width,
+            full_rounds: FULL_ROUNDS,
+            partial_rounds,
+            alpha: ALPHA,
+            round_constants,
+            mds,
+        })
+    }
+
+    /// Applies the permutation in place: R_F / 2 full rounds, R_P partial rounds and R_F / 2 full rounds, each adding the
+    /// round constants, applying x^alpha (to every element in full rounds, to the first one in partial rounds) and
+    /// multiplying by the MDS matrix
+    pub fn permute(&self, state: &mut [F]) -> Result<(), String> {
+        if state.len() != self.width {
+            return Err("State length doesnt match the Poseidon width".to_string());
+        }
+        let half_full_rounds = self.full_rounds / 2;
+        let n_rounds = self.full_rounds + self.partial_rounds;
+        for round in 0..n_rounds {
+            for (s, c) in state
+                .iter_mut()
+                .zip(&self.round_constants[round * self.width..(round + 1) * self.width])
+            {
+                *s += c;
+            }
+            if round < half_full_rounds || round >= half_full_rounds + self.partial_rounds {
+                for s in state.iter_mut() {
+                    *s = s.pow([self.alpha]);
+                }
+            } else {
+                state[0] = state[0].pow([self.alpha]);
+            }
+            let mixed: Vec<F> = self
+                .mds
+                .iter()
+                .map(|row| row.iter().zip(state.iter()).map(|(m, s)| *m * s).sum())
+                .collect();
+            state.copy_from_slice(&mixed);
+        }
+        Ok(())
+    }
+
+    /// Fixed length hash of width - 1 inputs: the first element of the permutation of [0, inputs]
+    pub fn hash(&self, inputs: &[F]) -> Result<F, String> {
+        if inputs.len() != self.width - 1 {
+            return Err("Number of inputs must be the Poseidon width minus one".to_string());
+        }
+        let mut state = Vec::with_capacity(self.width);
+        state.push(F::zero());
+        state.extend_from_slice(inputs);
+        self.permute(&mut state)?;
+        Ok(state[0])
+    }
+}
+
+/// Duplex sponge over the Poseidon permutation with capacity 1 (the first state element) and rate width - 1.
+/// Absorbed elements are added to the rate part, which is permuted whenever it is full or when switching from
+/// absorbing to squeezing
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoseidonSponge<F: PrimeField> {
+    params: PoseidonParams<F>,
+    state: Vec<F>,
+    position: usize,
+    squeezing: bool,
+}
+
+impl<F: PrimeField> PoseidonSponge<F> {
+    pub fn new(params: PoseidonParams<F>) -> Self {
+        let state = vec![F::zero(); params.width];
+        Self {
+            params,
+            state,
+            position: 0,
+            squeezing: false,
+        }
+    }
+
+    pub fn rate(&self) -> usize {
+        self.params.width - 1
+    }
+
+    pub fn absorb(&mut self, inputs: &[F]) {
+        if self.squeezing {
+            self.squeezing = false;
+            self.position = 0;
+        }
+        for input in inputs {
+            if self.position == self.rate() {
+                self.permute();
+            }
+            self.state[1 + self.position] += input;
+            self.position += 1;
+        }
+    }
+
+    pub fn squeeze(&mut self, n: usize) -> Vec<F> {
+        if !self.squeezing {
+            self.permute();
+            self.squeezing = true;
+        }
+        let mut outputs = Vec::with_capacity(n);
+        for _ in 0..n {
+            if self.position == self.rate() {
+                self.permute();
+            }
+            outputs.push(self.state[1 + self.position]);
+            self.position += 1;
+        }
+        outputs
+    }
+
+    fn permute(&mut self) {
+        self.params
+            .permute(&mut self.state)
+            .expect("Sponge state has the Poseidon width");
+        self.position = 0;
+    }
+}
+
+/// Self-shrinking Grain LFSR used by the reference implementation to generate Poseidon parameters
+struct Grain {
+    state: Vec<bool>,
+}
+
+impl Grain {
+    fn new(n_bits: usize, width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let mut state = Vec::with_capacity(80);
+        let mut push_bits = |value: usize, n: usize| {
+            for i in (0..n).rev() {
+                state.push((value >> i) & 1 == 1);
+            }
+        };
+        push_bits(1, 2); // prime field
+        push_bits(0, 4); // x^alpha S-box
+        push_bits(n_bits, 12);
+        push_bits(width, 12);
+        push_bits(full_rounds, 10);
+        push_bits(partial_rounds, 10);
+        push_bits((1 << 30) - 1, 30);
+
+        let mut grain = Grain { state };
+        for _ in 0..160 {
+            grain.next_raw();
+        }
+        grain
+    }
+
+    fn next_raw(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.remove(0);
+        self.state.push(bit);
+        bit
+    }
+
+    // Bits are read in pairs, the second one is output only if the first one is set
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.next_raw();
+            let bit = self.next_raw();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    fn bits(&mut self, n: usize) -> Vec<bool> {
+        (0..n).map(|_| self.next_bit()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_ff::MontFp;
+
+    use super::*;
+
+    #[test]
+    fn test_poseidon_bn254_width_3_vector() {
+        // Reference implementation test vector for x^5, 254 bits, width 3 on input [0, 1, 2]
+        let params = PoseidonParams::<Fr>::new(3).unwrap();
+        let mut state = [Fr::from(0u64), Fr::from(1u64), Fr::from(2u64)];
+        params.permute(&mut state).unwrap();
+
+        let expected: Fr =
+            MontFp!("7853200120776062878684798364095072458815029376092732009249414926327459813530");
+        assert_eq!(state[0], expected);
+    }
+
+    #[test]
+    fn test_poseidon_hash_matches_permutation() {
+        let params = PoseidonParams::<Fr>::new(3).unwrap();
+        let expected: Fr =
+            MontFp!("7853200120776062878684798364095072458815029376092732009249414926327459813530");
+
+        assert_eq!(
+            params.hash(&[Fr::from(1u64), Fr::from(2u64)]).unwrap(),
+            expected
+        );
+        assert!(params.hash(&[Fr::from(1u64)]).is_err());
+    }
+
+    #[test]
+    fn test_poseidon_sponge_deterministic() {
+        let params = PoseidonParams::<Fr>::new(3).unwrap();
+        let inputs: Vec<Fr> = (0..5u64).map(Fr::from).collect();
+
+        let mut sponge1 = PoseidonSponge::new(params.clone());
+        sponge1.absorb(&inputs);
+        let mut sponge2 = PoseidonSponge::new(params.clone());
+        sponge2.absorb(&inputs[..2]);
+        sponge2.absorb(&inputs[2..]);
+        assert_eq!(sponge1.squeeze(3), sponge2.squeeze(3));
+
+        let mut sponge3 = PoseidonSponge::new(params.clone());
+        sponge3.absorb(&inputs[..4]);
+        let mut sponge4 = PoseidonSponge::new(params);
+        sponge4.absorb(&inputs);
+        assert_ne!(sponge3.squeeze(1), sponge4.squeeze(1));
+    }
+
+    #[test]
+    fn test_poseidon_invalid_width() {
+        assert!(PoseidonParams::<Fr>::new(1).is_err());
+        assert!(PoseidonParams::<Fr>::new(18).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use std::marker::PhantomData;
+
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use sha2::{Digest as _, Sha256};
+
+use super::poseidon::{PoseidonParams, PoseidonSponge};
+
+/// Fiat-Shamir transcript. Prover and verifier append the same messages in the same order and derive the verifier
+/// challenges from them, which makes interactive protocols non interactive
+pub trait Transcript<F: PrimeField> {
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]);
+
+    fn append_scalars(&mut self, label: &[u8], scalars: &[F]);
+
+    fn challenge_scalar(&mut self, label: &[u8]) -> F;
+
+    fn append_scalar(&mut self, label: &[u8], scalar: &F) {
+        self.append_scalars(label, std::slice::from_ref(scalar));
+    }
+
+    /// Appends any serializable message, such as a curve point, by its compressed encoding
+    fn append_serializable<S: CanonicalSerialize>(&mut self, label: &[u8], message: &S) {
+        let mut bytes = Vec::with_capacity(message.compressed_size());
+        message
+            .serialize_compressed(&mut bytes)
+            .expect("Serializing into a vector doesnt fail");
+        self.append_bytes(label, &bytes);
+    }
+
+    fn challenge_scalars(&mut self, label: &[u8], n: usize) -> Vec<F> {
+        (0..n).map(|_| self.challenge_scalar(label)).collect()
+    }
+
+    /// Position in 0..n taken from the low limb of a challenge, nearly uniform since n is far below the field order
+    fn challenge_index(&mut self, label: &[u8], n: usize) -> usize {
+        let challenge = self.challenge_scalar(label).into_bigint();
+        (challenge.as_ref()[0] % n as u64) as usize
+    }
+}
+
+/// Byte oriented transcript. The state is a SHA-256 hash chain H(state || label || length || message) and challenges
+/// are the next state reduced modulo the field order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sha256Transcript<F: PrimeField> {
+    state: [u8; 32],
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> Sha256Transcript<F> {
+    pub fn new(domain_separator: &[u8]) -> Self {
+        Self {
+            state: Sha256::digest(domain_separator).into(),
+            _field: PhantomData,
+        }
+    }
+
+    fn update(&mut self, label: &[u8], message: &[u8]) {
+        let mut hasher = Sha256::new();
+        hasher.update(self.state);
+        hasher.update((label.len() as u64).to_le_bytes());
+        hasher.update(label);
+        hasher.update((message.len() as u64).to_le_bytes());
+        hasher.update(message);
+        self.state = hasher.finalize().into();
+    }
+}
+
+impl<F: PrimeField> Transcript<F> for Sha256Transcript<F> {
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.update(label, bytes);
+    }
+
+    fn append_scalars(&mut self, label: &[u8], scalars: &[F]) {
+        let mut bytes =
+            Vec::with_capacity(scalars.len() * F::MODULUS_BIT_SIZE.div_ceil(8) as usize);
+        for scalar in scalars {
+            scalar
+                .serialize_compressed(&mut bytes)
+                .expect("Serializing into a vector doesnt fail");
+        }
+        self.update(label, &bytes);
+    }
+
+    fn challenge_scalar(&mut self, label: &[u8]) -> F {
+        self.update(label, b"challenge");
+        F::from_le_bytes_mod_order(&self.state)
+    }
+}
+
+/// Algebraic transcript over a Poseidon sponge, cheap to verify inside a circuit over F. Scalars are absorbed natively,
+/// and labels and byte messages are packed into field elements of MODULUS_BIT_SIZE / 8 bytes, prefixed by their length
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoseidonTranscript<F: PrimeField> {
+    sponge: PoseidonSponge<F>,
+}
+
+impl<F: PrimeField> PoseidonTranscript<F> {
+    pub fn new(params: PoseidonParams<F>, domain_separator: &[u8]) -> Self {
+        let mut transcript = Self {
+            sponge: PoseidonSponge::new(params),
+        };
+        transcript.absorb_bytes(domain_separator);
+        transcript
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        let chunk_size = (F::MODULUS_BIT_SIZE as usize - 1) / 8;
+        let mut elements = Vec::with_capacity(1 + bytes.len().div_ceil(chunk_size));
+        elements.push(F::from(bytes.len() as u64));
+        elements.extend(bytes.chunks(chunk_size).map(F::from_le_bytes_mod_order));
+        self.sponge.absorb(&elements);
+    }
+}
+
+impl<F: PrimeField> Transcript<F> for PoseidonTranscript<F> {
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.absorb_bytes(label);
+        self.absorb_bytes(bytes);
+    }
+
+    fn append_scalars(&mut self, label: &[u8], scalars: &[F]) {
+        self.absorb_bytes(label);
+        self.sponge.absorb(&[F::from(scalars.len() as u64)]);
+        self.sponge.absorb(scalars);
+    }
+
+    fn challenge_scalar(&mut self, label: &[u8]) -> F {
+        self.absorb_bytes(label);
+        self.sponge.squeeze(1)[0]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::{Fr, G1Projective};
+    use ark_ec::PrimeGroup;
+    use ark_std::One;
+
+    use super::*;
+
+    fn check_transcript<T: Transcript<Fr> + Clone>(transcript: T) {
+        let point = G1Projective::generator();
+        let scalars = vec![Fr::from(3u64), Fr::from(5u64)];
+
+        let mut prover = transcript.clone();
+        prover.append_serializable(b"commitment", &point);
+        prover.append_scalars(b"evaluations", &scalars);
+        let prover_challenges = prover.challenge_scalars(b"u", 3);
+
+        let mut verifier = transcript.clone();
+        verifier.append_serializable(b"commitment", &point);
+        verifier.append_scalars(b"evaluations", &scalars);
+        let verifier_challenges = verifier.challenge_scalars(b"u", 3);
+
+        assert_eq!(prover_challenges, verifier_challenges);
+        assert_ne!(prover_challenges[0], prover_challenges[1]);
+        assert_eq!(
+            prover.challenge_index(b"index", 10),
+            verifier.challenge_index(b"index", 10)
+        );
+        assert!(prover.challenge_index(b"index", 10) < 10);
+
+        let mut tampered = transcript;
+        tampered.append_serializable(b"commitment", &point);
+        tampered.append_scalars(b"evaluations", &[scalars[0], scalars[1] + Fr::one()]);
+        assert_ne!(tampered.challenge_scalar(b"u"), prover_challenges[0]);
+    }
+
+    #[test]
+    fn test_sha256_transcript() {
+        check_transcript(Sha256Transcript::new(b"test"));
+    }
+
+    #[test]
+    fn test_poseidon_transcript() {
+        let params = PoseidonParams::<Fr>::new(3).unwrap();
+        check_transcript(PoseidonTranscript::new(params, b"test"));
+    }
+
+    #[test]
+    fn test_transcript_labels_separate_messages() {
+        let mut transcript1 = Sha256Transcript::<Fr>::new(b"test");
+        transcript1.append_bytes(b"a", b"bc");
+        let mut transcript2 = Sha256Transcript::<Fr>::new(b"test");
+        transcript2.append_bytes(b"ab", b"c");
+
+        assert_ne!(
+            transcript1.challenge_scalar(b"x"),
+            transcript2.challenge_scalar(b"x")
+        );
+    }
+}
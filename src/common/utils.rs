@@ -1,4 +1,6 @@
-use ark_ff::FftField;
+use std::ops::{Add, Mul, Sub};
+
+use ark_ff::{FftField, Zero};
 
 pub fn compute_roots_of_unity<F: FftField>(order: u64) -> Result<Vec<F>, String> {
     if !order.is_power_of_two() {
@@ -15,3 +17,107 @@ pub fn compute_roots_of_unity<F: FftField>(order: u64) -> Result<Vec<F>, String>
     }
     Ok(roots)
 }
+
+/// Evaluates the polynomial with coefficients `coeffs` over the domain `roots` = [1, w, w^2, ..., w^(n-1)] as returned by
+/// `compute_roots_of_unity`, using an iterative radix-2 Cooley-Tukey FFT. Coefficients can be field elements or group
+/// elements, e.g. the FFT of the powers s^i * G gives the Lagrange basis L_i(s) * G
+pub fn fft<F, T>(coeffs: &[T], roots: &[F]) -> Result<Vec<T>, String>
+where
+    F: FftField,
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Mul<F, Output = T>,
+{
+    let n = roots.len();
+    if !n.is_power_of_two() {
+        return Err("Domain size must be a power of two".to_string());
+    }
+    if coeffs.len() > n {
+        return Err("Number of coefficients is higher than the domain size".to_string());
+    }
+
+    let mut values = coeffs.to_vec();
+    values.resize(n, T::zero());
+
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = bit_reverse(i, log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut size = 2;
+    while size <= n {
+        let step = n / size;
+        for start in (0..n).step_by(size) {
+            for j in 0..size / 2 {
+                let t = values[start + j + size / 2] * roots[j * step];
+                let u = values[start + j];
+                values[start + j] = u + t;
+                values[start + j + size / 2] = u - t;
+            }
+        }
+        size *= 2;
+    }
+    Ok(values)
+}
+
+/// Recovers the coefficients of the polynomial taking `evaluations` over the domain `roots`
+pub fn ifft<F, T>(evaluations: &[T], roots: &[F]) -> Result<Vec<T>, String>
+where
+    F: FftField,
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Mul<F, Output = T>,
+{
+    let n = roots.len();
+    if evaluations.len() != n {
+        return Err("Number of evaluations doesnt match the domain size".to_string());
+    }
+    let inverse_roots: Vec<F> = (0..n).map(|i| roots[(n - i) % n]).collect();
+    let n_inverse = F::from(n as u64)
+        .inverse()
+        .ok_or("Domain size is not invertible")?;
+    let coeffs = fft(evaluations, &inverse_roots)?;
+    Ok(coeffs.into_iter().map(|c| c * n_inverse).collect())
+}
+
+/// Reverses the `n_bits` least significant bits of `index`
+pub fn bit_reverse(index: usize, n_bits: u32) -> usize {
+    if n_bits == 0 {
+        return 0;
+    }
+    index.reverse_bits() >> (usize::BITS - n_bits)
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::UniformRand;
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::common::polynomial::Polynomial;
+
+    #[test]
+    fn test_fft_matches_evaluation() {
+        let mut rng = thread_rng();
+        let roots = compute_roots_of_unity::<Fr>(32).unwrap();
+        let coeffs: Vec<Fr> = (0..20).map(|_| Fr::rand(&mut rng)).collect();
+        let poly = Polynomial::from_vector_coefficients(coeffs.clone());
+
+        let evaluations = fft(&coeffs, &roots).unwrap();
+
+        for (root, eval) in roots.iter().zip(evaluations) {
+            assert_eq!(poly.evaluate(root), eval);
+        }
+    }
+
+    #[test]
+    fn test_ifft_inverts_fft() {
+        let mut rng = thread_rng();
+        let roots = compute_roots_of_unity::<Fr>(64).unwrap();
+        let coeffs: Vec<Fr> = (0..64).map(|_| Fr::rand(&mut rng)).collect();
+
+        let evaluations = fft(&coeffs, &roots).unwrap();
+
+        assert_eq!(ifft(&evaluations, &roots).unwrap(), coeffs);
+    }
+}
@@ -0,0 +1,40 @@
+pub mod pasta;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::{pairing::Pairing, short_weierstrass::Projective, CurveGroup, PrimeGroup};
+
+use crate::ipa::setup::GlobalIpaParams;
+use crate::kzg::setup::GlobalKzgParams;
+use pasta::{PallasProjective, VestaProjective};
+
+/// Cycle of curves, the base field of each curve is the scalar field of the other one
+pub trait CurveCycle {
+    type Curve1: CurveGroup<BaseField = <Self::Curve2 as PrimeGroup>::ScalarField>;
+    type Curve2: CurveGroup<BaseField = <Self::Curve1 as PrimeGroup>::ScalarField>;
+}
+
+/// Pairing curves supported by the KZG based schemes, BN254 and BLS12-381
+pub trait KzgCurve: Pairing {
+    fn kzg_setup(max_degree: usize) -> GlobalKzgParams<Self> {
+        GlobalKzgParams::new(max_degree)
+    }
+}
+
+impl KzgCurve for Bn254 {}
+
+impl KzgCurve for Bls12_381 {}
+
+/// Curves supported by the IPA based schemes, the BN254 G1 group and the Pallas/Vesta cycle
+pub trait IpaCurve: CurveGroup {
+    fn ipa_setup(max_degree: usize) -> GlobalIpaParams<Self> {
+        GlobalIpaParams::new(max_degree)
+    }
+}
+
+// ark_bn254::G1Projective names its config through BnConfig, which coherence cant tell apart from Pallas and Vesta
+impl IpaCurve for Projective<ark_bn254::g1::Config> {}
+
+impl IpaCurve for PallasProjective {}
+
+impl IpaCurve for VestaProjective {}
@@ -0,0 +1,65 @@
+//! Pallas and Vesta, the cycle of curves y^2 = x^3 + 5 used by Halo: the base field of each curve is the scalar field
+//! of the other one, so IPA accumulators of one curve are verified in circuits over the other.
+use super::CurveCycle;
+
+pub use ark_pallas::{Affine as PallasAffine, PallasConfig, Projective as PallasProjective};
+pub use ark_vesta::{Affine as VestaAffine, Projective as VestaProjective, VestaConfig};
+
+/// Base field of Pallas and scalar field of Vesta
+pub type Fp = ark_pallas::Fq;
+/// Scalar field of Pallas and base field of Vesta
+pub type Fq = ark_pallas::Fr;
+
+/// Pallas/Vesta cycle, starting on Pallas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PastaCycle;
+
+impl CurveCycle for PastaCycle {
+    type Curve1 = PallasProjective;
+    type Curve2 = VestaProjective;
+}
+
+#[cfg(test)]
+mod test {
+    use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+    use ark_ff::{FftField, PrimeField, Zero};
+    use ark_std::UniformRand;
+    use rand::thread_rng;
+
+    use super::*;
+
+    #[test]
+    fn test_pasta_generators() {
+        let pallas = PallasAffine::generator();
+        let vesta = VestaAffine::generator();
+
+        assert!(pallas.is_on_curve());
+        assert!(vesta.is_on_curve());
+        // Prime order curves: the scalar field order annihilates the generator
+        assert!(PallasProjective::generator()
+            .mul_bigint(Fq::MODULUS)
+            .is_zero());
+        assert!(VestaProjective::generator()
+            .mul_bigint(Fp::MODULUS)
+            .is_zero());
+    }
+
+    #[test]
+    fn test_pasta_fields() {
+        assert_eq!(Fp::TWO_ADICITY, 32);
+        assert_eq!(Fq::TWO_ADICITY, 32);
+        assert!(Fp::get_root_of_unity(1 << 32).is_some());
+        assert!(Fq::get_root_of_unity(1 << 32).is_some());
+    }
+
+    #[test]
+    fn test_pasta_group_law() {
+        let mut rng = thread_rng();
+        let a = Fq::rand(&mut rng);
+        let b = Fq::rand(&mut rng);
+        let g = PallasProjective::generator();
+
+        assert_eq!(g * a + g * b, g * (a + b));
+        assert!((g * a).into_affine().is_on_curve());
+    }
+}
@@ -0,0 +1,38 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::{FftField, PrimeField};
+
+use super::{bit_reversal_permutation, bytes_to_field, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT};
+use crate::common::polynomial::Polynomial;
+use crate::common::utils;
+use crate::kzg::commit::commit;
+use crate::kzg::setup::GlobalKzgParams;
+
+/// Parses a blob into its 4096 evaluations over the bit reversed roots of unity
+pub fn blob_to_polynomial<F: PrimeField>(blob: &[u8]) -> Result<Vec<F>, String> {
+    if blob.len() != BYTES_PER_BLOB {
+        return Err("Blob must be 131072 bytes".to_string());
+    }
+    blob.chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(bytes_to_field)
+        .collect()
+}
+
+/// Commitment C = p(s) * G to the polynomial of the blob. The spec commits with the Lagrange form of the setup, here
+/// the evaluations are interpolated and committed with the monomial powers, which gives the same point
+pub fn blob_to_kzg_commitment<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    blob: &[u8],
+) -> Result<P::G1Affine, String> {
+    let evaluations = blob_to_polynomial::<P::ScalarField>(blob)?;
+    let polynomial = evaluations_to_polynomial(&evaluations)?;
+    commit(global_params, &polynomial)
+}
+
+/// Interpolates the evaluations over the bit reversed roots of unity into coefficient form
+pub(crate) fn evaluations_to_polynomial<F: FftField>(
+    evaluations: &[F],
+) -> Result<Polynomial<F>, String> {
+    let roots = utils::compute_roots_of_unity::<F>(evaluations.len() as u64)?;
+    let coeffs = utils::ifft(&bit_reversal_permutation(evaluations), &roots)?;
+    Ok(Polynomial::from_vector_coefficients(coeffs))
+}
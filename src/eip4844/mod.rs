@@ -0,0 +1,146 @@
+pub mod commit;
+pub mod open;
+pub mod setup;
+pub mod verify;
+
+use ark_ff::{BigInteger, FftField, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use sha2::{Digest as _, Sha256};
+
+use crate::common::utils;
+
+pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
+pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
+pub const BYTES_PER_BLOB: usize = FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
+pub const FIAT_SHAMIR_PROTOCOL_DOMAIN: &[u8] = b"FSBLOBVERIFY_V1_";
+pub const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: &[u8] = b"RCKZGBATCH___V1_";
+
+/// Evaluation challenge of a blob: hash_to_bls_field(FIAT_SHAMIR_PROTOCOL_DOMAIN || 4096 as 16 bytes || blob || commitment)
+pub fn compute_challenge<F: PrimeField, C: CanonicalSerialize>(blob: &[u8], commitment: &C) -> F {
+    let mut data = Vec::with_capacity(FIAT_SHAMIR_PROTOCOL_DOMAIN.len() + 16 + blob.len() + 48);
+    data.extend_from_slice(FIAT_SHAMIR_PROTOCOL_DOMAIN);
+    data.extend_from_slice(&(FIELD_ELEMENTS_PER_BLOB as u128).to_be_bytes());
+    data.extend_from_slice(blob);
+    data.extend_from_slice(&point_to_bytes(commitment));
+    hash_to_field(&data)
+}
+
+/// SHA-256 of the data read as a big endian integer modulo the field order
+pub fn hash_to_field<F: PrimeField>(data: &[u8]) -> F {
+    F::from_be_bytes_mod_order(&Sha256::digest(data))
+}
+
+/// Parses a 32 byte big endian field element, rejecting non canonical encodings (values >= modulus)
+pub fn bytes_to_field<F: PrimeField>(bytes: &[u8]) -> Result<F, String> {
+    if bytes.len() != BYTES_PER_FIELD_ELEMENT {
+        return Err("Field element must be 32 bytes".to_string());
+    }
+    let modulus = F::MODULUS.to_bytes_be();
+    let mut padded = vec![0u8; modulus.len().saturating_sub(BYTES_PER_FIELD_ELEMENT)];
+    padded.extend_from_slice(bytes);
+    if padded.len() != modulus.len() || padded >= modulus {
+        return Err("Field element is not canonical".to_string());
+    }
+    Ok(F::from_be_bytes_mod_order(bytes))
+}
+
+/// 32 byte big endian encoding of a field element
+pub fn field_to_bytes<F: PrimeField>(element: &F) -> Vec<u8> {
+    let bytes = element.into_bigint().to_bytes_be();
+    bytes[bytes.len().saturating_sub(BYTES_PER_FIELD_ELEMENT)..].to_vec()
+}
+
+/// Compressed encoding of a commitment or proof, 48 bytes in the zcash format for BLS12-381
+pub fn point_to_bytes<C: CanonicalSerialize>(point: &C) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(point.compressed_size());
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("Serializing into a vector doesnt fail");
+    bytes
+}
+
+/// Roots of unity of order 4096 in bit reversal permutation order, blob element i is the evaluation at the i-th one
+pub fn compute_roots_of_unity_brp<F: FftField>() -> Vec<F> {
+    let roots = utils::compute_roots_of_unity::<F>(FIELD_ELEMENTS_PER_BLOB as u64)
+        .expect("Blob size is a power of two");
+    bit_reversal_permutation(&roots)
+}
+
+pub fn bit_reversal_permutation<T: Copy>(values: &[T]) -> Vec<T> {
+    let n_bits = values.len().trailing_zeros();
+    (0..values.len())
+        .map(|i| values[utils::bit_reverse(i, n_bits)])
+        .collect()
+}
+
+/// Evaluates the polynomial given by its evaluations over the bit reversed roots of unity at z with the barycentric
+/// formula p(z) = (z^n - 1) / n * Sum{i} p(w_i) * w_i / (z - w_i), or returns p(w_i) if z = w_i
+pub fn evaluate_polynomial_in_evaluation_form<F: FftField>(evaluations: &[F], z: &F) -> F {
+    let roots = compute_roots_of_unity_brp::<F>();
+    if let Some(i) = roots.iter().position(|root| root == z) {
+        return evaluations[i];
+    }
+    let n = F::from(evaluations.len() as u64);
+    let sum: F = evaluations
+        .iter()
+        .zip(&roots)
+        .map(|(p, w)| *p * w * (*z - w).inverse().unwrap())
+        .sum();
+    sum * (z.pow([evaluations.len() as u64]) - F::one()) * n.inverse().unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bls12_381::Fr;
+    use ark_ff::{Field, One, UniformRand};
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::common::polynomial::Polynomial;
+
+    #[test]
+    fn test_bytes_to_field_canonical() {
+        let modulus = Fr::MODULUS.to_bytes_be();
+        assert!(bytes_to_field::<Fr>(&modulus).is_err());
+
+        let mut modulus_minus_one = modulus.clone();
+        modulus_minus_one[31] -= 1;
+        assert_eq!(
+            bytes_to_field::<Fr>(&modulus_minus_one).unwrap(),
+            -Fr::one()
+        );
+        assert_eq!(field_to_bytes(&-Fr::one()), modulus_minus_one);
+        assert!(bytes_to_field::<Fr>(&modulus[1..]).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_polynomial_in_evaluation_form() {
+        let mut rng = thread_rng();
+        let polynomial = Polynomial::<Fr>::from_random_coefficients(FIELD_ELEMENTS_PER_BLOB - 1);
+        let roots = compute_roots_of_unity_brp::<Fr>();
+        let evaluations: Vec<Fr> = roots.iter().map(|w| polynomial.evaluate(w)).collect();
+
+        let z = Fr::rand(&mut rng);
+        assert_eq!(
+            evaluate_polynomial_in_evaluation_form(&evaluations, &z),
+            polynomial.evaluate(&z)
+        );
+        assert_eq!(
+            evaluate_polynomial_in_evaluation_form(&evaluations, &roots[5]),
+            evaluations[5]
+        );
+    }
+
+    #[test]
+    fn test_roots_of_unity_brp() {
+        // The spec derives the domain from the primitive root 7, as arkworks does for BLS12-381
+        let roots = compute_roots_of_unity_brp::<Fr>();
+        let mut exponent = Fr::MODULUS;
+        exponent.sub_with_borrow(&1u64.into());
+        exponent >>= FIELD_ELEMENTS_PER_BLOB.trailing_zeros();
+        let generator = Fr::from(7u64).pow(exponent);
+        assert_eq!(roots[0], Fr::one());
+        assert_eq!(roots[1], -Fr::one());
+        assert_eq!(roots[FIELD_ELEMENTS_PER_BLOB / 2], generator);
+    }
+}
@@ -0,0 +1,27 @@
+use ark_ec::pairing::Pairing;
+
+use super::commit::{blob_to_polynomial, evaluations_to_polynomial};
+use super::compute_challenge;
+use crate::kzg::open::evaluation_proof;
+use crate::kzg::setup::GlobalKzgParams;
+
+/// KZG proof of the blob polynomial at z, returning the proof and y = p(z)
+pub fn compute_kzg_proof<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    blob: &[u8],
+    z: &P::ScalarField,
+) -> Result<(P::G1Affine, P::ScalarField), String> {
+    let evaluations = blob_to_polynomial::<P::ScalarField>(blob)?;
+    let polynomial = evaluations_to_polynomial(&evaluations)?;
+    evaluation_proof(global_params, &polynomial, z)
+}
+
+/// KZG proof of the blob polynomial at the Fiat-Shamir challenge of the blob and its commitment
+pub fn compute_blob_kzg_proof<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    blob: &[u8],
+    commitment: &P::G1Affine,
+) -> Result<P::G1Affine, String> {
+    let z = compute_challenge(blob, commitment);
+    Ok(compute_kzg_proof(global_params, blob, &z)?.0)
+}
@@ -0,0 +1,48 @@
+use ark_ec::pairing::Pairing;
+use ark_serialize::CanonicalDeserialize;
+
+use crate::kzg::setup::GlobalKzgParams;
+
+/// Loads the output of the KZG ceremony in the text format of c-kzg-4844: the number of G1 and G2 points, the G1
+/// points in Lagrange form, the G2 points and the G1 points in monomial form, one compressed point in hex per line.
+/// Only the monomial points are kept as they are the powers s^i * G used by `kzg`. Points are only decompressed, the
+/// ceremony output is trusted to be in the prime order subgroup
+pub fn load_trusted_setup<P: Pairing>(contents: &str) -> Result<GlobalKzgParams<P>, String> {
+    let mut lines = contents.lines().map(str::trim).filter(|l| !l.is_empty());
+    let n_g1 = decode_count(lines.next())?;
+    let n_g2 = decode_count(lines.next())?;
+
+    let mut lines = lines.skip(n_g1);
+    let powers_of_g2 = (0..n_g2)
+        .map(|_| decode_point::<P::G2Affine>(lines.next()))
+        .collect::<Result<Vec<_>, String>>()?;
+    let powers_of_g1 = (0..n_g1)
+        .map(|_| decode_point::<P::G1Affine>(lines.next()))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(GlobalKzgParams {
+        powers_of_g1,
+        powers_of_g2,
+    })
+}
+
+fn decode_count(line: Option<&str>) -> Result<usize, String> {
+    line.and_then(|l| l.parse().ok())
+        .ok_or_else(|| "Invalid number of points in trusted setup".to_string())
+}
+
+fn decode_point<C: CanonicalDeserialize>(line: Option<&str>) -> Result<C, String> {
+    let bytes = decode_hex(line.ok_or("Missing points in trusted setup")?)?;
+    C::deserialize_compressed_unchecked(&bytes[..]).map_err(|e| e.to_string())
+}
+
+pub(crate) fn decode_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if !hex.len().is_multiple_of(2) {
+        return Err("Hex string has an odd length".to_string());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|e| e.to_string()))
+        .collect()
+}
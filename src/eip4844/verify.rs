@@ -0,0 +1,83 @@
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{One, Zero};
+
+use super::commit::blob_to_polynomial;
+use super::{
+    compute_challenge, evaluate_polynomial_in_evaluation_form, field_to_bytes, hash_to_field,
+    point_to_bytes, FIELD_ELEMENTS_PER_BLOB, RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
+};
+use crate::kzg::setup::GlobalKzgParams;
+use crate::kzg::verify::verify;
+
+pub fn verify_kzg_proof<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    commitment: &P::G1Affine,
+    z: &P::ScalarField,
+    y: &P::ScalarField,
+    proof: &P::G1Affine,
+) -> bool {
+    verify(global_params, commitment, proof, z, y)
+}
+
+/// Recomputes the challenge z of the blob, evaluates y = p(z) from the blob and checks the KZG proof
+pub fn verify_blob_kzg_proof<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    blob: &[u8],
+    commitment: &P::G1Affine,
+    proof: &P::G1Affine,
+) -> Result<bool, String> {
+    let evaluations = blob_to_polynomial::<P::ScalarField>(blob)?;
+    let z = compute_challenge(blob, commitment);
+    let y = evaluate_polynomial_in_evaluation_form(&evaluations, &z);
+    Ok(verify_kzg_proof(global_params, commitment, &z, &y, proof))
+}
+
+/// Verifies n blob proofs with a random linear combination, r = hash_to_bls_field(RANDOM_CHALLENGE_KZG_BATCH_DOMAIN ||
+/// 4096 || n || Sum{i} C_i || z_i || y_i || proof_i), and the single pairing check
+/// e(Sum{i} r^i * proof_i, s * G2) = e(Sum{i} r^i * (C_i - y_i * G + z_i * proof_i), G2)
+pub fn verify_blob_kzg_proof_batch<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    blobs: &[Vec<u8>],
+    commitments: &[P::G1Affine],
+    proofs: &[P::G1Affine],
+) -> Result<bool, String> {
+    if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
+        return Err("Number of blobs, commitments and proofs doesnt match".to_string());
+    }
+    if global_params.is_empty() || global_params.powers_of_g2.len() < 2 {
+        return Err("Not enough G2 powers in the setup".to_string());
+    }
+
+    let mut data = RANDOM_CHALLENGE_KZG_BATCH_DOMAIN.to_vec();
+    data.extend_from_slice(&(FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes());
+    data.extend_from_slice(&(commitments.len() as u64).to_be_bytes());
+    let mut openings = Vec::with_capacity(blobs.len());
+    for ((blob, commitment), proof) in blobs.iter().zip(commitments).zip(proofs) {
+        let evaluations = blob_to_polynomial::<P::ScalarField>(blob)?;
+        let z = compute_challenge(blob, commitment);
+        let y = evaluate_polynomial_in_evaluation_form(&evaluations, &z);
+        data.extend_from_slice(&point_to_bytes(commitment));
+        data.extend_from_slice(&field_to_bytes(&z));
+        data.extend_from_slice(&field_to_bytes(&y));
+        data.extend_from_slice(&point_to_bytes(proof));
+        openings.push((z, y));
+    }
+    let r: P::ScalarField = hash_to_field(&data);
+
+    let g1_generator = P::G1Affine::generator();
+    let mut proof_lincomb = P::G1::zero();
+    let mut commitment_lincomb = P::G1::zero();
+    let mut r_i = P::ScalarField::one();
+    for ((commitment, proof), (z, y)) in commitments.iter().zip(proofs).zip(openings) {
+        proof_lincomb += *proof * r_i;
+        commitment_lincomb += (*commitment - g1_generator * y + *proof * z) * r_i;
+        r_i *= r;
+    }
+
+    let lhs = P::pairing(proof_lincomb.into_affine(), global_params.powers_of_g2[1]);
+    let rhs = P::pairing(
+        commitment_lincomb.into_affine(),
+        global_params.powers_of_g2[0],
+    );
+    Ok(lhs == rhs)
+}
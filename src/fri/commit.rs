@@ -0,0 +1,31 @@
+use ark_ff::PrimeField;
+
+use super::setup::GlobalFriParams;
+use crate::common::merkle::{Digest, MerkleTree};
+use crate::common::polynomial::Polynomial;
+use crate::common::utils;
+
+/// The commitment to f(x) is the Merkle root of its Reed-Solomon codeword [f(1), f(w), ..., f(w^(N-1))]
+pub fn commit<F: PrimeField>(
+    global_params: &GlobalFriParams<F>,
+    polynomial: &Polynomial<F>,
+) -> Result<Digest, String> {
+    let (_, tree) = encode(global_params, polynomial)?;
+    Ok(tree.root())
+}
+
+/// Codeword of f over the FRI domain and its Merkle tree
+pub(crate) fn encode<F: PrimeField>(
+    global_params: &GlobalFriParams<F>,
+    polynomial: &Polynomial<F>,
+) -> Result<(Vec<F>, MerkleTree<F>), String> {
+    if polynomial.degree() >= global_params.degree_bound {
+        return Err(
+            "Error committing to Polynomial. Polynomial degree is higher than the degree bound"
+                .to_string(),
+        );
+    }
+    let codeword = utils::fft(polynomial.coeffs(), &global_params.domain)?;
+    let tree = MerkleTree::new(&codeword)?;
+    Ok((codeword, tree))
+}
@@ -0,0 +1,47 @@
+pub mod commit;
+pub mod open;
+pub mod setup;
+pub mod verify;
+
+use ark_ff::PrimeField;
+
+use crate::common::merkle::{Digest, MerklePath};
+use crate::common::transcript::{Sha256Transcript, Transcript};
+
+/// Opening of the pair of codeword positions (i, i + N/2) that fold together in one FRI round
+#[derive(Debug, Clone, PartialEq)]
+pub struct FriQueryRound<F: PrimeField> {
+    pub value_lo: F,
+    pub value_hi: F,
+    pub path_lo: MerklePath,
+    pub path_hi: MerklePath,
+}
+
+/// `rounds[0]` opens the committed codeword of f, and `rounds[j]` the committed folding layer j
+#[derive(Debug, Clone, PartialEq)]
+pub struct FriQuery<F: PrimeField> {
+    pub rounds: Vec<FriQueryRound<F>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FriProof<F: PrimeField> {
+    pub layer_roots: Vec<Digest>, // Merkle roots of folding layers 1..r-1
+    pub final_value: F,           // constant polynomial after r folding rounds
+    pub queries: Vec<FriQuery<F>>,
+}
+
+const EVALUATION_LABEL: &[u8] = b"fri_evaluation";
+
+/// Transcript over the commitment to f, the point z and the claimed evaluation v, from which the folding challenges and
+/// the query positions are drawn
+pub(crate) fn evaluation_transcript<F: PrimeField>(
+    commitment: &Digest,
+    z: &F,
+    v: &F,
+) -> Sha256Transcript<F> {
+    let mut transcript = Sha256Transcript::new(EVALUATION_LABEL);
+    transcript.append_bytes(b"fri_commitment", commitment);
+    transcript.append_scalar(b"fri_point", z);
+    transcript.append_scalar(b"fri_value", v);
+    transcript
+}
@@ -0,0 +1,89 @@
+use ark_ff::{batch_inversion, PrimeField};
+
+use super::commit::encode;
+use super::setup::GlobalFriParams;
+use super::{evaluation_transcript, FriProof, FriQuery, FriQueryRound};
+use crate::common::merkle::MerkleTree;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
+
+/// We want to show that f(z) = v, which holds iff q(x) = (f(x) - v) / (x - z) is a polynomial of degree below the
+/// degree bound. The prover runs FRI on the codeword of q, which the verifier derives from the committed codeword of f.
+/// Each round folds p(x) = p_e(x^2) + x * p_o(x^2) into p'(x^2) = p_e(x^2) + beta * p_o(x^2) over a domain of half the
+/// size, until the codeword encodes a constant. The queries open every layer at a position i and its pair i + N/2.
+/// Each beta is drawn from a transcript over the commitment, z, v and the layer roots so far, and the query positions
+/// after also appending the final constant
+pub fn evaluation_proof<F: PrimeField>(
+    global_params: &GlobalFriParams<F>,
+    polynomial: &Polynomial<F>,
+    z: &F,
+) -> Result<(FriProof<F>, F), String> {
+    let (codeword, tree) = encode(global_params, polynomial)?;
+    let v = polynomial.evaluate(z);
+
+    let mut denominators: Vec<F> = global_params.domain.iter().map(|x| *x - z).collect();
+    if denominators.iter().any(|d| d.is_zero()) {
+        return Err("Evaluation point belongs to the domain".to_string());
+    }
+    batch_inversion(&mut denominators);
+    let q_codeword: Vec<F> = codeword
+        .iter()
+        .zip(denominators)
+        .map(|(f, d)| (*f - v) * d)
+        .collect();
+
+    let mut transcript = evaluation_transcript(&tree.root(), z, &v);
+    let num_rounds = global_params.num_rounds();
+    let two_inverse = F::from(2u64).inverse().unwrap();
+    let mut layers = vec![q_codeword];
+    let mut trees = vec![tree];
+    for j in 0..num_rounds {
+        let beta = transcript.challenge_scalar(b"fri_beta");
+        let current = layers.last().unwrap();
+        let half = current.len() / 2;
+        let next: Vec<F> = (0..half)
+            .map(|i| {
+                let x_inverse = global_params.domain[i << j].inverse().unwrap();
+                let (lo, hi) = (current[i], current[i + half]);
+                (lo + hi) * two_inverse + beta * (lo - hi) * two_inverse * x_inverse
+            })
+            .collect();
+        if j + 1 < num_rounds {
+            let tree = MerkleTree::new(&next)?;
+            transcript.append_bytes(b"fri_layer_root", &tree.root());
+            trees.push(tree);
+        }
+        layers.push(next);
+    }
+    let final_value = layers.last().unwrap()[0];
+    transcript.append_scalar(b"fri_final_value", &final_value);
+
+    let mut queries = Vec::with_capacity(global_params.num_queries);
+    for _ in 0..global_params.num_queries {
+        let index = transcript.challenge_index(b"fri_query", global_params.len() / 2);
+        let mut rounds = Vec::with_capacity(trees.len());
+        let mut idx = index;
+        for (j, tree) in trees.iter().enumerate() {
+            let half = tree.len() / 2;
+            idx %= half;
+            let values = if j == 0 { &codeword } else { &layers[j] };
+            rounds.push(FriQueryRound {
+                value_lo: values[idx],
+                value_hi: values[idx + half],
+                path_lo: tree.open(idx)?,
+                path_hi: tree.open(idx + half)?,
+            });
+        }
+        queries.push(FriQuery { rounds });
+    }
+
+    let layer_roots = trees[1..].iter().map(|t| t.root()).collect();
+    Ok((
+        FriProof {
+            layer_roots,
+            final_value,
+            queries,
+        },
+        v,
+    ))
+}
@@ -0,0 +1,76 @@
+use ark_ff::PrimeField;
+
+use crate::common::utils;
+
+/// FRI parameters. Polynomials of degree below `degree_bound` = next power of two of max_degree + 1 are Reed-Solomon
+/// encoded over the roots of unity domain of size degree_bound * blowup_factor, so the code has rate 1 / blowup_factor.
+#[derive(Debug, PartialEq)]
+pub struct GlobalFriParams<F: PrimeField> {
+    pub domain: Vec<F>,
+    pub degree_bound: usize,
+    pub blowup_factor: usize,
+    pub num_queries: usize,
+}
+
+impl<F: PrimeField> GlobalFriParams<F> {
+    pub fn new(
+        max_degree: usize,
+        blowup_factor: usize,
+        num_queries: usize,
+    ) -> Result<Self, String> {
+        if !blowup_factor.is_power_of_two() || blowup_factor < 2 {
+            return Err("Blowup factor must be a power of two greater than one".to_string());
+        }
+        if num_queries == 0 {
+            return Err("Number of queries must be positive".to_string());
+        }
+        let degree_bound = (max_degree + 1).next_power_of_two();
+        let domain = utils::compute_roots_of_unity::<F>((degree_bound * blowup_factor) as u64)?;
+        Ok(GlobalFriParams {
+            domain,
+            degree_bound,
+            blowup_factor,
+            num_queries,
+        })
+    }
+
+    /// Size of the evaluation domain
+    pub fn len(&self) -> usize {
+        self.domain.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.domain.is_empty()
+    }
+
+    pub fn domain_get(&self, n: usize) -> Option<&F> {
+        self.domain.get(n)
+    }
+
+    /// Number of folding rounds until the codeword encodes a constant polynomial
+    pub fn num_rounds(&self) -> usize {
+        self.degree_bound.trailing_zeros() as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+
+    use super::*;
+
+    #[test]
+    fn test_fri_setup_length() {
+        let global_params = GlobalFriParams::<Fr>::new(100, 4, 10).unwrap();
+
+        assert_eq!(global_params.degree_bound, 128);
+        assert_eq!(global_params.len(), 512);
+        assert_eq!(global_params.num_rounds(), 7);
+    }
+
+    #[test]
+    fn test_fri_setup_invalid_blowup() {
+        assert!(GlobalFriParams::<Fr>::new(100, 3, 10).is_err());
+        assert!(GlobalFriParams::<Fr>::new(100, 1, 10).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use ark_ff::PrimeField;
+
+use super::setup::GlobalFriParams;
+use super::{evaluation_transcript, FriProof, FriQueryRound};
+use crate::common::merkle::{Digest, MerkleTree};
+use crate::common::transcript::Transcript;
+
+/// The verifier replays the transcript to get the folding challenges and the query positions. For every query it
+/// checks the Merkle paths of each layer, derives q(x) = (f(x) - v) / (x - z) at the queried positions of the codeword
+/// of f, and checks that folding each pair with beta gives the value opened in the next layer, and the final constant
+/// after the last round
+pub fn verify<F: PrimeField>(
+    global_params: &GlobalFriParams<F>,
+    commitment: &Digest,
+    proof: &FriProof<F>,
+    z: &F,
+    v: &F,
+) -> bool {
+    let num_rounds = global_params.num_rounds();
+    if global_params.is_empty()
+        || proof.layer_roots.len() != num_rounds.saturating_sub(1)
+        || proof.queries.len() != global_params.num_queries
+    {
+        return false;
+    }
+
+    let mut transcript = evaluation_transcript(commitment, z, v);
+    let mut betas = Vec::with_capacity(num_rounds);
+    for j in 0..num_rounds {
+        betas.push(transcript.challenge_scalar(b"fri_beta"));
+        if let Some(layer_root) = proof.layer_roots.get(j) {
+            transcript.append_bytes(b"fri_layer_root", layer_root);
+        }
+    }
+    transcript.append_scalar(b"fri_final_value", &proof.final_value);
+
+    let two_inverse = F::from(2u64).inverse().unwrap();
+    let roots: Vec<&Digest> = std::iter::once(commitment)
+        .chain(proof.layer_roots.iter())
+        .collect();
+
+    for query in &proof.queries {
+        if query.rounds.len() != roots.len() {
+            return false;
+        }
+
+        let mut idx = transcript.challenge_index(b"fri_query", global_params.len() / 2);
+        let mut layer_len = global_params.len();
+        let mut lo_hi = None;
+        for (j, (round, root)) in query.rounds.iter().zip(&roots).enumerate() {
+            let half = layer_len / 2;
+            let position = idx;
+            idx %= half;
+            if !verify_round(round, root, idx, half) {
+                return false;
+            }
+
+            let (lo, hi) = if j == 0 {
+                // q(x) and q(-x) from the codeword of f
+                let x = global_params.domain[idx];
+                let lo_denominator = x - z;
+                let hi_denominator = -x - z;
+                match (lo_denominator.inverse(), hi_denominator.inverse()) {
+                    (Some(lo_inverse), Some(hi_inverse)) => (
+                        (round.value_lo - v) * lo_inverse,
+                        (round.value_hi - v) * hi_inverse,
+                    ),
+                    _ => return false,
+                }
+            } else {
+                // the folded value of the previous round sits at `position` in this layer
+                let expected = if position < half {
+                    round.value_lo
+                } else {
+                    round.value_hi
+                };
+                if lo_hi != Some(expected) {
+                    return false;
+                }
+                (round.value_lo, round.value_hi)
+            };
+
+            lo_hi = if j < num_rounds {
+                let x_inverse = global_params.domain[idx << j].inverse().unwrap();
+                Some((lo + hi) * two_inverse + betas[j] * (lo - hi) * two_inverse * x_inverse)
+            } else {
+                // no folding rounds, q must already be the final constant
+                if lo != hi {
+                    return false;
+                }
+                Some(lo)
+            };
+            layer_len = half;
+        }
+
+        if lo_hi != Some(proof.final_value) {
+            return false;
+        }
+    }
+    true
+}
+
+fn verify_round<F: PrimeField>(
+    round: &FriQueryRound<F>,
+    root: &Digest,
+    idx: usize,
+    half: usize,
+) -> bool {
+    MerkleTree::verify(root, idx, &round.value_lo, &round.path_lo)
+        && MerkleTree::verify(root, idx + half, &round.value_hi, &round.path_hi)
+}
@@ -0,0 +1,76 @@
+pub mod permutation_check;
+pub mod product_check;
+pub mod sum_check;
+pub mod zero_test;
+
+use ark_ff::{Field, PrimeField};
+
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::{Polynomial, SparsePolynomial};
+use crate::common::utils;
+
+/// Evaluation p(x) = value of a committed polynomial with its opening proof
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation<F: PrimeField, S: PolynomialCommitment<F>> {
+    pub value: F,
+    pub proof: S::Proof,
+}
+
+impl<F: PrimeField, S: PolynomialCommitment<F>> Evaluation<F, S> {
+    pub fn open(params: &S::Params, polynomial: &Polynomial<F>, point: &F) -> Result<Self, String> {
+        let (proof, value) = S::open(params, polynomial, point)?;
+        Ok(Evaluation { value, proof })
+    }
+
+    pub fn verify(&self, params: &S::Params, commitment: &S::Commitment, point: &F) -> bool {
+        S::verify(params, commitment, &self.proof, point, &self.value)
+    }
+}
+
+/// Checks that `domain` is a multiplicative subgroup H = [1, w, ..., w^(n-1)] as returned by
+/// `utils::compute_roots_of_unity` with n >= 2, returning n
+pub(crate) fn domain_size<F: Field>(domain: &[F]) -> Result<usize, String> {
+    let n = domain.len();
+    if n < 2 || !n.is_power_of_two() {
+        return Err("Domain size must be a power of two greater than one".to_string());
+    }
+    Ok(n)
+}
+
+/// Splits p(X) = q(X) * Z_H(X) + r(X), where r(X) has degree lower than n and agrees with p(X) over the domain
+pub(crate) fn divide_by_vanishing<F: Field>(
+    polynomial: &Polynomial<F>,
+    n: usize,
+) -> Result<(Polynomial<F>, Polynomial<F>), String> {
+    Polynomial::div_sparse(polynomial.clone(), &SparsePolynomial::vanishing(n))
+        .ok_or("Error in polynomial division".to_string())
+}
+
+/// Evaluates p(X) over the domain. As w^n = 1 the coefficients of p(X) are folded modulo X^n - 1 before the FFT, so the
+/// degree of p(X) can exceed the domain size
+pub(crate) fn evaluate_over_domain<F: PrimeField>(
+    polynomial: &Polynomial<F>,
+    domain: &[F],
+) -> Result<Vec<F>, String> {
+    let n = domain.len();
+    let mut coeffs = vec![F::zero(); n];
+    for (i, coeff) in polynomial.coeffs().iter().enumerate() {
+        coeffs[i % n] += coeff;
+    }
+    utils::fft(&coeffs, domain)
+}
+
+/// p(w * X), with coefficients w^i * p_i
+pub(crate) fn shift<F: Field>(polynomial: &Polynomial<F>, w: &F) -> Polynomial<F> {
+    let mut power = F::one();
+    let coeffs = polynomial
+        .coeffs()
+        .iter()
+        .map(|c| {
+            let coeff = *c * power;
+            power *= w;
+            coeff
+        })
+        .collect();
+    Polynomial::from_vector_coefficients(coeffs)
+}
@@ -0,0 +1,52 @@
+use ark_ff::PrimeField;
+
+use super::product_check::{prove_product, verify_product, ProductCheckProof};
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
+
+/// Proves that the evaluations of g(X) over H are a permutation of the evaluations of f(X). After committing to both,
+/// a random gamma is drawn and the product check shows Prod{a in H} (f(a) + gamma) / (g(a) + gamma) = 1, i.e. the
+/// polynomials Prod{a in H} (X + f(a)) and Prod{a in H} (X + g(a)) agree at gamma, so they are equal w.h.p.
+pub fn prove_permutation_check<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    f_poly: &Polynomial<F>,
+    g_poly: &Polynomial<F>,
+    commitment_f: &S::Commitment,
+    commitment_g: &S::Commitment,
+    transcript: &mut T,
+) -> Result<ProductCheckProof<F, S>, String> {
+    let gamma = challenge::<F, S, T>(transcript, commitment_f, commitment_g);
+    prove_product(params, domain, f_poly, Some(g_poly), &gamma, transcript)
+}
+
+pub fn verify_permutation_check<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    commitment_f: &S::Commitment,
+    commitment_g: &S::Commitment,
+    proof: &ProductCheckProof<F, S>,
+    transcript: &mut T,
+) -> bool {
+    let gamma = challenge::<F, S, T>(transcript, commitment_f, commitment_g);
+    verify_product(
+        params,
+        domain,
+        commitment_f,
+        Some(commitment_g),
+        &gamma,
+        proof,
+        transcript,
+    )
+}
+
+fn challenge<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    transcript: &mut T,
+    commitment_f: &S::Commitment,
+    commitment_g: &S::Commitment,
+) -> F {
+    transcript.append_serializable(b"permutation_check_commitment_f", commitment_f);
+    transcript.append_serializable(b"permutation_check_commitment_g", commitment_g);
+    transcript.challenge_scalar(b"permutation_check_gamma")
+}
@@ -0,0 +1,159 @@
+use ark_ff::PrimeField;
+
+use super::{divide_by_vanishing, domain_size, evaluate_over_domain, shift, Evaluation};
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
+use crate::common::utils;
+
+/// Commitments to the partial products t(X) and the quotient q(X) with the evaluations t(x), t(w * x), t(w^(n-1)),
+/// f(w * x), g(w * x) and q(x) at the challenge x. g is only opened by the permutation check
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductCheckProof<F: PrimeField, S: PolynomialCommitment<F>> {
+    pub commitment_t: S::Commitment,
+    pub commitment_q: S::Commitment,
+    pub t_x: Evaluation<F, S>,
+    pub t_wx: Evaluation<F, S>,
+    pub t_last: Evaluation<F, S>,
+    pub f_wx: Evaluation<F, S>,
+    pub g_wx: Option<Evaluation<F, S>>,
+    pub q_x: Evaluation<F, S>,
+}
+
+/// Proves Prod{a in H} f(a) = 1
+pub fn prove_product_check<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    polynomial: &Polynomial<F>,
+    commitment: &S::Commitment,
+    transcript: &mut T,
+) -> Result<ProductCheckProof<F, S>, String> {
+    transcript.append_serializable(b"product_check_commitment_f", commitment);
+    prove_product(params, domain, polynomial, None, &F::zero(), transcript)
+}
+
+pub fn verify_product_check<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    commitment: &S::Commitment,
+    proof: &ProductCheckProof<F, S>,
+    transcript: &mut T,
+) -> bool {
+    transcript.append_serializable(b"product_check_commitment_f", commitment);
+    verify_product(
+        params,
+        domain,
+        commitment,
+        None,
+        &F::zero(),
+        proof,
+        transcript,
+    )
+}
+
+/// Proves Prod{a in H} (f(a) + gamma) / (g(a) + gamma) = 1, with g(X) = 1 and gamma = 0 when `g_poly` is None.
+/// The prover interpolates the partial products t(w^i) = Prod{j=0,j=i} (f(w^j) + gamma) / (g(w^j) + gamma) and shows
+/// t(w^(n-1)) = 1 and that t(w * X) * (g(w * X) + gamma) - t(X) * (f(w * X) + gamma) vanishes on H. Going around the
+/// domain from t(w^(n-1)) = 1 with this relation gives back t(w^(n-1)) times the whole product
+pub(crate) fn prove_product<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    f_poly: &Polynomial<F>,
+    g_poly: Option<&Polynomial<F>>,
+    gamma: &F,
+    transcript: &mut T,
+) -> Result<ProductCheckProof<F, S>, String> {
+    let n = domain_size(domain)?;
+    let w = domain[1];
+    let numerator_poly = f_poly.clone() + Polynomial::from_vector_coefficients(vec![*gamma]);
+    let denominator_poly = match g_poly {
+        Some(g_poly) => g_poly.clone() + Polynomial::from_vector_coefficients(vec![*gamma]),
+        None => Polynomial::from_vector_coefficients(vec![F::one()]),
+    };
+
+    let numerators = evaluate_over_domain(&numerator_poly, domain)?;
+    let mut denominators = evaluate_over_domain(&denominator_poly, domain)?;
+    if denominators.iter().any(|d| d.is_zero()) {
+        return Err("Denominator vanishes on the domain".to_string());
+    }
+    ark_ff::batch_inversion(&mut denominators);
+    let mut t_evals = Vec::with_capacity(n);
+    let mut product = F::one();
+    for (numerator, denominator_inverse) in numerators.iter().zip(&denominators) {
+        product *= *numerator * denominator_inverse;
+        t_evals.push(product);
+    }
+    if product != F::one() {
+        return Err("Product over the domain isnt one".to_string());
+    }
+    let t_poly = Polynomial::from_vector_coefficients(utils::ifft(&t_evals, domain)?);
+    let commitment_t = S::commit(params, &t_poly)?;
+
+    let constraint_poly = shift(&t_poly, &w) * shift(&denominator_poly, &w)
+        - t_poly.clone() * shift(&numerator_poly, &w);
+    let (q_poly, r_poly) = divide_by_vanishing(&constraint_poly, n)?;
+    if !r_poly.is_zero() {
+        return Err("Partial products dont satisfy the constraint".to_string());
+    }
+    let commitment_q = S::commit(params, &q_poly)?;
+
+    transcript.append_serializable(b"product_check_commitment_t", &commitment_t);
+    transcript.append_serializable(b"product_check_commitment_q", &commitment_q);
+    let x = transcript.challenge_scalar(b"product_check_x");
+    let wx = w * x;
+
+    Ok(ProductCheckProof {
+        commitment_t,
+        commitment_q,
+        t_x: Evaluation::open(params, &t_poly, &x)?,
+        t_wx: Evaluation::open(params, &t_poly, &wx)?,
+        t_last: Evaluation::open(params, &t_poly, &domain[n - 1])?,
+        f_wx: Evaluation::open(params, f_poly, &wx)?,
+        g_wx: g_poly
+            .map(|g_poly| Evaluation::open(params, g_poly, &wx))
+            .transpose()?,
+        q_x: Evaluation::open(params, &q_poly, &x)?,
+    })
+}
+
+pub(crate) fn verify_product<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    commitment_f: &S::Commitment,
+    commitment_g: Option<&S::Commitment>,
+    gamma: &F,
+    proof: &ProductCheckProof<F, S>,
+    transcript: &mut T,
+) -> bool {
+    let n = match domain_size(domain) {
+        Ok(n) => n,
+        Err(_) => return false,
+    };
+    let w = domain[1];
+    transcript.append_serializable(b"product_check_commitment_t", &proof.commitment_t);
+    transcript.append_serializable(b"product_check_commitment_q", &proof.commitment_q);
+    let x = transcript.challenge_scalar(b"product_check_x");
+    let wx = w * x;
+
+    let denominator = match (commitment_g, &proof.g_wx) {
+        (Some(commitment_g), Some(g_wx)) if g_wx.verify(params, commitment_g, &wx) => {
+            g_wx.value + gamma
+        }
+        (None, None) => F::one(),
+        _ => return false,
+    };
+    if !(proof.t_x.verify(params, &proof.commitment_t, &x)
+        && proof.t_wx.verify(params, &proof.commitment_t, &wx)
+        && proof
+            .t_last
+            .verify(params, &proof.commitment_t, &domain[n - 1])
+        && proof.f_wx.verify(params, commitment_f, &wx)
+        && proof.q_x.verify(params, &proof.commitment_q, &x))
+    {
+        return false;
+    }
+
+    proof.t_last.value == F::one()
+        && proof.t_wx.value * denominator - proof.t_x.value * (proof.f_wx.value + gamma)
+            == proof.q_x.value * (x.pow([n as u64]) - F::one())
+}
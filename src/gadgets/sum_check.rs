@@ -0,0 +1,133 @@
+use ark_ff::PrimeField;
+
+use super::{divide_by_vanishing, domain_size, Evaluation};
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
+
+/// Commitments to g(X), X^(D-n+2) * g(X) and q(X) with their evaluations and f(r) at the challenge r
+#[derive(Debug, Clone, PartialEq)]
+pub struct SumCheckProof<F: PrimeField, S: PolynomialCommitment<F>> {
+    pub commitment_g: S::Commitment,
+    pub commitment_g_shifted: S::Commitment,
+    pub commitment_q: S::Commitment,
+    pub f_r: Evaluation<F, S>,
+    pub g_r: Evaluation<F, S>,
+    pub g_shifted_r: Evaluation<F, S>,
+    pub q_r: Evaluation<F, S>,
+}
+
+/// Univariate sum-check, proves Sum{a in H} f(a) = s. As Sum{a in H} a^i = 0 for 0 < i < n, the sum holds iff
+/// f(X) = X * g(X) + s / n + Z_H(X) * q(X) with deg(g) <= n - 2. The bound on g is enforced by committing to
+/// X^(D-n+2) * g(X), which doesnt fit in the parameters of maximum degree D otherwise
+pub fn prove_sum_check<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    polynomial: &Polynomial<F>,
+    commitment: &S::Commitment,
+    sum: &F,
+    transcript: &mut T,
+) -> Result<SumCheckProof<F, S>, String> {
+    let n = domain_size(domain)?;
+    let degree_shift = degree_shift::<F, S>(params, n)?;
+    let (q_poly, r_poly) = divide_by_vanishing(polynomial, n)?;
+    let constant = r_poly.get_coeff(0).copied().unwrap_or(F::zero());
+    if F::from(n as u64) * constant != *sum {
+        return Err("Claimed sum doesnt match the polynomial".to_string());
+    }
+
+    let g_coeffs: Vec<F> = r_poly.coeffs().iter().skip(1).copied().collect();
+    let g_poly = Polynomial::from_vector_coefficients(g_coeffs.clone());
+    let g_shifted_poly = if g_coeffs.is_empty() {
+        g_poly.clone()
+    } else {
+        Polynomial::from_vector_coefficients([vec![F::zero(); degree_shift], g_coeffs].concat())
+    };
+
+    let commitment_g = S::commit(params, &g_poly)?;
+    let commitment_g_shifted = S::commit(params, &g_shifted_poly)?;
+    let commitment_q = S::commit(params, &q_poly)?;
+    let r = challenge::<F, S, T>(
+        transcript,
+        commitment,
+        sum,
+        &[&commitment_g, &commitment_g_shifted, &commitment_q],
+    );
+
+    Ok(SumCheckProof {
+        commitment_g,
+        commitment_g_shifted,
+        commitment_q,
+        f_r: Evaluation::open(params, polynomial, &r)?,
+        g_r: Evaluation::open(params, &g_poly, &r)?,
+        g_shifted_r: Evaluation::open(params, &g_shifted_poly, &r)?,
+        q_r: Evaluation::open(params, &q_poly, &r)?,
+    })
+}
+
+/// Checks the openings at r, f(r) = r * g(r) + s / n + q(r) * (r^n - 1) and r^(D-n+2) * g(r) = g_shifted(r)
+pub fn verify_sum_check<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    commitment: &S::Commitment,
+    sum: &F,
+    proof: &SumCheckProof<F, S>,
+    transcript: &mut T,
+) -> bool {
+    let n = match domain_size(domain) {
+        Ok(n) => n,
+        Err(_) => return false,
+    };
+    let degree_shift = match degree_shift::<F, S>(params, n) {
+        Ok(degree_shift) => degree_shift,
+        Err(_) => return false,
+    };
+    let r = challenge::<F, S, T>(
+        transcript,
+        commitment,
+        sum,
+        &[
+            &proof.commitment_g,
+            &proof.commitment_g_shifted,
+            &proof.commitment_q,
+        ],
+    );
+    if !(proof.f_r.verify(params, commitment, &r)
+        && proof.g_r.verify(params, &proof.commitment_g, &r)
+        && proof
+            .g_shifted_r
+            .verify(params, &proof.commitment_g_shifted, &r)
+        && proof.q_r.verify(params, &proof.commitment_q, &r))
+    {
+        return false;
+    }
+
+    let n_inverse = F::from(n as u64).inverse().unwrap();
+    proof.f_r.value
+        == r * proof.g_r.value + *sum * n_inverse + proof.q_r.value * (r.pow([n as u64]) - F::one())
+        && proof.g_shifted_r.value == r.pow([degree_shift as u64]) * proof.g_r.value
+}
+
+/// D - (n - 2), the shift that takes a polynomial of degree n - 2 to the maximum degree D
+fn degree_shift<F: PrimeField, S: PolynomialCommitment<F>>(
+    params: &S::Params,
+    n: usize,
+) -> Result<usize, String> {
+    (S::max_degree(params) + 2)
+        .checked_sub(n)
+        .ok_or("Domain is larger than the maximum degree".to_string())
+}
+
+fn challenge<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    transcript: &mut T,
+    commitment: &S::Commitment,
+    sum: &F,
+    commitments: &[&S::Commitment],
+) -> F {
+    transcript.append_serializable(b"sum_check_commitment_f", commitment);
+    transcript.append_scalar(b"sum_check_sum", sum);
+    for commitment in commitments {
+        transcript.append_serializable(b"sum_check_commitment", *commitment);
+    }
+    transcript.challenge_scalar(b"sum_check_r")
+}
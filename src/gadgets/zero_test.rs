@@ -0,0 +1,61 @@
+use ark_ff::PrimeField;
+
+use super::{divide_by_vanishing, domain_size, Evaluation};
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
+
+/// Commitment to q(X) = f(X) / Z_H(X) and the evaluations f(r), q(r) at the challenge r
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZeroTestProof<F: PrimeField, S: PolynomialCommitment<F>> {
+    pub commitment_q: S::Commitment,
+    pub f_r: Evaluation<F, S>,
+    pub q_r: Evaluation<F, S>,
+}
+
+/// Proves that f(X) vanishes on the domain H, i.e. Z_H(X) = X^n - 1 divides f(X). The prover commits to the
+/// quotient q(X) and opens f and q at a random r, where the verifier checks f(r) = q(r) * (r^n - 1)
+pub fn prove_zero_test<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    polynomial: &Polynomial<F>,
+    commitment: &S::Commitment,
+    transcript: &mut T,
+) -> Result<ZeroTestProof<F, S>, String> {
+    let n = domain_size(domain)?;
+    let (q_poly, r_poly) = divide_by_vanishing(polynomial, n)?;
+    if !r_poly.is_zero() {
+        return Err("Polynomial doesnt vanish on the domain".to_string());
+    }
+
+    let commitment_q = S::commit(params, &q_poly)?;
+    transcript.append_serializable(b"zero_test_commitment_f", commitment);
+    transcript.append_serializable(b"zero_test_commitment_q", &commitment_q);
+    let r = transcript.challenge_scalar(b"zero_test_r");
+
+    Ok(ZeroTestProof {
+        commitment_q,
+        f_r: Evaluation::open(params, polynomial, &r)?,
+        q_r: Evaluation::open(params, &q_poly, &r)?,
+    })
+}
+
+pub fn verify_zero_test<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    params: &S::Params,
+    domain: &[F],
+    commitment: &S::Commitment,
+    proof: &ZeroTestProof<F, S>,
+    transcript: &mut T,
+) -> bool {
+    let n = match domain_size(domain) {
+        Ok(n) => n,
+        Err(_) => return false,
+    };
+    transcript.append_serializable(b"zero_test_commitment_f", commitment);
+    transcript.append_serializable(b"zero_test_commitment_q", &proof.commitment_q);
+    let r = transcript.challenge_scalar(b"zero_test_r");
+
+    proof.f_r.verify(params, commitment, &r)
+        && proof.q_r.verify(params, &proof.commitment_q, &r)
+        && proof.f_r.value == proof.q_r.value * (r.pow([n as u64]) - F::one())
+}
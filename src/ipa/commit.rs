@@ -1,5 +1,9 @@
 use ark_ec::CurveGroup;
 use ark_ff::UniformRand;
+use ark_std::cfg_iter;
+use rand::{thread_rng, CryptoRng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use super::setup::GlobalIpaParams;
 use super::utils;
@@ -13,24 +17,39 @@ pub fn commit<P: CurveGroup>(
         return Err(
             "Error committing to Polynomial. Polynomial degree is higher than the number of powers"
                 .to_string(),
-        )?;
-    }
-
-    let mut commitment = P::zero();
-    for (i, coeff) in polynomial.coeffs().iter().enumerate() {
-        commitment += global_params.g_get(i).unwrap().to_owned() * coeff;
+        );
     }
 
-    Ok(commitment)
+    let coeffs = polynomial.coeffs();
+    let bases = P::normalize_batch(&global_params.g_coeffs()[..coeffs.len()]);
+    Ok(P::msm_unchecked(&bases, coeffs))
 }
 
+/// `batch_commit_with_rng` with the simulated verifier challenge rho drawn from `thread_rng`
+#[allow(clippy::type_complexity)]
 pub fn batch_commit<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    polynomials: &[Polynomial<P::ScalarField>],
+    z_poly: &Polynomial<P::ScalarField>,
+    z_i_poly: &[Polynomial<P::ScalarField>],
+) -> Result<(P, Polynomial<P::ScalarField>, Vec<P::ScalarField>), String> {
+    batch_commit_with_rng(
+        global_params,
+        polynomials,
+        z_poly,
+        z_i_poly,
+        &mut thread_rng(),
+    )
+}
+
+#[allow(clippy::type_complexity)]
+pub fn batch_commit_with_rng<P: CurveGroup>(
     global_params: &GlobalIpaParams<P>,
     polynomials: &[Polynomial<P::ScalarField>],
     z_poly: &Polynomial<P::ScalarField>, // z(x) = Product (X-omega), for all omegas in Big Omega
     z_i_poly: &[Polynomial<P::ScalarField>], // z_i(x) = Product (X - omega) for omega_i in Big Omerga - Big Omega i
+    rng: &mut (impl RngCore + CryptoRng),
 ) -> Result<(P, Polynomial<P::ScalarField>, Vec<P::ScalarField>), String> {
-    let mut rng = rand::thread_rng();
     if polynomials.iter().any(|p| p.degree() > global_params.len()) {
         return Err(
             "Error batch committing Polynomials. Some polynomial degree is higher than the number of powers"
@@ -43,30 +62,35 @@ pub fn batch_commit<P: CurveGroup>(
     }
 
     // these coefficients come from verifier in reality
-    let rho = utils::compute_b::<P>(P::ScalarField::rand(&mut rng), polynomials.len());
+    let rho = utils::compute_b::<P>(P::ScalarField::rand(rng), polynomials.len());
 
-    let mut q_poly = Polynomial::<P::ScalarField>::from_vector_coefficients(vec![]);
-    q_poly = polynomials
-        .iter()
-        .enumerate()
-        .fold(q_poly, |acc, (idx, p)| {
-            let mut poly = p.clone() * z_i_poly[idx].clone();
+    // the products rho_i * f_i(X) * z_i(X) are independent, only their sum is sequential
+    let products: Vec<Polynomial<P::ScalarField>> = cfg_iter!(polynomials)
+        .zip(cfg_iter!(z_i_poly))
+        .zip(cfg_iter!(rho))
+        .map(|((p, z_i), rho_i)| {
+            let poly = p.clone() * z_i.clone();
             let poly_coeffs: Vec<P::ScalarField> =
-                poly.coeffs().iter().map(|p| *p * rho[idx]).collect();
-            poly = Polynomial::<P::ScalarField>::from_vector_coefficients(poly_coeffs);
-            poly + acc
-        });
-    let (q_poly, r_poly) = Polynomial::<P::ScalarField>::div_polynomials(q_poly, &z_poly)
+                poly.coeffs().iter().map(|c| *c * rho_i).collect();
+            Polynomial::<P::ScalarField>::from_vector_coefficients(poly_coeffs)
+        })
+        .collect();
+    let q_poly = products.into_iter().fold(
+        Polynomial::<P::ScalarField>::from_vector_coefficients(vec![]),
+        |acc, poly| poly + acc,
+    );
+    let (q_poly, r_poly) = Polynomial::<P::ScalarField>::div_polynomials(q_poly, z_poly)
         .ok_or("Error in polynomial division".to_string())?;
 
     if !r_poly.is_zero() {
-        return Err("quotient polynomial should be divisible by z poly".to_string())?;
+        return Err("quotient polynomial should be divisible by z poly".to_string());
     }
 
-    let mut commitment = P::zero();
-    for (i, coeff) in q_poly.coeffs().iter().enumerate() {
-        commitment += global_params.g_get(i).unwrap().to_owned() * coeff;
-    }
+    let commitment = utils::inner_product_group::<P>(
+        q_poly.coeffs(),
+        global_params.g_coeffs(),
+        q_poly.coeffs().len(),
+    );
 
     Ok((commitment, q_poly, rho))
 }
@@ -0,0 +1,128 @@
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::Zero;
+
+use super::commit::commit as ipa_commit;
+use super::open::inner_product_proof_with_transcript;
+use super::setup::GlobalIpaParams;
+use super::verify::verify_inner_product_with_transcript;
+use crate::common::multilinear::MultilinearPolynomial;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::{Sha256Transcript, Transcript};
+
+/// Hyrax multilinear commitment. The 2^n hypercube evaluations of f are arranged as a 2^(n - n/2) x 2^(n/2) matrix M,
+/// with evaluation index b = row * n_columns + column, and each row is committed as a Pedersen vector commitment
+/// C_i = Sum{j} M_ij * G_j.
+///
+/// Since f(r) = L^T * M * R, where R = eq(., r_column) and L = eq(., r_row) are the equality tables of the first n/2 and
+/// last n - n/2 coordinates of r, the prover sends an IPA proof that t = L^T * M, committed as C_t = Sum{i} L_i * C_i,
+/// satisfies <t, R> = f(r). The IPA challenges are drawn from a transcript over the point, C_t and f(r)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HyraxProof<P: CurveGroup> {
+    pub a_0: P::ScalarField,
+    pub l_r_group: Vec<(P, P)>,
+}
+
+const EVALUATION_LABEL: &[u8] = b"hyrax_evaluation";
+
+/// Commits to a multilinear polynomial given by its evaluations over the hypercube, returning one commitment per row
+pub fn commit<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    evaluations: &[P::ScalarField],
+) -> Result<Vec<P>, String> {
+    if !evaluations.len().is_power_of_two() {
+        return Err("Number of evaluations must be a power of two".to_string());
+    }
+    let num_vars = ark_std::log2(evaluations.len()) as usize;
+    let (_, n_columns) = matrix_dimensions(num_vars);
+    if n_columns > global_params.len() {
+        return Err(
+            "Error committing to Polynomial. Number of columns is higher than the number of generators"
+                .to_string(),
+        );
+    }
+
+    evaluations
+        .chunks(n_columns)
+        .map(|row| {
+            ipa_commit(
+                global_params,
+                &Polynomial::from_vector_coefficients(row.to_vec()),
+            )
+        })
+        .collect()
+}
+
+pub fn evaluation_proof<P: CurveGroup + PrimeGroup>(
+    global_params: &GlobalIpaParams<P>,
+    evaluations: &[P::ScalarField],
+    point: &[P::ScalarField],
+) -> Result<(HyraxProof<P>, P::ScalarField), String> {
+    let num_vars = point.len();
+    if evaluations.len() != 1 << num_vars {
+        return Err("Number of evaluations doesnt match the number of variables".to_string());
+    }
+    let (_, n_columns) = matrix_dimensions(num_vars);
+    let (point_columns, point_rows) = point.split_at(ark_std::log2(n_columns) as usize);
+    let eq_rows = MultilinearPolynomial::eq(point_rows);
+    let eq_columns = MultilinearPolynomial::eq(point_columns);
+
+    // t = L^T * M
+    let mut t = vec![P::ScalarField::zero(); n_columns];
+    for (row, l_i) in evaluations.chunks(n_columns).zip(eq_rows.evaluations()) {
+        for (t_j, m_ij) in t.iter_mut().zip(row) {
+            *t_j += *l_i * m_ij;
+        }
+    }
+
+    let mut transcript = Sha256Transcript::new(EVALUATION_LABEL);
+    transcript.append_scalars(b"hyrax_point", point);
+    let (a_0, _, l_r_group, f_r) = inner_product_proof_with_transcript(
+        global_params,
+        &t,
+        eq_columns.evaluations(),
+        &mut transcript,
+    )?;
+
+    Ok((HyraxProof { a_0, l_r_group }, f_r))
+}
+
+/// The verifier computes C_t = Sum{i} L_i * C_i from the row commitments, replays the transcript and checks the IPA
+/// proof of <t, R> = v
+pub fn verify<P: CurveGroup + PrimeGroup>(
+    global_params: &GlobalIpaParams<P>,
+    row_commitments: &[P],
+    proof: &HyraxProof<P>,
+    point: &[P::ScalarField],
+    v: &P::ScalarField,
+) -> bool {
+    let (n_rows, n_columns) = matrix_dimensions(point.len());
+    if row_commitments.len() != n_rows || proof.l_r_group.len() != point.len() / 2 {
+        return false;
+    }
+    let (point_columns, point_rows) = point.split_at(ark_std::log2(n_columns) as usize);
+    let eq_rows = MultilinearPolynomial::eq(point_rows);
+    let eq_columns = MultilinearPolynomial::eq(point_columns);
+
+    let commitment_t = row_commitments
+        .iter()
+        .zip(eq_rows.evaluations())
+        .fold(P::zero(), |acc, (c_i, l_i)| acc + *c_i * l_i);
+
+    let mut transcript = Sha256Transcript::new(EVALUATION_LABEL);
+    transcript.append_scalars(b"hyrax_point", point);
+    verify_inner_product_with_transcript(
+        global_params,
+        &commitment_t,
+        v,
+        eq_columns.evaluations(),
+        &proof.l_r_group,
+        &proof.a_0,
+        &mut transcript,
+    )
+}
+
+/// (rows, columns) of the evaluation matrix of a polynomial in `num_vars` variables
+pub fn matrix_dimensions(num_vars: usize) -> (usize, usize) {
+    let column_vars = num_vars / 2;
+    (1 << (num_vars - column_vars), 1 << column_vars)
+}
@@ -1,5 +1,114 @@
 pub mod commit;
+pub mod hyrax;
 pub mod open;
+pub mod range_proof;
 pub mod setup;
+pub mod split_accumulation;
 pub mod utils;
 pub mod verify;
+
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_ff::Zero;
+
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::{Sha256Transcript, Transcript};
+use setup::GlobalIpaParams;
+
+/// Inner product argument for two committed vectors, C = <a, G> + <b, H>, proving <a, b> = c
+#[derive(Debug, Clone, PartialEq)]
+pub struct InnerProductProof<P: CurveGroup> {
+    pub l_r_group: Vec<(P, P)>, // L,R vectors
+    pub a_0: P::ScalarField,
+    pub b_0: P::ScalarField,
+}
+
+/// Evaluation proof of `Ipa`, the inner product proof of the coefficients against the powers of the point
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpaEvaluationProof<P: CurveGroup> {
+    pub l_r_group: Vec<(P, P)>,
+    pub a_0: P::ScalarField,
+}
+
+const EVALUATION_LABEL: &[u8] = b"ipa_evaluation";
+
+/// IPA as a `PolynomialCommitment`. Coefficient vectors are padded with zeros to the largest power of two of generators,
+/// which doesnt change the commitment, so any degree up to that power minus one can be opened and every proof has the
+/// same number of rounds. The challenges are drawn from a transcript over the commitment, the point and the evaluation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ipa<P: CurveGroup>(PhantomData<P>);
+
+impl<P: CurveGroup> PolynomialCommitment<P::ScalarField> for Ipa<P> {
+    type Params = GlobalIpaParams<P>;
+    type Commitment = P;
+    type Proof = IpaEvaluationProof<P>;
+
+    fn max_degree(params: &Self::Params) -> usize {
+        match params.len() {
+            0 => 0,
+            len => (1 << len.ilog2()) - 1,
+        }
+    }
+
+    fn commit(
+        params: &Self::Params,
+        polynomial: &Polynomial<P::ScalarField>,
+    ) -> Result<Self::Commitment, String> {
+        if params.is_empty() || polynomial.degree() > Self::max_degree(params) {
+            return Err(
+                "Error committing to Polynomial. Polynomial degree is higher than the maximum degree"
+                    .to_string(),
+            );
+        }
+        commit::commit(params, polynomial)
+    }
+
+    fn open(
+        params: &Self::Params,
+        polynomial: &Polynomial<P::ScalarField>,
+        point: &P::ScalarField,
+    ) -> Result<(Self::Proof, P::ScalarField), String> {
+        if params.is_empty() || polynomial.degree() > Self::max_degree(params) {
+            return Err(
+                "Error opening Polynomial. Polynomial degree is higher than the maximum degree"
+                    .to_string(),
+            );
+        }
+        let n = Self::max_degree(params) + 1;
+        let mut coeffs = polynomial.coeffs().to_vec();
+        coeffs.resize(n, P::ScalarField::zero());
+        let coeffs_b = utils::compute_b::<P>(*point, n);
+        let mut transcript = Sha256Transcript::new(EVALUATION_LABEL);
+        transcript.append_scalar(b"ipa_point", point);
+        let (a_0, _, l_r_group, f_x) =
+            open::inner_product_proof_with_transcript(params, &coeffs, &coeffs_b, &mut transcript)?;
+        Ok((IpaEvaluationProof { l_r_group, a_0 }, f_x))
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        proof: &Self::Proof,
+        point: &P::ScalarField,
+        value: &P::ScalarField,
+    ) -> bool {
+        let n = Self::max_degree(params) + 1;
+        if params.is_empty() || proof.l_r_group.len() != n.ilog2() as usize {
+            return false;
+        }
+        let coeffs_b = utils::compute_b::<P>(*point, n);
+        let mut transcript = Sha256Transcript::new(EVALUATION_LABEL);
+        transcript.append_scalar(b"ipa_point", point);
+        verify::verify_inner_product_with_transcript(
+            params,
+            commitment,
+            value,
+            &coeffs_b,
+            &proof.l_r_group,
+            &proof.a_0,
+            &mut transcript,
+        )
+    }
+}
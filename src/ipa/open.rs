@@ -3,15 +3,39 @@ use ark_ec::{CurveGroup, PrimeGroup};
 use ark_ff::Field;
 use ark_ff::UniformRand;
 use ark_ff::Zero;
-use rand::thread_rng;
+use rand::{thread_rng, CryptoRng, RngCore};
 
 use super::setup::GlobalIpaParams;
+use super::InnerProductProof;
 use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
 
+/// `evaluation_proof_with_rng` with the simulated verifier challenges drawn from `thread_rng`
+#[allow(clippy::type_complexity)]
 pub fn evaluation_proof<P: CurveGroup + PrimeGroup>(
     global_params: &GlobalIpaParams<P>,
     polynomial: &Polynomial<P::ScalarField>,
     x_value: &P::ScalarField,
+) -> Result<
+    (
+        P::ScalarField,
+        P,
+        Vec<(P, P)>,
+        P::ScalarField,
+        Vec<P::ScalarField>,
+        P,
+    ),
+    String,
+> {
+    evaluation_proof_with_rng(global_params, polynomial, x_value, &mut thread_rng())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn evaluation_proof_with_rng<P: CurveGroup + PrimeGroup>(
+    global_params: &GlobalIpaParams<P>,
+    polynomial: &Polynomial<P::ScalarField>,
+    x_value: &P::ScalarField,
+    rng: &mut (impl RngCore + CryptoRng),
 ) -> Result<
     (
         P::ScalarField, // a[0]
@@ -26,52 +50,304 @@ pub fn evaluation_proof<P: CurveGroup + PrimeGroup>(
     ),
     String,
 > {
-    let mut coeffs_b = utils::compute_b::<P>(*x_value, polynomial.degree() + 1);
-    let mut coeffs_a = polynomial.coeffs().to_vec();
-    let mut g_group_elements = global_params.g_coeffs().to_vec();
+    // Padding the coefficients with zeros to a power of two doesnt change the commitment
+    let n = (polynomial.degree() + 1).next_power_of_two();
+    let mut coeffs = polynomial.coeffs().to_vec();
+    coeffs.resize(n, P::ScalarField::zero());
+    let coeffs_b = utils::compute_b::<P>(*x_value, n);
+    let (a_0, g_0, l_r_group, _, u_values, u_group) =
+        inner_product_proof_with_rng(global_params, &coeffs, &coeffs_b, rng)?;
+    let f_x = polynomial.evaluate(x_value);
+
+    Ok((a_0, g_0, l_r_group, f_x, u_values, u_group))
+}
+
+/// `inner_product_proof_with_rng` with the simulated verifier challenges drawn from `thread_rng`
+#[allow(clippy::type_complexity)]
+pub fn inner_product_proof<P: CurveGroup + PrimeGroup>(
+    global_params: &GlobalIpaParams<P>,
+    coeffs_a: &[P::ScalarField],
+    coeffs_b: &[P::ScalarField],
+) -> Result<
+    (
+        P::ScalarField,
+        P,
+        Vec<(P, P)>,
+        P::ScalarField,
+        Vec<P::ScalarField>,
+        P,
+    ),
+    String,
+> {
+    inner_product_proof_with_rng(global_params, coeffs_a, coeffs_b, &mut thread_rng())
+}
+
+/// Proves <a, b> = c for a vector a committed as C = Sum a_i * G_i and a public vector b known by the verifier.
+/// Each round splits a, b and G in halves and sends L = <a_lo, G_hi> + <a_lo, b_hi> * U and R = <a_hi, G_lo> + <a_hi, b_lo> * U,
+/// folding the vectors with challenge u until a single element a[0] remains.
+#[allow(clippy::type_complexity)]
+pub fn inner_product_proof_with_rng<P: CurveGroup + PrimeGroup>(
+    global_params: &GlobalIpaParams<P>,
+    coeffs_a: &[P::ScalarField],
+    coeffs_b: &[P::ScalarField],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<
+    (
+        P::ScalarField, // a[0]
+        P,              // G[0] -> needed for accumulator
+        Vec<(P, P)>,    // L,R vectors
+        P::ScalarField, // inner product <a, b>
+        // Below parameters are not necessary in the
+        // final protocol as they will be computed by prover and verifier
+        // using fiat shamir transform to make protocol non inteactive
+        Vec<P::ScalarField>, // u challenges
+        P,                   // U group element
+    ),
+    String,
+> {
+    let u_group = utils::compute_u_group_element_with_rng::<P>(rng);
+    let (a_0, _, g_0, l_r_group, u_values) = inner_product_rounds(
+        global_params.g_coeffs(),
+        None,
+        coeffs_a,
+        coeffs_b,
+        &u_group,
+        |_, _| P::ScalarField::rand(rng),
+    )?;
+    let inner_product = utils::inner_product_field_element::<P>(coeffs_a, coeffs_b, coeffs_a.len());
+    Ok((a_0, g_0, l_r_group, inner_product, u_values, u_group))
+}
+
+/// `inner_product_proof` made non interactive with a transcript. The commitment C = <a, G> and c = <a, b> are appended
+/// to it, U = w * H for a challenge w, and the challenge u of each round is drawn after appending its L and R. Anything
+/// else b depends on, like the evaluation point, must be appended by the caller beforehand
+#[allow(clippy::type_complexity)]
+pub fn inner_product_proof_with_transcript<
+    P: CurveGroup + PrimeGroup,
+    T: Transcript<P::ScalarField>,
+>(
+    global_params: &GlobalIpaParams<P>,
+    coeffs_a: &[P::ScalarField],
+    coeffs_b: &[P::ScalarField],
+    transcript: &mut T,
+) -> Result<
+    (
+        P::ScalarField, // a[0]
+        P,              // G[0]
+        Vec<(P, P)>,    // L,R vectors
+        P::ScalarField, // inner product <a, b>
+    ),
+    String,
+> {
+    let n = coeffs_a.len();
+    if !n.is_power_of_two() {
+        return Err("Vector length must be a power of two".to_string());
+    }
+    if coeffs_b.len() != n {
+        return Err("Vectors a and b have different lengths".to_string());
+    }
+    if global_params.len() < n {
+        return Err("Vector a is longer than the number of generators".to_string());
+    }
+    let commitment = utils::inner_product_group::<P>(coeffs_a, global_params.g_coeffs(), n);
+    let inner_product = utils::inner_product_field_element::<P>(coeffs_a, coeffs_b, n);
+    transcript.append_serializable(b"ipa_commitment", &commitment);
+    transcript.append_scalar(b"ipa_inner_product", &inner_product);
+    let u_group = global_params.h_get() * transcript.challenge_scalar(b"ipa_w");
+
+    let (a_0, _, g_0, l_r_group, _) = inner_product_rounds(
+        global_params.g_coeffs(),
+        None,
+        coeffs_a,
+        coeffs_b,
+        &u_group,
+        |l_group, r_group| {
+            transcript.append_serializable(b"ipa_l", l_group);
+            transcript.append_serializable(b"ipa_r", r_group);
+            transcript.challenge_scalar(b"ipa_u")
+        },
+    )?;
+    Ok((a_0, g_0, l_r_group, inner_product))
+}
+
+/// Rounds of `inner_product_proof` with the challenge of each round computed by `challenge` from its L and R. Given
+/// generators H for a committed b, as in `inner_product_argument`, L and R also carry <b_hi, H_lo> and <b_lo, H_hi> and
+/// H is folded as H' = u * H_lo + u^-1 * H_hi. Returns a[0], b[0], G[0], the L,R vectors and the challenges
+#[allow(clippy::type_complexity)]
+pub(crate) fn inner_product_rounds<P: CurveGroup>(
+    g_group_elements: &[P],
+    h_group_elements: Option<&[P]>,
+    coeffs_a: &[P::ScalarField],
+    coeffs_b: &[P::ScalarField],
+    u_group: &P,
+    mut challenge: impl FnMut(&P, &P) -> P::ScalarField,
+) -> Result<
+    (
+        P::ScalarField,
+        P::ScalarField,
+        P,
+        Vec<(P, P)>,
+        Vec<P::ScalarField>,
+    ),
+    String,
+> {
     let mut n = coeffs_a.len();
+    if n == 0 {
+        return Err("Vector a is empty".to_string());
+    }
+    if coeffs_b.len() < n {
+        return Err("Vector b is shorter than vector a".to_string());
+    }
+    if g_group_elements.len() < n || h_group_elements.is_some_and(|h| h.len() < n) {
+        return Err("Vector a is longer than the number of generators".to_string());
+    }
+    let mut coeffs_a = coeffs_a.to_vec();
+    let mut coeffs_b = coeffs_b.to_vec();
+    let mut g_group_elements = g_group_elements[..n].to_vec();
+    let mut h_group_elements = h_group_elements.map(|h| h[..n].to_vec());
     let m = ark_std::log2(n) as usize;
-    let mut l_r_group = Vec::with_capacity(ark_std::log2(n) as usize);
-    let u_group = utils::compute_u_group_element::<P>();
-    let u_values = compute_u_field_values::<P>(m);
-    let f_x = polynomial.evaluate(&x_value);
+    let mut l_r_group = Vec::with_capacity(m);
+    let mut u_values = Vec::with_capacity(m);
 
-    for j in 0..m {
+    for _ in 0..m {
         let inner_product_a_g =
             utils::inner_product_group::<P>(&coeffs_a[..n / 2], &g_group_elements[n / 2..], n / 2);
         let inner_product_a_b =
             utils::inner_product_field_element::<P>(&coeffs_a[..n / 2], &coeffs_b[n / 2..], n / 2);
-        let group_inner_product_a_b = u_group * inner_product_a_b;
-        let l_group = inner_product_a_g + group_inner_product_a_b;
+        let group_inner_product_a_b = *u_group * inner_product_a_b;
+        let mut l_group = inner_product_a_g + group_inner_product_a_b;
 
         let inner_product_a_g =
             utils::inner_product_group::<P>(&coeffs_a[n / 2..], &g_group_elements[..n / 2], n / 2);
         let inner_product_a_b =
             utils::inner_product_field_element::<P>(&coeffs_a[n / 2..], &coeffs_b[..n / 2], n / 2);
-        let group_inner_product_a_b = u_group * inner_product_a_b;
-        let r_group = inner_product_a_g + group_inner_product_a_b;
+        let group_inner_product_a_b = *u_group * inner_product_a_b;
+        let mut r_group = inner_product_a_g + group_inner_product_a_b;
+
+        if let Some(h_group_elements) = &h_group_elements {
+            l_group += utils::inner_product_group::<P>(
+                &coeffs_b[n / 2..],
+                &h_group_elements[..n / 2],
+                n / 2,
+            );
+            r_group += utils::inner_product_group::<P>(
+                &coeffs_b[..n / 2],
+                &h_group_elements[n / 2..],
+                n / 2,
+            );
+        }
+
+        let u = challenge(&l_group, &r_group);
         l_r_group.push((l_group, r_group));
+        u_values.push(u);
 
-        let u_inverse = u_values[j].inverse().unwrap();
-        let u = u_values[j];
-        for i in 0..n / 2 {
-            coeffs_a[i] = u * coeffs_a[i] + u_inverse * coeffs_a[n / 2 + i];
-            coeffs_b[i] = u_inverse * coeffs_b[i] + u * coeffs_b[n / 2 + i];
-            g_group_elements[i] = g_group_elements[i] * u_inverse + g_group_elements[n / 2 + i] * u;
+        let u_inverse = u
+            .inverse()
+            .ok_or_else(|| "Challenge is not invertible".to_string())?;
+        utils::fold_field_elements(&mut coeffs_a[..n], u, u_inverse);
+        utils::fold_field_elements(&mut coeffs_b[..n], u_inverse, u);
+        utils::fold_group_elements(&mut g_group_elements[..n], u_inverse, u);
+        if let Some(h_group_elements) = &mut h_group_elements {
+            utils::fold_group_elements(&mut h_group_elements[..n], u, u_inverse);
         }
         n /= 2;
     }
     Ok((
         coeffs_a[0],
+        coeffs_b[0],
         g_group_elements[0],
         l_r_group,
-        f_x,
         u_values,
-        u_group,
     ))
 }
 
+/// Proves <a, b> = c for two vectors committed as C = <a, G> + <b, H>, where unlike `inner_product_proof` the verifier
+/// doesnt know b. The commitment and c are appended to the transcript and the argument runs on
+/// C + c * U' = <a, G> + <b, H> + <a, b> * U' with U' = w * U. Each round sends L = <a_lo, G_hi> + <b_hi, H_lo> + <a_lo, b_hi> * U'
+/// and R = <a_hi, G_lo> + <b_lo, H_hi> + <a_hi, b_lo> * U' and folds a' = u * a_lo + u^-1 * a_hi, b' = u^-1 * b_lo + u * b_hi,
+/// G' = u^-1 * G_lo + u * G_hi and H' = u * H_lo + u^-1 * H_hi until a single element a[0], b[0] remains.
+pub fn inner_product_argument<P: CurveGroup, T: Transcript<P::ScalarField>>(
+    coeffs_a: &[P::ScalarField],
+    coeffs_b: &[P::ScalarField],
+    g_group_elements: &[P],
+    h_group_elements: &[P],
+    u_group: &P,
+    transcript: &mut T,
+) -> Result<
+    (
+        InnerProductProof<P>,
+        P,              // commitment C = <a, G> + <b, H>
+        P::ScalarField, // inner product <a, b>
+    ),
+    String,
+> {
+    let n = coeffs_a.len();
+    if !n.is_power_of_two() {
+        return Err("Vector length must be a power of two".to_string());
+    }
+    if coeffs_b.len() != n {
+        return Err("Vectors a and b have different lengths".to_string());
+    }
+    if g_group_elements.len() < n || h_group_elements.len() < n {
+        return Err("Vectors are longer than the number of generators".to_string());
+    }
+
+    let commitment = utils::inner_product_group::<P>(coeffs_a, g_group_elements, n)
+        + utils::inner_product_group::<P>(coeffs_b, h_group_elements, n);
+    let inner_product = utils::inner_product_field_element::<P>(coeffs_a, coeffs_b, n);
+    transcript.append_serializable(b"ipa_commitment", &commitment);
+    transcript.append_scalar(b"ipa_inner_product", &inner_product);
+    let u_group = *u_group * transcript.challenge_scalar(b"ipa_w");
+
+    let (a_0, b_0, _, l_r_group, _) = inner_product_rounds(
+        g_group_elements,
+        Some(h_group_elements),
+        coeffs_a,
+        coeffs_b,
+        &u_group,
+        |l_group, r_group| {
+            transcript.append_serializable(b"ipa_l", l_group);
+            transcript.append_serializable(b"ipa_r", r_group);
+            transcript.challenge_scalar(b"ipa_u")
+        },
+    )?;
+
+    Ok((
+        InnerProductProof {
+            l_r_group,
+            a_0,
+            b_0,
+        },
+        commitment,
+        inner_product,
+    ))
+}
+
+/// `batch_evaluation_proof_with_rng` with the simulated verifier challenges drawn from `thread_rng`
+#[allow(clippy::type_complexity)]
 pub fn batch_evaluation_proof<P: CurveGroup + PrimeGroup>(
+    global_params: &GlobalIpaParams<P>,
+    polynomials: &[Polynomial<P::ScalarField>],
+    q_poly: &Polynomial<P::ScalarField>,
+    z_poly: &Polynomial<P::ScalarField>,
+    z_i_poly: &[Polynomial<P::ScalarField>],
+    rho: &[P::ScalarField],
+    x_value: &P::ScalarField,
+) -> Result<(P::ScalarField, P, Vec<(P, P)>, Vec<P::ScalarField>, P), String> {
+    batch_evaluation_proof_with_rng(
+        global_params,
+        polynomials,
+        q_poly,
+        z_poly,
+        z_i_poly,
+        rho,
+        x_value,
+        &mut thread_rng(),
+    )
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn batch_evaluation_proof_with_rng<P: CurveGroup + PrimeGroup>(
     global_params: &GlobalIpaParams<P>,
     polynomials: &[Polynomial<P::ScalarField>],
     q_poly: &Polynomial<P::ScalarField>,
@@ -79,6 +355,7 @@ pub fn batch_evaluation_proof<P: CurveGroup + PrimeGroup>(
     z_i_poly: &[Polynomial<P::ScalarField>], // z_i(x) = Product (X - omega) for omega_i in Big Omerga - Big Omega i
     rho: &[P::ScalarField],
     x_value: &P::ScalarField,
+    rng: &mut (impl RngCore + CryptoRng),
 ) -> Result<
     (
         P::ScalarField, // a[0]
@@ -101,10 +378,10 @@ pub fn batch_evaluation_proof<P: CurveGroup + PrimeGroup>(
         .map(|(idx, v)| *v * rho[idx])
         .collect();
 
-    let g_poly = compute_g_poly(&polynomials, &q_poly, &z_evaluation, &scaled_zi_evaluations);
+    let g_poly = compute_g_poly(polynomials, q_poly, &z_evaluation, &scaled_zi_evaluations);
 
     let (a_m, g_m, l_r_group, f_x, u_values, u_group_element) =
-        evaluation_proof(&global_params, &g_poly, x_value)?;
+        evaluation_proof_with_rng(global_params, &g_poly, x_value, rng)?;
 
     if f_x != P::ScalarField::zero() {
         return Err("g_poly should evaluate to zero at point x".to_string());
@@ -113,15 +390,6 @@ pub fn batch_evaluation_proof<P: CurveGroup + PrimeGroup>(
     Ok((a_m, g_m, l_r_group, u_values, u_group_element))
 }
 
-fn compute_u_field_values<P: CurveGroup>(n: usize) -> Vec<P::ScalarField> {
-    let mut rng = thread_rng();
-    let mut u = Vec::with_capacity(n);
-    for _ in 0..n {
-        u.push(P::ScalarField::rand(&mut rng));
-    }
-    u
-}
-
 fn compute_g_poly<F: Field>(
     polynomials: &[Polynomial<F>],
     q_poly: &Polynomial<F>,
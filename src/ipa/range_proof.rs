@@ -0,0 +1,295 @@
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::{Field, One, UniformRand};
+use rand::{thread_rng, CryptoRng, RngCore};
+
+use super::open::inner_product_argument;
+use super::setup::GlobalIpaParams;
+use super::utils;
+use super::verify::verify_inner_product_argument;
+use super::InnerProductProof;
+use crate::common::transcript::Transcript;
+
+/// Aggregated Bulletproofs range proof that m Pedersen committed values V_j = v_j * B + gamma_j * B~ lie in [0, 2^n).
+/// The bit vectors a_L of the values and a_R = a_L - 1 are committed in A = alpha * B~ + <a_L, G> + <a_R, H>, and the
+/// constraints a_L o a_R = 0, a_L - a_R = 1 and <a_L, 2^n> = v_j are reduced to a single inner product
+/// t(x) = <l(x), r(x)>, proven with an inner product argument over G and H' = y^-i * H.
+///
+/// Generators are taken from the IPA setup: G = g[0..nm], H = g[nm..2nm], B~ = g[2nm], U = g[2nm + 1] and B = h
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeProof<P: CurveGroup> {
+    pub a: P,
+    pub s: P,
+    pub t_1: P,
+    pub t_2: P,
+    pub tau_x: P::ScalarField,
+    pub mu: P::ScalarField,
+    pub t_hat: P::ScalarField,
+    pub inner_product_proof: InnerProductProof<P>,
+}
+
+/// Pedersen commitment V = v * B + gamma * B~ to a value of a range proof over `n_values` values of `n_bits` bits
+pub fn commit_value<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    value: u64,
+    blinding: &P::ScalarField,
+    n_bits: usize,
+    n_values: usize,
+) -> Result<P, String> {
+    let generators = RangeGenerators::new(global_params, n_bits, n_values)?;
+    Ok(generators.b * P::ScalarField::from(value) + generators.b_blinding * blinding)
+}
+
+/// `prove_with_rng` with the blinding factors drawn from `thread_rng`
+pub fn prove<P: CurveGroup + PrimeGroup, T: Transcript<P::ScalarField>>(
+    global_params: &GlobalIpaParams<P>,
+    values: &[u64],
+    blindings: &[P::ScalarField],
+    n_bits: usize,
+    transcript: &mut T,
+) -> Result<(RangeProof<P>, Vec<P>), String> {
+    prove_with_rng(
+        global_params,
+        values,
+        blindings,
+        n_bits,
+        transcript,
+        &mut thread_rng(),
+    )
+}
+
+/// Proves that every value lies in [0, 2^n_bits), returning the proof and the value commitments
+pub fn prove_with_rng<P: CurveGroup + PrimeGroup, T: Transcript<P::ScalarField>>(
+    global_params: &GlobalIpaParams<P>,
+    values: &[u64],
+    blindings: &[P::ScalarField],
+    n_bits: usize,
+    transcript: &mut T,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<(RangeProof<P>, Vec<P>), String> {
+    let m = values.len();
+    if blindings.len() != m {
+        return Err("Number of blindings doesnt match the number of values".to_string());
+    }
+    if n_bits < 64 && values.iter().any(|v| v >> n_bits != 0) {
+        return Err("Value out of range".to_string());
+    }
+    let generators = RangeGenerators::new(global_params, n_bits, m)?;
+    let nm = n_bits * m;
+
+    let commitments: Vec<P> = values
+        .iter()
+        .zip(blindings)
+        .map(|(v, gamma)| generators.b * P::ScalarField::from(*v) + generators.b_blinding * gamma)
+        .collect();
+    for commitment in &commitments {
+        transcript.append_serializable(b"V", commitment);
+    }
+
+    let a_l: Vec<P::ScalarField> = values
+        .iter()
+        .flat_map(|v| (0..n_bits).map(move |i| P::ScalarField::from((v >> i) & 1)))
+        .collect();
+    let a_r: Vec<P::ScalarField> = a_l.iter().map(|a| *a - P::ScalarField::one()).collect();
+    let alpha = P::ScalarField::rand(rng);
+    let a = generators.b_blinding * alpha
+        + utils::inner_product_group::<P>(&a_l, &generators.g, nm)
+        + utils::inner_product_group::<P>(&a_r, &generators.h, nm);
+
+    let s_l: Vec<P::ScalarField> = (0..nm).map(|_| P::ScalarField::rand(rng)).collect();
+    let s_r: Vec<P::ScalarField> = (0..nm).map(|_| P::ScalarField::rand(rng)).collect();
+    let rho = P::ScalarField::rand(rng);
+    let s = generators.b_blinding * rho
+        + utils::inner_product_group::<P>(&s_l, &generators.g, nm)
+        + utils::inner_product_group::<P>(&s_r, &generators.h, nm);
+
+    transcript.append_serializable(b"A", &a);
+    transcript.append_serializable(b"S", &s);
+    let y = transcript.challenge_scalar(b"y");
+    let z = transcript.challenge_scalar(b"z");
+
+    // l(X) = a_L - z * 1 + s_L * X
+    // r(X) = y^nm o (a_R + z * 1 + s_R * X) + Sum{j} z^(1+j) * (0^((j-1)n) || 2^n || 0^((m-j)n))
+    let powers_y = powers(&y, nm);
+    let z_2n = z_powers_of_two(&z, n_bits, m);
+    let l_0: Vec<P::ScalarField> = a_l.iter().map(|a| *a - z).collect();
+    let r_0: Vec<P::ScalarField> = (0..nm)
+        .map(|i| powers_y[i] * (a_r[i] + z) + z_2n[i])
+        .collect();
+    let r_1: Vec<P::ScalarField> = (0..nm).map(|i| powers_y[i] * s_r[i]).collect();
+
+    // t(X) = <l(X), r(X)> = t_0 + t_1 * X + t_2 * X^2
+    let t_1 = utils::inner_product_field_element::<P>(&l_0, &r_1, nm)
+        + utils::inner_product_field_element::<P>(&s_l, &r_0, nm);
+    let t_2 = utils::inner_product_field_element::<P>(&s_l, &r_1, nm);
+    let tau_1 = P::ScalarField::rand(rng);
+    let tau_2 = P::ScalarField::rand(rng);
+    let t_1_group = generators.b * t_1 + generators.b_blinding * tau_1;
+    let t_2_group = generators.b * t_2 + generators.b_blinding * tau_2;
+
+    transcript.append_serializable(b"T1", &t_1_group);
+    transcript.append_serializable(b"T2", &t_2_group);
+    let x = transcript.challenge_scalar(b"x");
+
+    let l: Vec<P::ScalarField> = (0..nm).map(|i| l_0[i] + s_l[i] * x).collect();
+    let r: Vec<P::ScalarField> = (0..nm).map(|i| r_0[i] + r_1[i] * x).collect();
+    let t_hat = utils::inner_product_field_element::<P>(&l, &r, nm);
+    let mut tau_x = tau_2 * x * x + tau_1 * x;
+    let mut z_j = z;
+    for gamma in blindings {
+        z_j *= z;
+        tau_x += z_j * gamma;
+    }
+    let mu = alpha + rho * x;
+
+    transcript.append_scalars(b"tau_x_mu", &[tau_x, mu]);
+
+    let h_prime = scaled_h(&generators.h, &y);
+    let (inner_product_proof, _, _) =
+        inner_product_argument(&l, &r, &generators.g, &h_prime, &generators.u, transcript)?;
+
+    Ok((
+        RangeProof {
+            a,
+            s,
+            t_1: t_1_group,
+            t_2: t_2_group,
+            tau_x,
+            mu,
+            t_hat,
+            inner_product_proof,
+        },
+        commitments,
+    ))
+}
+
+/// The verifier checks t_hat * B + tau_x * B~ = Sum{j} z^(1+j) * V_j + delta(y, z) * B + x * T_1 + x^2 * T_2 and that
+/// P = A + x * S - z * <1, G> + <z * y^nm + z^(1+j) * 2^n, H'> - mu * B~ opens to l, r with <l, r> = t_hat
+pub fn verify<P: CurveGroup + PrimeGroup, T: Transcript<P::ScalarField>>(
+    global_params: &GlobalIpaParams<P>,
+    commitments: &[P],
+    proof: &RangeProof<P>,
+    n_bits: usize,
+    transcript: &mut T,
+) -> bool {
+    let m = commitments.len();
+    let generators = match RangeGenerators::new(global_params, n_bits, m) {
+        Ok(generators) => generators,
+        Err(_) => return false,
+    };
+    let nm = n_bits * m;
+
+    for commitment in commitments {
+        transcript.append_serializable(b"V", commitment);
+    }
+    transcript.append_serializable(b"A", &proof.a);
+    transcript.append_serializable(b"S", &proof.s);
+    let y = transcript.challenge_scalar(b"y");
+    let z = transcript.challenge_scalar(b"z");
+    transcript.append_serializable(b"T1", &proof.t_1);
+    transcript.append_serializable(b"T2", &proof.t_2);
+    let x = transcript.challenge_scalar(b"x");
+    transcript.append_scalars(b"tau_x_mu", &[proof.tau_x, proof.mu]);
+
+    // delta(y, z) = (z - z^2) * <1, y^nm> - Sum{j} z^(j+2) * <1, 2^n>
+    let powers_y = powers(&y, nm);
+    let sum_y: P::ScalarField = powers_y.iter().sum();
+    let sum_2n = P::ScalarField::from(2u64).pow([n_bits as u64]) - P::ScalarField::one();
+    let mut delta = (z - z * z) * sum_y;
+    let mut commitments_v = P::zero();
+    let mut z_j = z;
+    for commitment in commitments {
+        z_j *= z;
+        delta -= z_j * z * sum_2n;
+        commitments_v += *commitment * z_j;
+    }
+
+    let lhs = generators.b * proof.t_hat + generators.b_blinding * proof.tau_x;
+    let rhs = commitments_v + generators.b * delta + proof.t_1 * x + proof.t_2 * (x * x);
+    if lhs != rhs {
+        return false;
+    }
+
+    let h_prime = scaled_h(&generators.h, &y);
+    let z_2n = z_powers_of_two(&z, n_bits, m);
+    let h_scalars: Vec<P::ScalarField> = (0..nm).map(|i| z * powers_y[i] + z_2n[i]).collect();
+    let sum_g: P = generators.g.iter().copied().sum();
+    let p = proof.a + proof.s * x - sum_g * z
+        + utils::inner_product_group::<P>(&h_scalars, &h_prime, nm)
+        - generators.b_blinding * proof.mu;
+
+    verify_inner_product_argument(
+        &generators.g,
+        &h_prime,
+        &generators.u,
+        &p,
+        &proof.t_hat,
+        nm,
+        &proof.inner_product_proof,
+        transcript,
+    )
+}
+
+struct RangeGenerators<P: CurveGroup> {
+    g: Vec<P>,
+    h: Vec<P>,
+    b: P,
+    b_blinding: P,
+    u: P,
+}
+
+impl<P: CurveGroup> RangeGenerators<P> {
+    fn new(
+        global_params: &GlobalIpaParams<P>,
+        n_bits: usize,
+        n_values: usize,
+    ) -> Result<Self, String> {
+        if !n_bits.is_power_of_two() || n_bits > 64 {
+            return Err("Number of bits must be a power of two up to 64".to_string());
+        }
+        if !n_values.is_power_of_two() {
+            return Err("Number of values must be a power of two".to_string());
+        }
+        let nm = n_bits * n_values;
+        if global_params.len() < 2 * nm + 2 {
+            return Err("Not enough generators for the range proof".to_string());
+        }
+        Ok(Self {
+            g: global_params.g[..nm].to_vec(),
+            h: global_params.g[nm..2 * nm].to_vec(),
+            b: global_params.h_get(),
+            b_blinding: global_params.g[2 * nm],
+            u: global_params.g[2 * nm + 1],
+        })
+    }
+}
+
+/// H'_i = y^-i * H_i
+fn scaled_h<P: CurveGroup>(h: &[P], y: &P::ScalarField) -> Vec<P> {
+    let y_inverse = y.inverse().unwrap();
+    h.iter()
+        .zip(powers(&y_inverse, h.len()))
+        .map(|(h_i, y_i)| *h_i * y_i)
+        .collect()
+}
+
+/// Sum{j} z^(1+j) * (0^((j-1)n) || 2^n || 0^((m-j)n)) for j = 1..m
+fn z_powers_of_two<F: Field>(z: &F, n_bits: usize, n_values: usize) -> Vec<F> {
+    let powers_2 = powers(&F::from(2u64), n_bits);
+    let mut result = Vec::with_capacity(n_bits * n_values);
+    let mut z_j = *z;
+    for _ in 0..n_values {
+        z_j *= z;
+        result.extend(powers_2.iter().map(|p| *p * z_j));
+    }
+    result
+}
+
+fn powers<F: Field>(x: &F, n: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(n);
+    let mut current = F::one();
+    for _ in 0..n {
+        result.push(current);
+        current *= x;
+    }
+    result
+}
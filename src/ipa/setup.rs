@@ -1,7 +1,9 @@
 use ark_ec::AffineRepr;
 use ark_ec::CurveGroup;
-use ark_std::UniformRand;
-use rand::thread_rng;
+use ark_std::{cfg_iter, UniformRand};
+use rand::{thread_rng, CryptoRng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[derive(Debug, PartialEq)]
 pub struct GlobalIpaParams<P: CurveGroup> {
@@ -11,14 +13,19 @@ pub struct GlobalIpaParams<P: CurveGroup> {
 
 impl<P: CurveGroup> GlobalIpaParams<P> {
     pub fn new(max_degree: usize) -> Self {
-        ipa_setup(max_degree)
+        Self::new_with_rng(max_degree, &mut thread_rng())
+    }
+
+    /// Setup with the generators drawn from `rng`, reproducible from a seeded rng
+    pub fn new_with_rng(max_degree: usize, rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        ipa_setup(max_degree, rng)
     }
 
     pub fn len(&self) -> usize {
         self.g.len()
     }
 
-    pub fn g_iter(&self) -> std::slice::Iter<P> {
+    pub fn g_iter(&self) -> std::slice::Iter<'_, P> {
         self.g.iter()
     }
 
@@ -41,19 +48,19 @@ impl<P: CurveGroup> GlobalIpaParams<P> {
     }
 }
 
-fn ipa_setup<P: CurveGroup>(max_degree: usize) -> GlobalIpaParams<P> {
-    let mut rng = thread_rng();
+fn ipa_setup<P: CurveGroup>(
+    max_degree: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> GlobalIpaParams<P> {
+    let r = P::ScalarField::rand(rng);
 
-    let r = P::ScalarField::rand(&mut rng);
-
-    let mut g = Vec::with_capacity(max_degree + 1);
     let g1_generator = P::Affine::generator();
     let h = g1_generator * r;
 
-    for _ in 0..=max_degree {
-        let s = P::ScalarField::rand(&mut rng);
-        g.push(g1_generator * s);
-    }
+    let scalars: Vec<P::ScalarField> = (0..=max_degree)
+        .map(|_| P::ScalarField::rand(rng))
+        .collect();
+    let g = cfg_iter!(scalars).map(|s| g1_generator * s).collect();
 
     GlobalIpaParams { g, h }
 }
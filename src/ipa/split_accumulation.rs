@@ -0,0 +1,194 @@
+use ark_ec::CurveGroup;
+use ark_ff::{Field, One, Zero};
+
+use super::commit::commit;
+use super::setup::GlobalIpaParams;
+use crate::common::polynomial::{Polynomial, SparsePolynomial};
+use crate::common::transcript::Transcript;
+
+/// Evaluation claim p(z) = v for the polynomial committed in C = <p, G>
+#[derive(Debug, Clone, PartialEq)]
+pub struct PedersenClaim<P: CurveGroup> {
+    pub commitment: P,
+    pub z_value: P::ScalarField,
+    pub v_value: P::ScalarField,
+}
+
+/// Split accumulator (Bünz-Chiesa-Lin-Mishra-Spooner) for Pedersen commitments. The instance is a claim and the
+/// witness the full committed polynomial, so accumulating doesnt need any opening proof and only the decider does
+/// linear work. Fresh claims and accumulators have the same form and are accumulated alike
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitAccumulator<P: CurveGroup> {
+    pub instance: PedersenClaim<P>,
+    pub witness: Polynomial<P::ScalarField>,
+}
+
+/// Commitment C_w to w(X) = Sum{i} alpha^i * (p_i(X) - v_i) / (X - z_i) and the evaluations p_i(r), w(r)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitAccumulationProof<P: CurveGroup> {
+    pub commitment_w: P,
+    pub evaluations: Vec<P::ScalarField>,
+    pub w_r: P::ScalarField,
+}
+
+/// Claim p(z) = v with its witness, the input of the accumulation prover
+pub fn claim<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    polynomial: &Polynomial<P::ScalarField>,
+    z_value: &P::ScalarField,
+) -> Result<SplitAccumulator<P>, String> {
+    Ok(SplitAccumulator {
+        instance: PedersenClaim {
+            commitment: commit(global_params, polynomial)?,
+            z_value: *z_value,
+            v_value: polynomial.evaluate(z_value),
+        },
+        witness: polynomial.clone(),
+    })
+}
+
+/// Accumulates the claims p_i(z_i) = v_i into a single claim at a random point r. The prover commits to
+/// w(X) = Sum{i} alpha^i * (p_i(X) - v_i) / (X - z_i), which is a polynomial only if every claim holds, and sends
+/// y_i = p_i(r) and y_w = w(r). The new claim is C = Sum{i} beta^i * C_i + beta^m * C_w, z = r, v = Sum{i} beta^i * y_i + beta^m * y_w,
+/// with witness Sum{i} beta^i * p_i(X) + beta^m * w(X)
+pub fn accumulate<P: CurveGroup, T: Transcript<P::ScalarField>>(
+    global_params: &GlobalIpaParams<P>,
+    inputs: &[SplitAccumulator<P>],
+    transcript: &mut T,
+) -> Result<(SplitAccumulator<P>, SplitAccumulationProof<P>), String> {
+    if inputs.is_empty() {
+        return Err("No claims to accumulate".to_string());
+    }
+    for input in inputs {
+        append_claim(transcript, &input.instance);
+    }
+    let alpha = transcript.challenge_scalar(b"split_acc_alpha");
+
+    let mut w_poly = Polynomial::from_vector_coefficients(vec![P::ScalarField::zero()]);
+    let mut alpha_power = P::ScalarField::one();
+    for input in inputs {
+        let numerator_poly = input.witness.clone()
+            - Polynomial::from_vector_coefficients(vec![input.instance.v_value]);
+        let denominator_poly = SparsePolynomial::monomial_from_coefficient(input.instance.z_value);
+        let (q_poly, r_poly) = Polynomial::div_sparse(numerator_poly, &denominator_poly)
+            .ok_or("Error in polynomial division")?;
+        if !r_poly.is_zero() {
+            return Err("Claimed evaluation doesnt match the witness".to_string());
+        }
+        w_poly = w_poly + scale(&q_poly, &alpha_power);
+        alpha_power *= alpha;
+    }
+    let commitment_w = commit(global_params, &w_poly)?;
+    transcript.append_serializable(b"split_acc_commitment_w", &commitment_w);
+    let r = transcript.challenge_scalar(b"split_acc_r");
+
+    let evaluations: Vec<P::ScalarField> = inputs
+        .iter()
+        .map(|input| input.witness.evaluate(&r))
+        .collect();
+    let w_r = w_poly.evaluate(&r);
+    transcript.append_scalars(b"split_acc_evaluations", &evaluations);
+    transcript.append_scalar(b"split_acc_w_r", &w_r);
+    let beta = transcript.challenge_scalar(b"split_acc_beta");
+
+    let mut witness = Polynomial::from_vector_coefficients(vec![P::ScalarField::zero()]);
+    let mut beta_power = P::ScalarField::one();
+    for input in inputs {
+        witness = witness + scale(&input.witness, &beta_power);
+        beta_power *= beta;
+    }
+    witness = witness + scale(&w_poly, &beta_power);
+
+    let proof = SplitAccumulationProof {
+        commitment_w,
+        evaluations,
+        w_r,
+    };
+    let instances: Vec<PedersenClaim<P>> =
+        inputs.iter().map(|input| input.instance.clone()).collect();
+    let instance = combine(&instances, &proof, &r, &beta);
+    Ok((SplitAccumulator { instance, witness }, proof))
+}
+
+/// Checks that `accumulator` is the accumulation of the claims with m + 1 scalar multiplications, and
+/// Sum{i} alpha^i * (y_i - v_i) / (r - z_i) = y_w, which binds w to the claims
+pub fn verify_accumulation<P: CurveGroup, T: Transcript<P::ScalarField>>(
+    inputs: &[PedersenClaim<P>],
+    accumulator: &PedersenClaim<P>,
+    proof: &SplitAccumulationProof<P>,
+    transcript: &mut T,
+) -> bool {
+    if inputs.is_empty() || proof.evaluations.len() != inputs.len() {
+        return false;
+    }
+    for input in inputs {
+        append_claim(transcript, input);
+    }
+    let alpha = transcript.challenge_scalar(b"split_acc_alpha");
+    transcript.append_serializable(b"split_acc_commitment_w", &proof.commitment_w);
+    let r = transcript.challenge_scalar(b"split_acc_r");
+    transcript.append_scalars(b"split_acc_evaluations", &proof.evaluations);
+    transcript.append_scalar(b"split_acc_w_r", &proof.w_r);
+    let beta = transcript.challenge_scalar(b"split_acc_beta");
+
+    let mut w_r = P::ScalarField::zero();
+    let mut alpha_power = P::ScalarField::one();
+    for (input, y) in inputs.iter().zip(&proof.evaluations) {
+        let denominator = match (r - input.z_value).inverse() {
+            Some(inverse) => inverse,
+            None => return false,
+        };
+        w_r += alpha_power * (*y - input.v_value) * denominator;
+        alpha_power *= alpha;
+    }
+
+    w_r == proof.w_r && combine(inputs, proof, &r, &beta) == *accumulator
+}
+
+/// Decider, checks the accumulated claim against its witness: C = <p, G> and p(z) = v
+pub fn decide<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    accumulator: &SplitAccumulator<P>,
+) -> bool {
+    let instance = &accumulator.instance;
+    match commit(global_params, &accumulator.witness) {
+        Ok(commitment) => {
+            commitment == instance.commitment
+                && accumulator.witness.evaluate(&instance.z_value) == instance.v_value
+        }
+        Err(_) => false,
+    }
+}
+
+fn combine<P: CurveGroup>(
+    inputs: &[PedersenClaim<P>],
+    proof: &SplitAccumulationProof<P>,
+    r: &P::ScalarField,
+    beta: &P::ScalarField,
+) -> PedersenClaim<P> {
+    let mut commitment = P::zero();
+    let mut v_value = P::ScalarField::zero();
+    let mut beta_power = P::ScalarField::one();
+    for (input, y) in inputs.iter().zip(&proof.evaluations) {
+        commitment += input.commitment * beta_power;
+        v_value += beta_power * y;
+        beta_power *= beta;
+    }
+    PedersenClaim {
+        commitment: commitment + proof.commitment_w * beta_power,
+        z_value: *r,
+        v_value: v_value + beta_power * proof.w_r,
+    }
+}
+
+fn append_claim<P: CurveGroup, T: Transcript<P::ScalarField>>(
+    transcript: &mut T,
+    claim: &PedersenClaim<P>,
+) {
+    transcript.append_serializable(b"split_acc_commitment", &claim.commitment);
+    transcript.append_scalars(b"split_acc_claim", &[claim.z_value, claim.v_value]);
+}
+
+fn scale<F: Field>(polynomial: &Polynomial<F>, factor: &F) -> Polynomial<F> {
+    Polynomial::from_vector_coefficients(polynomial.coeffs().iter().map(|c| *c * factor).collect())
+}
@@ -1,14 +1,14 @@
 use ark_ec::CurveGroup;
-use ark_ff::One;
-use ark_ff::Zero;
-use rand::thread_rng;
+use ark_ff::{Field, One};
+use ark_std::{cfg_iter, cfg_iter_mut};
+use rand::{thread_rng, CryptoRng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 pub fn compute_b<P: CurveGroup>(x_value: P::ScalarField, n: usize) -> Vec<P::ScalarField> {
     let mut b = Vec::with_capacity(n);
-    let mut powers_x = x_value * x_value;
-    b.push(P::ScalarField::one());
-    b.push(x_value);
-    for _ in 2..n {
+    let mut powers_x = P::ScalarField::one();
+    for _ in 0..n {
         b.push(powers_x);
         powers_x *= x_value;
     }
@@ -16,8 +16,11 @@ pub fn compute_b<P: CurveGroup>(x_value: P::ScalarField, n: usize) -> Vec<P::Sca
 }
 
 pub fn compute_u_group_element<P: CurveGroup>() -> P {
-    let mut rng = thread_rng();
-    P::rand(&mut rng)
+    compute_u_group_element_with_rng(&mut thread_rng())
+}
+
+pub fn compute_u_group_element_with_rng<P: CurveGroup>(rng: &mut (impl RngCore + CryptoRng)) -> P {
+    P::rand(rng)
 }
 
 pub fn inner_product_group<P: CurveGroup>(
@@ -25,20 +28,36 @@ pub fn inner_product_group<P: CurveGroup>(
     coeffs_b: &[P],
     n_elems: usize,
 ) -> P {
-    let mut group_elem = P::zero();
-    for i in 0..n_elems {
-        group_elem += coeffs_b[i] * coeffs_a[i];
-    }
-    group_elem
+    let bases = P::normalize_batch(&coeffs_b[..n_elems]);
+    P::msm_unchecked(&bases, &coeffs_a[..n_elems])
 }
 pub fn inner_product_field_element<P: CurveGroup>(
     coeffs_a: &[P::ScalarField],
     coeffs_b: &[P::ScalarField],
     n_elems: usize,
 ) -> P::ScalarField {
-    let mut field_elem = P::ScalarField::zero();
-    for i in 0..n_elems {
-        field_elem += coeffs_a[i] * coeffs_b[i];
-    }
-    field_elem
+    cfg_iter!(coeffs_a[..n_elems])
+        .zip(cfg_iter!(coeffs_b[..n_elems]))
+        .map(|(a, b)| *a * b)
+        .sum()
+}
+
+/// Folds the two halves of a vector of even length into its first half, v'_i = left * v_i + right * v_(n/2+i)
+pub fn fold_field_elements<F: Field>(values: &mut [F], left: F, right: F) {
+    let (lo, hi) = values.split_at_mut(values.len() / 2);
+    cfg_iter_mut!(lo)
+        .zip(cfg_iter!(hi))
+        .for_each(|(v_lo, v_hi)| *v_lo = left * *v_lo + right * *v_hi);
+}
+
+/// Folds the two halves of a vector of group elements of even length into its first half, as `fold_field_elements`
+pub fn fold_group_elements<P: CurveGroup>(
+    values: &mut [P],
+    left: P::ScalarField,
+    right: P::ScalarField,
+) {
+    let (lo, hi) = values.split_at_mut(values.len() / 2);
+    cfg_iter_mut!(lo)
+        .zip(cfg_iter!(hi))
+        .for_each(|(v_lo, v_hi)| *v_lo = *v_lo * left + *v_hi * right);
 }
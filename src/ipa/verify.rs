@@ -1,13 +1,15 @@
-#![allow(dead_code)]
-
 use super::setup::GlobalIpaParams;
 use super::utils;
+use super::InnerProductProof;
 use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
 use ark_ec::{CurveGroup, PrimeGroup};
 use ark_ff::Field;
-#[allow(unused_imports)]
-use ark_ff::One;
+use ark_std::{cfg_into_iter, cfg_iter};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
+#[allow(clippy::too_many_arguments)]
 pub fn verify<P: CurveGroup + PrimeGroup>(
     global_params: &GlobalIpaParams<P>,
     commitment: &P,
@@ -19,34 +21,157 @@ pub fn verify<P: CurveGroup + PrimeGroup>(
     u_values: &[P::ScalarField],
     u_group: &P,
 ) -> bool {
-    let m = l_r_group.len();
-    let n = 1 << m;
-    let mut c = *commitment + *u_group * f_x;
-    let s = compute_s(&u_values, n);
-
-    for i in 0..m {
-        let u = u_values[i];
-        let u_inverse = u.inverse().unwrap();
-        let (l_group, r_group) = l_r_group[i];
-        c += l_group * (u * u) + r_group * (u_inverse * u_inverse);
+    if global_params.is_empty() || l_r_group.len() > global_params.len().ilog2() as usize {
+        return false;
     }
+    let n = 1 << l_r_group.len();
+    let b_coeffs = utils::compute_b::<P>(*x_value, n);
+    verify_inner_product(
+        global_params,
+        commitment,
+        f_x,
+        &b_coeffs,
+        l_r_group,
+        a_0,
+        g_0,
+        u_values,
+        u_group,
+    )
+}
 
-    // We can compute b0 as below as well
+/// Verifies <a, b> = c for the vector a committed in `commitment` and the public vector b, whose length n must be a
+/// power of two with log2(n) rounds in the proof.
+/// The verifier folds the commitment C' = C + c * U + Sum{i} (u_i^2 * L_i + u_i^-2 * R_i) and accepts if
+/// C' = a[0] * (G[0] + b[0] * U), where G[0] = <s, G> and b[0] = <s, b>
+#[allow(clippy::too_many_arguments)]
+pub fn verify_inner_product<P: CurveGroup + PrimeGroup>(
+    global_params: &GlobalIpaParams<P>,
+    commitment: &P,
+    inner_product: &P::ScalarField,
+    coeffs_b: &[P::ScalarField],
+    l_r_group: &[(P, P)],
+    a_0: &P::ScalarField,
+    g_0: &Option<P>,
+    u_values: &[P::ScalarField],
+    u_group: &P,
+) -> bool {
+    let n = coeffs_b.len();
+    let m = l_r_group.len();
+    if !n.is_power_of_two()
+        || m != n.ilog2() as usize
+        || u_values.len() != m
+        || (g_0.is_none() && global_params.len() < n)
+    {
+        return false;
+    }
+    let mut c = *commitment + *u_group * inner_product;
 
-    // let mut g = Polynomial::<P::ScalarField>::from_vector_coefficients(vec![P::ScalarField::one()]);
-    //for i in 0..m {
-    // let binomial = compute_binomial(u_values[m - i - 1], 1 << i);
-    //g = g * binomial;
-    //}
-    //let b_0_field = g.evaluate(x_value);
+    for ((l_group, r_group), u) in l_r_group.iter().zip(u_values) {
+        let u_inverse = match u.inverse() {
+            Some(u_inverse) => u_inverse,
+            None => return false,
+        };
+        c += *l_group * (*u * u) + *r_group * (u_inverse * u_inverse);
+    }
 
-    let b_coeffs = utils::compute_b::<P>(*x_value, n);
-    let b_0_field = utils::inner_product_field_element::<P>(&s, &b_coeffs, n);
+    let s = compute_s(u_values, n);
+    let b_0_field = utils::inner_product_field_element::<P>(&s, coeffs_b, n);
     let g_0_group = g_0.unwrap_or_else(|| utils::inner_product_group(&s, &global_params.g, n));
 
     (g_0_group + *u_group * b_0_field) * a_0 == c
 }
 
+/// Verifies a proof of `inner_product_proof_with_transcript`, replaying its transcript to derive U and the challenges
+#[allow(clippy::too_many_arguments)]
+pub fn verify_inner_product_with_transcript<
+    P: CurveGroup + PrimeGroup,
+    T: Transcript<P::ScalarField>,
+>(
+    global_params: &GlobalIpaParams<P>,
+    commitment: &P,
+    inner_product: &P::ScalarField,
+    coeffs_b: &[P::ScalarField],
+    l_r_group: &[(P, P)],
+    a_0: &P::ScalarField,
+    transcript: &mut T,
+) -> bool {
+    transcript.append_serializable(b"ipa_commitment", commitment);
+    transcript.append_scalar(b"ipa_inner_product", inner_product);
+    let u_group = global_params.h_get() * transcript.challenge_scalar(b"ipa_w");
+    let u_values: Vec<P::ScalarField> = l_r_group
+        .iter()
+        .map(|(l_group, r_group)| {
+            transcript.append_serializable(b"ipa_l", l_group);
+            transcript.append_serializable(b"ipa_r", r_group);
+            transcript.challenge_scalar(b"ipa_u")
+        })
+        .collect();
+
+    verify_inner_product(
+        global_params,
+        commitment,
+        inner_product,
+        coeffs_b,
+        l_r_group,
+        a_0,
+        &None,
+        &u_values,
+        &u_group,
+    )
+}
+
+/// Verifies <a, b> = c for the vectors committed in C = <a, G> + <b, H>. The verifier replays the transcript, folds
+/// C' = C + c * U' + Sum{i} (u_i^2 * L_i + u_i^-2 * R_i) and the generators G, H, and accepts if
+/// C' = a[0] * G[0] + b[0] * H[0] + a[0] * b[0] * U'. The vectors have length `n`, a power of two, and the proof must
+/// have log2(n) rounds
+#[allow(clippy::too_many_arguments)]
+pub fn verify_inner_product_argument<P: CurveGroup, T: Transcript<P::ScalarField>>(
+    g_group_elements: &[P],
+    h_group_elements: &[P],
+    u_group: &P,
+    commitment: &P,
+    inner_product: &P::ScalarField,
+    n: usize,
+    proof: &InnerProductProof<P>,
+    transcript: &mut T,
+) -> bool {
+    if !n.is_power_of_two()
+        || proof.l_r_group.len() != n.ilog2() as usize
+        || g_group_elements.len() < n
+        || h_group_elements.len() < n
+    {
+        return false;
+    }
+    let mut n = n;
+    let mut g_group_elements = g_group_elements[..n].to_vec();
+    let mut h_group_elements = h_group_elements[..n].to_vec();
+
+    transcript.append_serializable(b"ipa_commitment", commitment);
+    transcript.append_scalar(b"ipa_inner_product", inner_product);
+    let u_group = *u_group * transcript.challenge_scalar(b"ipa_w");
+    let mut c = *commitment + u_group * inner_product;
+
+    for (l_group, r_group) in &proof.l_r_group {
+        transcript.append_serializable(b"ipa_l", l_group);
+        transcript.append_serializable(b"ipa_r", r_group);
+        let u = transcript.challenge_scalar(b"ipa_u");
+        let u_inverse = match u.inverse() {
+            Some(u_inverse) => u_inverse,
+            None => return false,
+        };
+        c += *l_group * (u * u) + *r_group * (u_inverse * u_inverse);
+
+        utils::fold_group_elements(&mut g_group_elements[..n], u_inverse, u);
+        utils::fold_group_elements(&mut h_group_elements[..n], u, u_inverse);
+        n /= 2;
+    }
+
+    c == g_group_elements[0] * proof.a_0
+        + h_group_elements[0] * proof.b_0
+        + u_group * (proof.a_0 * proof.b_0)
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn batch_verify<P: CurveGroup + PrimeGroup>(
     global_params: &GlobalIpaParams<P>,
     commitments_f: &[P],
@@ -68,7 +193,7 @@ pub fn batch_verify<P: CurveGroup + PrimeGroup>(
     verify(
         global_params,
         &commitment_g,
-        &f_x,
+        f_x,
         x_value,
         l_r_group,
         a_0,
@@ -87,50 +212,47 @@ pub fn preprocess_batch_verify<P: CurveGroup + PrimeGroup>(
     rho: &[P::ScalarField],
 ) -> P {
     let z_evaluation = z_poly.evaluate(x_value);
-    let zi_evaluations: Vec<P::ScalarField> =
-        z_i_poly.iter().map(|p| p.evaluate(x_value)).collect();
-    let scaled_zi_evaluations: Vec<P::ScalarField> = zi_evaluations
-        .iter()
-        .enumerate()
-        .map(|(idx, v)| *v * rho[idx])
+    let scaled_zi_evaluations: Vec<P::ScalarField> = cfg_iter!(z_i_poly)
+        .zip(cfg_iter!(rho))
+        .map(|(p, rho_i)| p.evaluate(x_value) * rho_i)
         .collect();
 
-    let mut commitment_linear_combination = P::zero();
-    commitment_linear_combination = commitments_f
-        .iter()
-        .enumerate()
-        .fold(commitment_linear_combination, |acc, (idx, c)| {
-            *c * scaled_zi_evaluations[idx] + acc
-        });
+    let commitment_linear_combination =
+        utils::inner_product_group::<P>(&scaled_zi_evaluations, commitments_f, commitments_f.len());
     commitment_linear_combination - *commitment_q * z_evaluation
 }
 
-fn compute_binomial<F: Field>(coeff: F, degree: usize) -> Polynomial<F> {
-    let mut poly_coeffs = vec![F::zero(); degree + 1];
-    poly_coeffs[0] = coeff.inverse().unwrap();
-    poly_coeffs[degree] = coeff;
-    Polynomial::<F>::from_vector_coefficients(poly_coeffs)
-}
-
 pub fn compute_s<F: Field>(coeffs: &[F], n: usize) -> Vec<F> {
-    let mut s = Vec::with_capacity(n);
-    for i in 0..n {
-        let mut s_elem = F::one();
-        for j in 0..coeffs.len() {
-            let mut c = coeffs[coeffs.len() - j - 1];
-            if is_inverse(i, j) {
-                c = c.inverse().unwrap();
+    cfg_into_iter!(0..n)
+        .map(|i| {
+            let mut s_elem = F::one();
+            for j in 0..coeffs.len() {
+                let mut c = coeffs[coeffs.len() - j - 1];
+                if is_inverse(i, j) {
+                    c = c.inverse().unwrap();
+                }
+                s_elem *= c;
             }
-            s_elem *= c;
-        }
-        s.push(s_elem);
+            s_elem
+        })
+        .collect()
+}
+
+/// Evaluates h(X) = Sum{i} s_i * X^i, the polynomial whose coefficients are `compute_s(u_values, 2^m)`, in O(m) as
+/// h(x) = Prod{j=0,j=m-1} (u_{m-1-j}^-1 + u_{m-1-j} * x^(2^j))
+pub fn evaluate_s<F: Field>(u_values: &[F], x_value: &F) -> F {
+    let mut x_power = *x_value;
+    let mut evaluation = F::one();
+    for u in u_values.iter().rev() {
+        evaluation *= u.inverse().unwrap() + *u * x_power;
+        x_power.square_in_place();
     }
-    s
+    evaluation
 }
 
 fn is_inverse(row_idx: usize, column_idx: usize) -> bool {
     let count = 1 << column_idx;
-    if (row_idx / count) % 2 == 0 {
+    if (row_idx / count).is_multiple_of(2) {
         return true;
     }
     false
@@ -0,0 +1,231 @@
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField, Zero};
+
+use super::{append_point, IpaAccumulator, IpaOpening};
+use crate::common::polynomial::Polynomial;
+use crate::common::poseidon::PoseidonParams;
+use crate::common::transcript::{PoseidonTranscript, Transcript};
+use crate::ipa::open::inner_product_rounds;
+use crate::ipa::setup::GlobalIpaParams;
+use crate::ipa::utils::compute_b;
+use crate::ipa::verify::{compute_s, evaluate_s, verify};
+
+const ACCUMULATION_LABEL: &[u8] = b"ipa_accumulation";
+const OPENING_LABEL: &[u8] = b"ipa_opening";
+
+/// Accumulates an IPA opening. After a succinct check of the opening, which trusts its G[0], the claims G[0] = <s, G>
+/// of the accumulator and of the opening are combined with a challenge alpha into C = G[0]_acc + alpha * G[0]_opening,
+/// the commitment to h_acc(X) + alpha * h_opening(X), and C is opened at a challenge z. The G[0] and challenges of that
+/// opening are the new accumulator, and the opening itself is the accumulation proof.
+/// The transcript runs over the base field of P, where the coordinates of its points are native
+pub fn accumulate<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    poseidon_params: &PoseidonParams<P::BaseField>,
+    accumulator: Option<&IpaAccumulator<P>>,
+    opening: &IpaOpening<P>,
+) -> Result<(IpaAccumulator<P>, IpaOpening<P>), String>
+where
+    P::BaseField: PrimeField,
+{
+    let opening_u_values = succinct_verify(global_params, poseidon_params, opening)
+        .ok_or_else(|| "IPA opening doesnt verify".to_string())?;
+    let (alpha, z) =
+        accumulation_challenges(poseidon_params, accumulator, opening, &opening_u_values);
+
+    let s_opening = compute_s(&opening_u_values, 1 << opening_u_values.len());
+    let mut coeffs: Vec<P::ScalarField> = s_opening.iter().map(|s| *s * alpha).collect();
+    let mut commitment = opening.g_m * alpha;
+    if let Some(accumulator) = accumulator {
+        let s_accumulator = compute_s(&accumulator.u_values, 1 << accumulator.u_values.len());
+        if s_accumulator.len() > coeffs.len() {
+            coeffs.resize(s_accumulator.len(), P::ScalarField::zero());
+        }
+        for (coeff, s) in coeffs.iter_mut().zip(s_accumulator) {
+            *coeff += s;
+        }
+        commitment += accumulator.g_m;
+    }
+    let h_poly = Polynomial::from_vector_coefficients(coeffs);
+
+    let (proof, u_values) = open(global_params, poseidon_params, commitment, &h_poly, z)?;
+    let new_accumulator = IpaAccumulator {
+        g_m: proof.g_m,
+        u_values,
+    };
+    Ok((new_accumulator, proof))
+}
+
+/// Checks an accumulation step without any MSM over the generators: the succinct check of the opening, and that the
+/// proof opens C = G[0]_acc + alpha * G[0]_opening at z to h_acc(z) + alpha * h_opening(z), each h evaluated from its
+/// challenges in O(log n). Returns the new accumulator, made of the G[0] and challenges of the proof
+pub fn verify_accumulation<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    poseidon_params: &PoseidonParams<P::BaseField>,
+    accumulator: Option<&IpaAccumulator<P>>,
+    opening: &IpaOpening<P>,
+    proof: &IpaOpening<P>,
+) -> Option<IpaAccumulator<P>>
+where
+    P::BaseField: PrimeField,
+{
+    let opening_u_values = succinct_verify(global_params, poseidon_params, opening)?;
+    let (alpha, z) =
+        accumulation_challenges(poseidon_params, accumulator, opening, &opening_u_values);
+
+    let mut commitment = opening.g_m * alpha;
+    let mut h_z = evaluate_s(&opening_u_values, &z) * alpha;
+    if let Some(accumulator) = accumulator {
+        commitment += accumulator.g_m;
+        h_z += evaluate_s(&accumulator.u_values, &z);
+    }
+    if proof.commitment != commitment || proof.x_value != z || proof.f_x != h_z {
+        return None;
+    }
+
+    let u_values = succinct_verify(global_params, poseidon_params, proof)?;
+    Some(IpaAccumulator {
+        g_m: proof.g_m,
+        u_values,
+    })
+}
+
+/// Opens the polynomial committed in `commitment` at x with an IPA, padding its coefficients to a power of two, and
+/// returns the opening with its challenges
+pub(crate) fn open<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    poseidon_params: &PoseidonParams<P::BaseField>,
+    commitment: P,
+    polynomial: &Polynomial<P::ScalarField>,
+    x_value: P::ScalarField,
+) -> Result<(IpaOpening<P>, Vec<P::ScalarField>), String>
+where
+    P::BaseField: PrimeField,
+{
+    let n = (polynomial.degree() + 1).next_power_of_two();
+    let mut coeffs = polynomial.coeffs().to_vec();
+    coeffs.resize(n, P::ScalarField::zero());
+    let coeffs_b = compute_b::<P>(x_value, n);
+    let f_x = polynomial.evaluate(&x_value);
+
+    let (mut transcript, u_group) =
+        opening_transcript(global_params, poseidon_params, &commitment, &x_value, &f_x);
+    let (a_m, _, g_m, l_r_group, u_values) = inner_product_rounds(
+        global_params.g_coeffs(),
+        None,
+        &coeffs,
+        &coeffs_b,
+        &u_group,
+        |l_group, r_group| round_challenge::<P>(&mut transcript, l_group, r_group),
+    )?;
+    let opening = IpaOpening {
+        commitment,
+        x_value,
+        f_x,
+        l_r_group,
+        a_m,
+        g_m,
+    };
+    Ok((opening, u_values))
+}
+
+/// Checks the opening with its claimed G[0], replaying its transcript, and returns its challenges
+fn succinct_verify<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    poseidon_params: &PoseidonParams<P::BaseField>,
+    opening: &IpaOpening<P>,
+) -> Option<Vec<P::ScalarField>>
+where
+    P::BaseField: PrimeField,
+{
+    let (mut transcript, u_group) = opening_transcript(
+        global_params,
+        poseidon_params,
+        &opening.commitment,
+        &opening.x_value,
+        &opening.f_x,
+    );
+    let u_values: Vec<P::ScalarField> = opening
+        .l_r_group
+        .iter()
+        .map(|(l_group, r_group)| round_challenge::<P>(&mut transcript, l_group, r_group))
+        .collect();
+
+    verify(
+        global_params,
+        &opening.commitment,
+        &opening.f_x,
+        &opening.x_value,
+        &opening.l_r_group,
+        &opening.a_m,
+        &Some(opening.g_m),
+        &u_values,
+        &u_group,
+    )
+    .then_some(u_values)
+}
+
+/// Transcript of an IPA opening, bound to its commitment, point and evaluation, and U = w * H for a challenge w
+fn opening_transcript<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    poseidon_params: &PoseidonParams<P::BaseField>,
+    commitment: &P,
+    x_value: &P::ScalarField,
+    f_x: &P::ScalarField,
+) -> (PoseidonTranscript<P::BaseField>, P)
+where
+    P::BaseField: PrimeField,
+{
+    let mut transcript = PoseidonTranscript::new(poseidon_params.clone(), OPENING_LABEL);
+    append_point(&mut transcript, b"commitment", commitment);
+    transcript.append_serializable(b"opening", &(*x_value, *f_x));
+    let w = transcript.challenge_scalar(b"ipa_w");
+    (transcript, global_params.h_get() * to_scalar::<P>(&w))
+}
+
+/// Challenge u of an IPA round, drawn after appending its L and R
+fn round_challenge<P: CurveGroup>(
+    transcript: &mut PoseidonTranscript<P::BaseField>,
+    l_group: &P,
+    r_group: &P,
+) -> P::ScalarField
+where
+    P::BaseField: PrimeField,
+{
+    append_point(transcript, b"ipa_l", l_group);
+    append_point(transcript, b"ipa_r", r_group);
+    to_scalar::<P>(&transcript.challenge_scalar(b"ipa_u"))
+}
+
+/// Challenges alpha and z, squeezed over the base field and reduced into the scalar field
+fn accumulation_challenges<P: CurveGroup>(
+    poseidon_params: &PoseidonParams<P::BaseField>,
+    accumulator: Option<&IpaAccumulator<P>>,
+    opening: &IpaOpening<P>,
+    opening_u_values: &[P::ScalarField],
+) -> (P::ScalarField, P::ScalarField)
+where
+    P::BaseField: PrimeField,
+{
+    let mut transcript = PoseidonTranscript::new(poseidon_params.clone(), ACCUMULATION_LABEL);
+    if let Some(accumulator) = accumulator {
+        append_point(&mut transcript, b"accumulator_g_m", &accumulator.g_m);
+        transcript.append_serializable(b"accumulator_u", &accumulator.u_values);
+    }
+    append_point(&mut transcript, b"commitment", &opening.commitment);
+    append_point(&mut transcript, b"g_m", &opening.g_m);
+    transcript.append_serializable(
+        b"opening",
+        &(opening.x_value, opening.f_x, opening_u_values.to_vec()),
+    );
+
+    let alpha = transcript.challenge_scalar(b"alpha");
+    let z = transcript.challenge_scalar(b"z");
+    (to_scalar::<P>(&alpha), to_scalar::<P>(&z))
+}
+
+fn to_scalar<P: CurveGroup>(element: &P::BaseField) -> P::ScalarField
+where
+    P::BaseField: PrimeField,
+{
+    P::ScalarField::from_le_bytes_mod_order(&element.into_bigint().to_bytes_le())
+}
@@ -0,0 +1,79 @@
+//! Halo style incrementally verifiable computation over a cycle of curves, where the base field of each curve is the
+//! scalar field of the other one (Pallas/Vesta). Steps alternate between the curves: each one commits to its witness
+//! and opens it with an IPA, and accumulates the opening of the previous step, which lives on the other curve. The
+//! accumulation only does group operations on the other curve, native arithmetic in the scalar field of the step, and
+//! defers the linear time part of every IPA check to a single decider per curve.
+pub mod accumulate;
+pub mod open;
+pub mod setup;
+pub mod verify;
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+
+use crate::common::poseidon::PoseidonParams;
+use crate::common::transcript::{PoseidonTranscript, Transcript};
+use crate::curves::CurveCycle;
+
+const STEP_LABEL: &[u8] = b"ivc_step";
+
+/// IPA opening f(x) = f_x of the polynomial committed in `commitment`. Its challenges are drawn from a transcript over
+/// the commitment, x, f_x and the L,R vectors, G[0] = <s, G> is claimed by the prover, the opening is checked succinctly
+/// and G[0] is left to an accumulator
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpaOpening<P: CurveGroup> {
+    pub commitment: P,
+    pub x_value: P::ScalarField,
+    pub f_x: P::ScalarField,
+    pub l_r_group: Vec<(P, P)>,
+    pub a_m: P::ScalarField,
+    pub g_m: P,
+}
+
+/// Deferred claim G[0] = <s, G>, where s are the coefficients of h(X) = Prod{j=0,j=m-1} (u_{m-1-j}^-1 + u_{m-1-j} * X^(2^j)),
+/// i.e. G[0] is the commitment to h(X)
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpaAccumulator<P: CurveGroup> {
+    pub g_m: P,
+    pub u_values: Vec<P::ScalarField>,
+}
+
+/// Openings of the steps on each curve, the first curve runs steps 0, 2, 4, ... and the second one steps 1, 3, 5, ...
+/// `accumulations_1[k]` accumulates `openings_1[k]` into the accumulator of the first curve, and likewise for the
+/// second curve
+#[derive(Debug, Clone, PartialEq)]
+pub struct IvcProof<C: CurveCycle> {
+    pub openings_1: Vec<IpaOpening<C::Curve1>>,
+    pub accumulations_1: Vec<IpaOpening<C::Curve1>>,
+    pub openings_2: Vec<IpaOpening<C::Curve2>>,
+    pub accumulations_2: Vec<IpaOpening<C::Curve2>>,
+}
+
+/// Appends a point by its affine coordinates, native elements of the transcript field. The point at infinity is
+/// appended as (0, 0), which isnt on a curve with b != 0
+pub(crate) fn append_point<P: CurveGroup>(
+    transcript: &mut PoseidonTranscript<P::BaseField>,
+    label: &[u8],
+    point: &P,
+) where
+    P::BaseField: PrimeField,
+{
+    let (x, y) = point.into_affine().xy().unwrap_or_default();
+    transcript.append_scalars(label, &[x, y]);
+}
+
+/// Opening point of a step on PA, bound to its witness commitment and to the accumulator of the other curve PB after
+/// the previous step
+pub(crate) fn step_challenge<PA: CurveGroup, PB: CurveGroup<BaseField = PA::ScalarField>>(
+    poseidon_params: &PoseidonParams<PA::ScalarField>,
+    commitment: &PA,
+    accumulator: Option<&IpaAccumulator<PB>>,
+) -> PA::ScalarField {
+    let mut transcript = PoseidonTranscript::new(poseidon_params.clone(), STEP_LABEL);
+    if let Some(accumulator) = accumulator {
+        append_point(&mut transcript, b"accumulator_g_m", &accumulator.g_m);
+        transcript.append_serializable(b"accumulator_u", &accumulator.u_values);
+    }
+    transcript.append_serializable(b"commitment", commitment);
+    transcript.challenge_scalar(b"x_value")
+}
@@ -0,0 +1,106 @@
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::PrimeField;
+
+use super::accumulate::{accumulate, open};
+use super::setup::GlobalIvcParams;
+use super::{step_challenge, IpaAccumulator, IpaOpening, IvcProof};
+use crate::common::polynomial::Polynomial;
+use crate::common::poseidon::PoseidonParams;
+use crate::curves::CurveCycle;
+use crate::ipa::commit::commit;
+use crate::ipa::setup::GlobalIpaParams;
+
+/// Runs the steps alternating between the curves, starting on the first one, with the witness polynomials of the
+/// steps on each curve. Step i opens its witness and accumulates the opening of step i - 1 on the other curve, and the
+/// opening of the last step is accumulated at the end, so every opening is covered by one of the two accumulators
+pub fn prove<C: CurveCycle>(
+    global_params: &GlobalIvcParams<C>,
+    witnesses_1: &[Polynomial<<C::Curve1 as PrimeGroup>::ScalarField>],
+    witnesses_2: &[Polynomial<<C::Curve2 as PrimeGroup>::ScalarField>],
+) -> Result<IvcProof<C>, String> {
+    if witnesses_2.is_empty()
+        || witnesses_1.len() < witnesses_2.len()
+        || witnesses_1.len() > witnesses_2.len() + 1
+    {
+        return Err(
+            "Steps must alternate between the curves with at least one step on each".to_string(),
+        );
+    }
+    let n_steps = witnesses_1.len() + witnesses_2.len();
+    let mut proof = IvcProof {
+        openings_1: Vec::with_capacity(witnesses_1.len()),
+        accumulations_1: Vec::with_capacity(witnesses_1.len()),
+        openings_2: Vec::with_capacity(witnesses_2.len()),
+        accumulations_2: Vec::with_capacity(witnesses_2.len()),
+    };
+    let mut accumulator_1 = None;
+    let mut accumulator_2 = None;
+
+    for step in 0..=n_steps {
+        let k = step / 2;
+        if step % 2 == 0 {
+            if let Some(opening) = k.checked_sub(1).and_then(|k| proof.openings_2.get(k)) {
+                let (accumulator, accumulation) = accumulate(
+                    &global_params.ipa_2,
+                    &global_params.poseidon_1,
+                    accumulator_2.as_ref(),
+                    opening,
+                )?;
+                accumulator_2 = Some(accumulator);
+                proof.accumulations_2.push(accumulation);
+            }
+            if let Some(witness) = witnesses_1.get(k) {
+                proof.openings_1.push(open_step(
+                    &global_params.ipa_1,
+                    &global_params.poseidon_1,
+                    &global_params.poseidon_2,
+                    accumulator_2.as_ref(),
+                    witness,
+                )?);
+            }
+        } else {
+            let (accumulator, accumulation) = accumulate(
+                &global_params.ipa_1,
+                &global_params.poseidon_2,
+                accumulator_1.as_ref(),
+                &proof.openings_1[k],
+            )?;
+            accumulator_1 = Some(accumulator);
+            proof.accumulations_1.push(accumulation);
+            if let Some(witness) = witnesses_2.get(k) {
+                proof.openings_2.push(open_step(
+                    &global_params.ipa_2,
+                    &global_params.poseidon_2,
+                    &global_params.poseidon_1,
+                    accumulator_1.as_ref(),
+                    witness,
+                )?);
+            }
+        }
+    }
+    Ok(proof)
+}
+
+/// Commits to the witness of a step on PA and opens it at a challenge bound to the accumulator of the other curve. The
+/// challenge is drawn over the scalar field of PA, and the IPA challenges over its base field
+fn open_step<PA: CurveGroup, PB: CurveGroup<BaseField = PA::ScalarField>>(
+    global_params: &GlobalIpaParams<PA>,
+    poseidon_params: &PoseidonParams<PA::ScalarField>,
+    ipa_poseidon_params: &PoseidonParams<PA::BaseField>,
+    accumulator: Option<&IpaAccumulator<PB>>,
+    witness: &Polynomial<PA::ScalarField>,
+) -> Result<IpaOpening<PA>, String>
+where
+    PA::BaseField: PrimeField,
+{
+    let commitment = commit(global_params, witness)?;
+    let x_value = step_challenge(poseidon_params, &commitment, accumulator);
+    let (opening, _) = open(
+        global_params,
+        ipa_poseidon_params,
+        commitment,
+        witness,
+        x_value,
+    )?;
+    Ok(opening)
+}
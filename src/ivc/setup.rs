@@ -0,0 +1,41 @@
+use ark_ec::PrimeGroup;
+use rand::{thread_rng, CryptoRng, RngCore};
+
+use crate::common::poseidon::PoseidonParams;
+use crate::curves::pasta::PastaCycle;
+use crate::curves::CurveCycle;
+use crate::ipa::setup::GlobalIpaParams;
+
+/// Width of the Poseidon permutation of the transcripts
+const POSEIDON_WIDTH: usize = 3;
+
+/// IPA parameters of both curves of the cycle, and Poseidon parameters over their scalar fields. Since the scalar field
+/// of each curve is the base field of the other one, `poseidon_1` also hashes points of the second curve natively
+#[derive(Debug, PartialEq)]
+pub struct GlobalIvcParams<C: CurveCycle> {
+    pub ipa_1: GlobalIpaParams<C::Curve1>,
+    pub ipa_2: GlobalIpaParams<C::Curve2>,
+    pub poseidon_1: PoseidonParams<<C::Curve1 as PrimeGroup>::ScalarField>,
+    pub poseidon_2: PoseidonParams<<C::Curve2 as PrimeGroup>::ScalarField>,
+}
+
+pub type PastaIvcParams = GlobalIvcParams<PastaCycle>;
+
+impl<C: CurveCycle> GlobalIvcParams<C> {
+    pub fn new(max_degree: usize) -> Result<Self, String> {
+        Self::new_with_rng(max_degree, &mut thread_rng())
+    }
+
+    /// Setup with the IPA generators of both curves drawn from `rng`, reproducible from a seeded rng
+    pub fn new_with_rng(
+        max_degree: usize,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<Self, String> {
+        Ok(GlobalIvcParams {
+            ipa_1: GlobalIpaParams::new_with_rng(max_degree, rng),
+            ipa_2: GlobalIpaParams::new_with_rng(max_degree, rng),
+            poseidon_1: PoseidonParams::new(POSEIDON_WIDTH)?,
+            poseidon_2: PoseidonParams::new(POSEIDON_WIDTH)?,
+        })
+    }
+}
@@ -0,0 +1,107 @@
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+
+use super::accumulate::verify_accumulation;
+use super::setup::GlobalIvcParams;
+use super::{step_challenge, IpaAccumulator, IpaOpening, IvcProof};
+use crate::common::poseidon::PoseidonParams;
+use crate::curves::CurveCycle;
+use crate::ipa::setup::GlobalIpaParams;
+use crate::ipa::verify::compute_s;
+
+/// Verifies the steps succinctly and decides both final accumulators. The succinct checks are what the circuit of
+/// each step proves in a recursive instantiation, where only the deciders remain to the verifier
+pub fn verify<C: CurveCycle>(global_params: &GlobalIvcParams<C>, proof: &IvcProof<C>) -> bool {
+    let n_steps_1 = proof.openings_1.len();
+    let n_steps_2 = proof.openings_2.len();
+    if n_steps_2 == 0
+        || n_steps_1 < n_steps_2
+        || n_steps_1 > n_steps_2 + 1
+        || proof.accumulations_1.len() != n_steps_1
+        || proof.accumulations_2.len() != n_steps_2
+    {
+        return false;
+    }
+
+    let accumulators_1 = match verify_accumulations(
+        &global_params.ipa_1,
+        &global_params.poseidon_2,
+        &proof.openings_1,
+        &proof.accumulations_1,
+    ) {
+        Some(accumulators) => accumulators,
+        None => return false,
+    };
+    let accumulators_2 = match verify_accumulations(
+        &global_params.ipa_2,
+        &global_params.poseidon_1,
+        &proof.openings_2,
+        &proof.accumulations_2,
+    ) {
+        Some(accumulators) => accumulators,
+        None => return false,
+    };
+
+    // Step 2k on the first curve is bound to the accumulator of the second one after step 2k - 1, and step 2k + 1 to
+    // the accumulator of the first one after step 2k
+    for (k, opening) in proof.openings_1.iter().enumerate() {
+        let accumulator = k.checked_sub(1).map(|k| &accumulators_2[k]);
+        if opening.x_value
+            != step_challenge(&global_params.poseidon_1, &opening.commitment, accumulator)
+        {
+            return false;
+        }
+    }
+    for (opening, accumulator) in proof.openings_2.iter().zip(&accumulators_1) {
+        if opening.x_value
+            != step_challenge(
+                &global_params.poseidon_2,
+                &opening.commitment,
+                Some(accumulator),
+            )
+        {
+            return false;
+        }
+    }
+
+    decide(&global_params.ipa_1, &accumulators_1[n_steps_1 - 1])
+        && decide(&global_params.ipa_2, &accumulators_2[n_steps_2 - 1])
+}
+
+/// Decider of an accumulator: the linear time check G[0] = <s, G> deferred by every accumulated opening
+pub fn decide<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    accumulator: &IpaAccumulator<P>,
+) -> bool {
+    let n = 1 << accumulator.u_values.len();
+    if global_params.len() < n {
+        return false;
+    }
+    let s = compute_s(&accumulator.u_values, n);
+    let bases = P::normalize_batch(&global_params.g_coeffs()[..n]);
+    P::msm_unchecked(&bases, &s) == accumulator.g_m
+}
+
+/// Replays the accumulation chain of one curve, returning the accumulator after each step
+fn verify_accumulations<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    poseidon_params: &PoseidonParams<P::BaseField>,
+    openings: &[IpaOpening<P>],
+    accumulations: &[IpaOpening<P>],
+) -> Option<Vec<IpaAccumulator<P>>>
+where
+    P::BaseField: PrimeField,
+{
+    let mut accumulators: Vec<IpaAccumulator<P>> = Vec::with_capacity(openings.len());
+    for (opening, accumulation) in openings.iter().zip(accumulations) {
+        let accumulator = verify_accumulation(
+            global_params,
+            poseidon_params,
+            accumulators.last(),
+            opening,
+            accumulation,
+        )?;
+        accumulators.push(accumulator);
+    }
+    Some(accumulators)
+}
@@ -1,5 +1,4 @@
-use ark_ec::{pairing::Pairing, CurveGroup};
-use ark_std::Zero;
+use ark_ec::{pairing::Pairing, CurveGroup, VariableBaseMSM};
 
 use crate::common::polynomial::Polynomial;
 use crate::kzg::setup::GlobalKzgParams;
@@ -10,17 +9,15 @@ pub fn commit<P: Pairing>(
     global_params: &GlobalKzgParams<P>,
     polynomial: &Polynomial<P::ScalarField>,
 ) -> Result<P::G1Affine, String> {
-    if polynomial.degree() > global_params.len() {
+    if polynomial.degree() >= global_params.len() {
         return Err(
             "Error committing to Polynomial. Polynomial degree is higher than the number of powers"
                 .to_string(),
-        )?;
+        );
     }
 
-    let mut commitment = P::G1::zero();
-    for (i, coeff) in polynomial.coeffs().iter().enumerate() {
-        commitment += global_params.g1_get(i).unwrap().to_owned() * coeff;
-    }
+    let coeffs = polynomial.coeffs();
+    let commitment = P::G1::msm_unchecked(&global_params.powers_of_g1[..coeffs.len()], coeffs);
     Ok(commitment.into_affine())
 }
 
@@ -28,16 +25,46 @@ pub fn commit_g2<P: Pairing>(
     global_params: &GlobalKzgParams<P>,
     polynomial: &Polynomial<P::ScalarField>,
 ) -> Result<P::G2Affine, String> {
-    if polynomial.degree() > global_params.len() {
+    if polynomial.degree() >= global_params.powers_of_g2.len() {
         return Err(
             "Error committing to Polynomial. Polynomial degree is higher than the number of powers"
                 .to_string(),
-        )?;
+        );
     }
 
-    let mut commitment = P::G2::zero();
-    for (i, coeff) in polynomial.coeffs().iter().enumerate() {
-        commitment += global_params.g2_get(i).unwrap().to_owned() * coeff;
-    }
+    let coeffs = polynomial.coeffs();
+    let commitment = P::G2::msm_unchecked(&global_params.powers_of_g2[..coeffs.len()], coeffs);
     Ok(commitment.into_affine())
 }
+
+/// To prove that f(x) has degree at most d < D, where D is the maximum degree supported by the setup, the prover
+/// commits to f(x) as usual and to the shifted polynomial x^(D-d) * f(x), C'_f = Sum{i=0,i=d} a_i * [ s_(i+D-d) * G1].
+/// A polynomial of degree higher than d would need powers of s beyond s^D, which the setup doesn't provide.
+pub fn commit_with_degree_bound<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    polynomial: &Polynomial<P::ScalarField>,
+    degree_bound: usize,
+) -> Result<(P::G1Affine, P::G1Affine), String> {
+    if global_params.is_empty() || degree_bound >= global_params.len() {
+        return Err(
+            "Error committing to Polynomial. Degree bound is higher than the number of powers"
+                .to_string(),
+        );
+    }
+    if polynomial.degree() > degree_bound {
+        return Err(
+            "Error committing to Polynomial. Polynomial degree is higher than the degree bound"
+                .to_string(),
+        );
+    }
+
+    let commitment = commit(global_params, polynomial)?;
+
+    let shift = global_params.max_degree() - degree_bound;
+    let coeffs = polynomial.coeffs();
+    let shifted_commitment = P::G1::msm_unchecked(
+        &global_params.powers_of_g1[shift..shift + coeffs.len()],
+        coeffs,
+    );
+    Ok((commitment, shifted_commitment.into_affine()))
+}
@@ -2,5 +2,51 @@ pub mod commit;
 pub mod open;
 pub mod setup;
 pub mod verify;
+pub mod zeromorph;
 
-pub struct Kzg;
+use std::marker::PhantomData;
+
+use ark_ec::pairing::Pairing;
+
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::Polynomial;
+use setup::GlobalKzgParams;
+
+/// KZG as a `PolynomialCommitment` over the scalar field of the pairing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Kzg<P: Pairing>(PhantomData<P>);
+
+impl<P: Pairing> PolynomialCommitment<P::ScalarField> for Kzg<P> {
+    type Params = GlobalKzgParams<P>;
+    type Commitment = P::G1Affine;
+    type Proof = P::G1Affine;
+
+    fn max_degree(params: &Self::Params) -> usize {
+        params.max_degree()
+    }
+
+    fn commit(
+        params: &Self::Params,
+        polynomial: &Polynomial<P::ScalarField>,
+    ) -> Result<Self::Commitment, String> {
+        commit::commit(params, polynomial)
+    }
+
+    fn open(
+        params: &Self::Params,
+        polynomial: &Polynomial<P::ScalarField>,
+        point: &P::ScalarField,
+    ) -> Result<(Self::Proof, P::ScalarField), String> {
+        open::evaluation_proof(params, polynomial, point)
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        proof: &Self::Proof,
+        point: &P::ScalarField,
+        value: &P::ScalarField,
+    ) -> bool {
+        verify::verify(params, commitment, proof, point, value)
+    }
+}
@@ -1,10 +1,9 @@
-use ark_ec::pairing::Pairing;
-use ark_std::One;
-use ark_std::Zero;
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::Zero;
 
 use super::commit::commit;
 use super::setup::GlobalKzgParams;
-use crate::common::polynomial::Polynomial;
+use crate::common::polynomial::{Polynomial, SparsePolynomial};
 
 /// we want to show that f(u) = v => u is a root of f(x) - v => (X - u) divides f(x) - v => There exists
 /// a polynomial q in Fp such that q(x) (x - u) = f(x) - f(u). The evaluation proof process consits on finding q(x) and
@@ -14,23 +13,18 @@ pub fn evaluation_proof<P: Pairing>(
     polynomial: &Polynomial<P::ScalarField>,
     u: &P::ScalarField,
 ) -> Result<(P::G1Affine, P::ScalarField), String> {
+    // The quotient of the zero polynomial is zero, so its proof is the identity
     if polynomial.is_zero() {
-        return Err("Polynomial is zero".to_string());
+        return Ok((P::G1Affine::zero(), P::ScalarField::zero()));
     }
-    let f_u = polynomial.evaluate(&u);
-    let coeff_0 = polynomial.get_coeff(0).unwrap().clone() - f_u;
+    let f_u = polynomial.evaluate(u);
+    let coeff_0 = *polynomial.get_coeff(0).unwrap() - f_u;
     let mut numerator_poly = polynomial.clone();
     numerator_poly.set_constant_coeff(coeff_0);
 
-    let denominator_poly = Polynomial::from_vector_coefficients(vec![
-        P::ScalarField::zero() - u,
-        P::ScalarField::one(),
-    ]);
-    let (q_poly, r_poly) = Polynomial::<<P as Pairing>::ScalarField>::div_polynomials(
-        numerator_poly,
-        &denominator_poly,
-    )
-    .ok_or("Error in polynomial division")?;
+    let denominator_poly = SparsePolynomial::monomial_from_coefficient(*u);
+    let (q_poly, r_poly) = Polynomial::div_sparse(numerator_poly, &denominator_poly)
+        .ok_or("Error in polynomial division")?;
     if !r_poly.is_zero() {
         return Err("Poly not divisible".to_string());
     }
@@ -45,7 +39,7 @@ pub fn batch_evaluation_proof<P: Pairing>(
     u: &[P::ScalarField],
 ) -> Result<(P::G1Affine, Polynomial<P::ScalarField>), String> {
     if polynomial.is_zero() {
-        return Err("Polynomial is zero".to_string());
+        return Ok((P::G1Affine::zero(), polynomial.clone()));
     }
     let monomials = Polynomial::monomial_vector_from_coefficients(u);
     let roots_poly = Polynomial::from_polys(monomials);
@@ -60,3 +54,20 @@ pub fn batch_evaluation_proof<P: Pairing>(
     let proof = commit(global_params, &psy_poly)?;
     Ok((proof, r_poly))
 }
+
+/// Evaluation proof for a polynomial committed with a degree bound d. The opening itself is the standard one on C_f;
+/// the degree bound is checked by the verifier against the shifted commitment C'_f.
+pub fn evaluation_proof_with_degree_bound<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    polynomial: &Polynomial<P::ScalarField>,
+    u: &P::ScalarField,
+    degree_bound: usize,
+) -> Result<(P::G1Affine, P::ScalarField), String> {
+    if degree_bound >= global_params.len() {
+        return Err("Degree bound is higher than the number of powers".to_string());
+    }
+    if polynomial.degree() > degree_bound {
+        return Err("Polynomial degree is higher than the degree bound".to_string());
+    }
+    evaluation_proof(global_params, polynomial, u)
+}
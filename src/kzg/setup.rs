@@ -1,7 +1,9 @@
 use ark_ec::AffineRepr;
 use ark_ec::{pairing::Pairing, CurveGroup};
-use ark_std::{One, UniformRand};
-use rand::thread_rng;
+use ark_std::{cfg_iter, One, UniformRand};
+use rand::{thread_rng, CryptoRng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[derive(Debug, PartialEq)]
 pub struct GlobalKzgParams<P: Pairing> {
@@ -11,17 +13,27 @@ pub struct GlobalKzgParams<P: Pairing> {
 
 impl<P: Pairing> GlobalKzgParams<P> {
     pub fn new(max_degree: usize) -> Self {
-        kzg_setup(max_degree)
+        Self::new_with_rng(max_degree, &mut thread_rng())
+    }
+
+    /// Setup with the secret s drawn from `rng`, reproducible from a seeded rng
+    pub fn new_with_rng(max_degree: usize, rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        kzg_setup(max_degree, rng)
     }
 
     pub fn len(&self) -> usize {
         self.powers_of_g1.len()
     }
 
-    pub fn g1_iter(&self) -> std::slice::Iter<P::G1Affine> {
+    /// Maximum polynomial degree D that can be committed with these parameters
+    pub fn max_degree(&self) -> usize {
+        self.len().saturating_sub(1)
+    }
+
+    pub fn g1_iter(&self) -> std::slice::Iter<'_, P::G1Affine> {
         self.powers_of_g1.iter()
     }
-    pub fn g2_iter(&self) -> std::slice::Iter<P::G2Affine> {
+    pub fn g2_iter(&self) -> std::slice::Iter<'_, P::G2Affine> {
         self.powers_of_g2.iter()
     }
 
@@ -41,23 +53,31 @@ impl<P: Pairing> GlobalKzgParams<P> {
     }
 }
 
-fn kzg_setup<P: Pairing>(max_degree: usize) -> GlobalKzgParams<P> {
-    let mut rng = thread_rng();
-
-    let s = P::ScalarField::rand(&mut rng);
+fn kzg_setup<P: Pairing>(
+    max_degree: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> GlobalKzgParams<P> {
+    let s = P::ScalarField::rand(rng);
 
-    let mut powers_of_g1 = Vec::with_capacity(max_degree + 1);
-    let mut powers_of_g2 = Vec::with_capacity(max_degree + 1);
+    let mut powers_of_s = Vec::with_capacity(max_degree + 1);
     let mut current_power = P::ScalarField::one();
-    let g1_generator = P::G1Affine::generator();
-    let g2_generator = P::G2Affine::generator();
-
     for _ in 0..=max_degree {
-        powers_of_g1.push((g1_generator * current_power).into_affine());
-        powers_of_g2.push((g2_generator * current_power).into_affine());
+        powers_of_s.push(current_power);
         current_power *= s;
     }
 
+    // the scalar multiplications dominate the setup and are independent of each other
+    let g1_generator = P::G1Affine::generator();
+    let g2_generator = P::G2Affine::generator();
+    let powers_of_g1: Vec<P::G1> = cfg_iter!(powers_of_s)
+        .map(|s_i| g1_generator * s_i)
+        .collect();
+    let powers_of_g2: Vec<P::G2> = cfg_iter!(powers_of_s)
+        .map(|s_i| g2_generator * s_i)
+        .collect();
+    let powers_of_g1 = P::G1::normalize_batch(&powers_of_g1);
+    let powers_of_g2 = P::G2::normalize_batch(&powers_of_g2);
+
     GlobalKzgParams {
         powers_of_g1,
         powers_of_g2,
@@ -78,6 +98,7 @@ mod test {
         let global_params = GlobalKzgParams::<Bn254>::new(degree);
 
         assert_eq!(global_params.len(), degree + 1);
+        assert_eq!(global_params.max_degree(), degree);
     }
 
     #[test]
@@ -48,15 +48,17 @@ pub fn batch_verify<P: Pairing>(
         return Err("Global parameters are empty".to_string());
     }
 
-    if u.len() != r_poly.degree() + 1 {
-        return Err("Number of points doesnt equal number of evaluations".to_string());
+    // r(X) interpolates the evaluations, so its degree is below the number of points, and lower when it drops, e.g.
+    // r(X) = 0 for the zero polynomial
+    if r_poly.degree() >= u.len() {
+        return Err("Remainder degree is not lower than the number of points".to_string());
     }
 
     let monomials = Polynomial::monomial_vector_from_coefficients(u);
     let accumulator_poly = Polynomial::from_polys(monomials);
     let commitment_a = commit_g2(global_params, &accumulator_poly)?;
 
-    let commitment_r = commit(global_params, &r_poly)?;
+    let commitment_r = commit(global_params, r_poly)?;
 
     // s * G2 - u * G2
     let lhs = P::pairing(proof, commitment_a);
@@ -67,3 +69,44 @@ pub fn batch_verify<P: Pairing>(
 
     Ok(lhs == rhs)
 }
+
+/// The verifier accepts that C_f commits to a polynomial of degree at most d if e(C_f, s^(D-d) * G2) = e(C'_f, G2),
+/// where C'_f is the shifted commitment to x^(D-d) * f(x) <=> s^(D-d) * f(s) = s^(D-d) * f(s)
+pub fn verify_degree_bound<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    commitment_f: &P::G1Affine,
+    shifted_commitment_f: &P::G1Affine,
+    degree_bound: usize,
+) -> bool {
+    if global_params.is_empty() || degree_bound >= global_params.len() {
+        return false;
+    }
+    let shift = global_params.max_degree() - degree_bound;
+    let g2_shift = match global_params.g2_get(shift) {
+        Some(g2_shift) => *g2_shift,
+        None => return false,
+    };
+
+    let lhs = P::pairing(commitment_f, g2_shift);
+    let rhs = P::pairing(shifted_commitment_f, P::G2Affine::generator());
+
+    lhs == rhs
+}
+
+/// Verifies both the evaluation proof f(u) = v and that f has degree at most d
+pub fn verify_with_degree_bound<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    commitment_f: &P::G1Affine,
+    shifted_commitment_f: &P::G1Affine,
+    proof: &P::G1Affine,
+    u: &P::ScalarField,
+    v: &P::ScalarField,
+    degree_bound: usize,
+) -> bool {
+    verify_degree_bound(
+        global_params,
+        commitment_f,
+        shifted_commitment_f,
+        degree_bound,
+    ) && verify(global_params, commitment_f, proof, u, v)
+}
@@ -0,0 +1,224 @@
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{Field, One, Zero};
+
+use super::commit::commit as kzg_commit;
+use super::open::evaluation_proof as kzg_evaluation_proof;
+use super::setup::GlobalKzgParams;
+use super::verify::verify as kzg_verify;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::{Sha256Transcript, Transcript};
+
+/// Zeromorph reduction from multilinear to univariate KZG. A multilinear polynomial f in n variables, given by its
+/// evaluations over {0,1}^n, is mapped to the univariate polynomial U_n(f)(X) = Sum{b} f(b) * X^b (b read as an integer
+/// with the first variable as least significant bit), so the hypercube evaluations are the KZG coefficients.
+///
+/// The evaluation f(u) = v is proven using f(X) - v = Sum{k=0,k=n-1} (X_k - u_k) * q_k(X_0, ..., X_{k-1}), which maps to
+/// U_n(f) - v * Phi_n(X) = Sum{k} (X^(2^k) * Phi_(n-k-1)(X^(2^(k+1))) - u_k * Phi_(n-k)(X^(2^k))) * U_k(q_k), where
+/// Phi_m(X) = 1 + X + ... + X^(2^m - 1). The challenge y is drawn from a transcript over C_f, u, v and the C_{q_k}, and x, z
+/// after also appending C_{q_hat}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZeromorphProof<P: Pairing> {
+    pub commitments_q: Vec<P::G1Affine>, // C_{q_k} for k = 0..n-1
+    pub commitment_q_hat: P::G1Affine,   // batched degree check of the q_k
+    pub proof: P::G1Affine,              // KZG opening proof at x
+}
+
+const EVALUATION_LABEL: &[u8] = b"zeromorph_evaluation";
+
+/// Commits to a multilinear polynomial given by its evaluations over the hypercube as C_f = commit(U_n(f))
+pub fn commit<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    evaluations: &[P::ScalarField],
+) -> Result<P::G1Affine, String> {
+    if !evaluations.len().is_power_of_two() {
+        return Err("Number of evaluations must be a power of two".to_string());
+    }
+    let polynomial = Polynomial::from_vector_coefficients(evaluations.to_vec());
+    kzg_commit(global_params, &polynomial)
+}
+
+pub fn evaluation_proof<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    evaluations: &[P::ScalarField],
+    point: &[P::ScalarField],
+) -> Result<(ZeromorphProof<P>, P::ScalarField), String> {
+    let n = point.len();
+    if evaluations.len() != 1 << n {
+        return Err("Number of evaluations doesnt match the number of variables".to_string());
+    }
+    if evaluations.len() > global_params.len() {
+        return Err(
+            "Error committing to Polynomial. Number of evaluations is higher than the number of powers"
+                .to_string(),
+        );
+    }
+    let max_degree = global_params.max_degree();
+    let commitment_f = commit(global_params, evaluations)?;
+
+    // Fix variables from the last one: q_k = f_(k+1)(.., 1) - f_(k+1)(.., 0), f_k = f_(k+1)(.., u_k)
+    let mut q_polys = vec![vec![]; n];
+    let mut f_k = evaluations.to_vec();
+    for k in (0..n).rev() {
+        let half = 1 << k;
+        let q_k: Vec<P::ScalarField> = (0..half).map(|j| f_k[half + j] - f_k[j]).collect();
+        f_k = (0..half).map(|j| f_k[j] + point[k] * q_k[j]).collect();
+        q_polys[k] = q_k;
+    }
+    let v = f_k[0];
+
+    let commitments_q = q_polys
+        .iter()
+        .map(|q_k| {
+            kzg_commit(
+                global_params,
+                &Polynomial::from_vector_coefficients(q_k.clone()),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut transcript = Sha256Transcript::new(EVALUATION_LABEL);
+    let y = challenge_y::<P>(&mut transcript, &commitment_f, point, &v, &commitments_q);
+
+    // q_hat = Sum{k} y^k * X^(D - 2^k + 1) * U_k(q_k) has degree at most D only if every U_k(q_k) has degree below 2^k
+    let mut q_hat = vec![P::ScalarField::zero(); max_degree + 1];
+    let mut y_k = P::ScalarField::one();
+    for (k, q_k) in q_polys.iter().enumerate() {
+        let shift = max_degree + 1 - (1 << k);
+        for (j, q) in q_k.iter().enumerate() {
+            q_hat[shift + j] += y_k * q;
+        }
+        y_k *= y;
+    }
+    let commitment_q_hat = kzg_commit(
+        global_params,
+        &Polynomial::from_vector_coefficients(q_hat.clone()),
+    )?;
+
+    let (x, z) = challenges_x_z::<P>(&mut transcript, &commitment_q_hat);
+
+    // zeta_x + z * Z_x = q_hat + z * U_n(f) - z * v * Phi_n(x) - Sum{k} (y^k * x^(D - 2^k + 1) + z * c_k(x)) * U_k(q_k)
+    // vanishes at x
+    let scalars = q_scalars::<P::ScalarField>(max_degree, n, point, &y, &x, &z);
+    let mut combined = q_hat;
+    for (c, f) in combined.iter_mut().zip(evaluations) {
+        *c += z * f;
+    }
+    combined[0] -= z * v * phi(&x, n);
+    for (q_k, scalar) in q_polys.iter().zip(scalars) {
+        for (c, q) in combined.iter_mut().zip(q_k) {
+            *c -= scalar * q;
+        }
+    }
+
+    let combined_poly = Polynomial::from_vector_coefficients(combined);
+    let (proof, eval) = kzg_evaluation_proof(global_params, &combined_poly, &x)?;
+    if !eval.is_zero() {
+        return Err("Combined polynomial should evaluate to zero at point x".to_string());
+    }
+
+    Ok((
+        ZeromorphProof {
+            commitments_q,
+            commitment_q_hat,
+            proof,
+        },
+        v,
+    ))
+}
+
+/// The verifier replays the transcript, rebuilds the commitment to zeta_x + z * Z_x from C_f, C_{q_k} and C_{q_hat} and
+/// checks that it opens to zero at x with the univariate KZG verifier
+pub fn verify<P: Pairing>(
+    global_params: &GlobalKzgParams<P>,
+    commitment_f: &P::G1Affine,
+    proof: &ZeromorphProof<P>,
+    point: &[P::ScalarField],
+    v: &P::ScalarField,
+) -> bool {
+    let n = point.len();
+    if global_params.is_empty() || proof.commitments_q.len() != n || (1 << n) > global_params.len()
+    {
+        return false;
+    }
+
+    let mut transcript = Sha256Transcript::new(EVALUATION_LABEL);
+    let y = challenge_y::<P>(
+        &mut transcript,
+        commitment_f,
+        point,
+        v,
+        &proof.commitments_q,
+    );
+    let (x, z) = challenges_x_z::<P>(&mut transcript, &proof.commitment_q_hat);
+
+    let scalars = q_scalars::<P::ScalarField>(global_params.max_degree(), n, point, &y, &x, &z);
+    let mut commitment = proof.commitment_q_hat.into_group() + *commitment_f * z
+        - P::G1Affine::generator() * (z * v * phi(&x, n));
+    for (c_q, scalar) in proof.commitments_q.iter().zip(scalars) {
+        commitment -= *c_q * scalar;
+    }
+
+    kzg_verify(
+        global_params,
+        &commitment.into_affine(),
+        &proof.proof,
+        &x,
+        &P::ScalarField::zero(),
+    )
+}
+
+/// Appends C_f, the point u, v and the C_{q_k} and draws the batching challenge y
+fn challenge_y<P: Pairing>(
+    transcript: &mut Sha256Transcript<P::ScalarField>,
+    commitment_f: &P::G1Affine,
+    point: &[P::ScalarField],
+    v: &P::ScalarField,
+    commitments_q: &[P::G1Affine],
+) -> P::ScalarField {
+    transcript.append_serializable(b"zeromorph_commitment", commitment_f);
+    transcript.append_scalars(b"zeromorph_point", point);
+    transcript.append_scalar(b"zeromorph_value", v);
+    for commitment_q in commitments_q {
+        transcript.append_serializable(b"zeromorph_commitment_q", commitment_q);
+    }
+    transcript.challenge_scalar(b"zeromorph_y")
+}
+
+/// Appends C_{q_hat} and draws the evaluation point x and the combination challenge z
+fn challenges_x_z<P: Pairing>(
+    transcript: &mut Sha256Transcript<P::ScalarField>,
+    commitment_q_hat: &P::G1Affine,
+) -> (P::ScalarField, P::ScalarField) {
+    transcript.append_serializable(b"zeromorph_commitment_q_hat", commitment_q_hat);
+    let x = transcript.challenge_scalar(b"zeromorph_x");
+    let z = transcript.challenge_scalar(b"zeromorph_z");
+    (x, z)
+}
+
+/// Scalars multiplying U_k(q_k) in zeta_x + z * Z_x: y^k * x^(D - 2^k + 1) + z * c_k(x), with
+/// c_k(x) = x^(2^k) * Phi_(n-k-1)(x^(2^(k+1))) - u_k * Phi_(n-k)(x^(2^k))
+fn q_scalars<F: Field>(max_degree: usize, n: usize, point: &[F], y: &F, x: &F, z: &F) -> Vec<F> {
+    let mut scalars = Vec::with_capacity(n);
+    let mut y_k = F::one();
+    let mut x_2k = *x; // x^(2^k)
+    for (k, u_k) in point.iter().enumerate() {
+        let shift = (max_degree + 1 - (1 << k)) as u64;
+        let x_2k1 = x_2k.square();
+        let c_k = x_2k * phi(&x_2k1, n - k - 1) - *u_k * phi(&x_2k, n - k);
+        scalars.push(y_k * x.pow([shift]) + *z * c_k);
+        y_k *= y;
+        x_2k = x_2k1;
+    }
+    scalars
+}
+
+/// Phi_m(x) = Sum{i=0,i=2^m-1} x^i = Prod{j=0,j=m-1} (1 + x^(2^j))
+fn phi<F: Field>(x: &F, m: usize) -> F {
+    let mut result = F::one();
+    let mut x_2j = *x;
+    for _ in 0..m {
+        result *= F::one() + x_2j;
+        x_2j.square_in_place();
+    }
+    result
+}
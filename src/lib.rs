@@ -1,3 +1,14 @@
 pub mod common;
+pub mod curves;
+pub mod eip4844;
+pub mod fri;
+pub mod gadgets;
 pub mod ipa;
+pub mod ivc;
 pub mod kzg;
+pub mod ligero;
+pub mod mlkzg;
+pub mod plonk;
+pub mod plookup;
+pub mod sumcheck;
+pub mod verkle;
@@ -0,0 +1,51 @@
+use ark_ff::PrimeField;
+
+use super::setup::GlobalLigeroParams;
+use crate::common::merkle::{hash_to_field, Digest, MerkleTree};
+use crate::common::polynomial::Polynomial;
+use crate::common::utils;
+
+/// The coefficients of f(x) are arranged as a matrix M with M_ij = a_(i * n_columns + j). Every row is encoded with the
+/// Reed-Solomon code and the commitment is the Merkle root of the hashes of the columns of the encoded matrix E
+pub fn commit<F: PrimeField>(
+    global_params: &GlobalLigeroParams<F>,
+    polynomial: &Polynomial<F>,
+) -> Result<Digest, String> {
+    let (_, _, tree) = encode(global_params, polynomial)?;
+    Ok(tree.root())
+}
+
+/// Coefficient matrix M, encoded matrix E and Merkle tree over the columns of E
+#[allow(clippy::type_complexity)]
+pub(crate) fn encode<F: PrimeField>(
+    global_params: &GlobalLigeroParams<F>,
+    polynomial: &Polynomial<F>,
+) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>, MerkleTree<F>), String> {
+    if polynomial.degree() >= global_params.len() {
+        return Err(
+            "Error committing to Polynomial. Polynomial degree is higher than the number of coefficients"
+                .to_string(),
+        );
+    }
+    let mut coeffs = polynomial.coeffs().to_vec();
+    coeffs.resize(global_params.len(), F::zero());
+
+    let matrix: Vec<Vec<F>> = coeffs
+        .chunks(global_params.n_columns)
+        .map(|row| row.to_vec())
+        .collect();
+    let encoded = matrix
+        .iter()
+        .map(|row| utils::fft(row, &global_params.domain))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let column_hashes: Vec<F> = (0..global_params.codeword_len())
+        .map(|c| hash_to_field(&column(&encoded, c)))
+        .collect();
+    let tree = MerkleTree::new(&column_hashes)?;
+    Ok((matrix, encoded, tree))
+}
+
+pub(crate) fn column<F: PrimeField>(matrix: &[Vec<F>], index: usize) -> Vec<F> {
+    matrix.iter().map(|row| row[index]).collect()
+}
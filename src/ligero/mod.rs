@@ -0,0 +1,61 @@
+pub mod commit;
+pub mod open;
+pub mod setup;
+pub mod verify;
+
+use ark_ff::PrimeField;
+
+use crate::common::merkle::{Digest, MerklePath};
+use crate::common::transcript::{Sha256Transcript, Transcript};
+
+/// Opened column of the encoded coefficient matrix with its Merkle path
+#[derive(Debug, Clone, PartialEq)]
+pub struct LigeroColumn<F: PrimeField> {
+    pub values: Vec<F>,
+    pub path: MerklePath,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LigeroProof<F: PrimeField> {
+    pub combined_row: Vec<F>, // gamma^T * M, proximity test
+    pub eval_row: Vec<F>,     // L^T * M, evaluation
+    pub columns: Vec<LigeroColumn<F>>,
+}
+
+const EVALUATION_LABEL: &[u8] = b"ligero_evaluation";
+
+/// Transcript over the commitment, the point z and the claimed evaluation v, and the proximity challenge gamma drawn
+/// from it
+pub(crate) fn evaluation_transcript<F: PrimeField>(
+    commitment: &Digest,
+    z: &F,
+    v: &F,
+    n_rows: usize,
+) -> (Sha256Transcript<F>, Vec<F>) {
+    let mut transcript = Sha256Transcript::new(EVALUATION_LABEL);
+    transcript.append_bytes(b"ligero_commitment", commitment);
+    transcript.append_scalar(b"ligero_point", z);
+    transcript.append_scalar(b"ligero_value", v);
+    let gamma = transcript.challenge_scalars(b"ligero_gamma", n_rows);
+    (transcript, gamma)
+}
+
+/// Appends u' and u and draws `num_queries` distinct column positions, so no column is opened twice
+pub(crate) fn query_indices<F: PrimeField>(
+    transcript: &mut Sha256Transcript<F>,
+    combined_row: &[F],
+    eval_row: &[F],
+    num_queries: usize,
+    codeword_len: usize,
+) -> Vec<usize> {
+    transcript.append_scalars(b"ligero_combined_row", combined_row);
+    transcript.append_scalars(b"ligero_eval_row", eval_row);
+    let mut indices = Vec::with_capacity(num_queries);
+    while indices.len() < num_queries {
+        let index = transcript.challenge_index(b"ligero_query", codeword_len);
+        if !indices.contains(&index) {
+            indices.push(index);
+        }
+    }
+    indices
+}
@@ -0,0 +1,84 @@
+use ark_ff::PrimeField;
+
+use super::commit::{column, encode};
+use super::setup::GlobalLigeroParams;
+use super::{evaluation_transcript, query_indices, LigeroColumn, LigeroProof};
+use crate::common::polynomial::Polynomial;
+
+/// f(z) = L^T * M * R, where R = (1, z, ..., z^(n_columns - 1)) and L = (1, z^n_columns, ..., z^((n_rows - 1) * n_columns)).
+/// The prover sends u' = gamma^T * M, to show that E is close to a matrix of codewords, and u = L^T * M, from which the
+/// verifier computes f(z) = <u, R>. Both are checked against distinct opened columns of E, since
+/// Enc(gamma^T * M) = gamma^T * E by linearity of the code. gamma is drawn from a transcript over the commitment, z and
+/// v, and the column positions after also appending u' and u
+pub fn evaluation_proof<F: PrimeField>(
+    global_params: &GlobalLigeroParams<F>,
+    polynomial: &Polynomial<F>,
+    z: &F,
+) -> Result<(LigeroProof<F>, F), String> {
+    let (matrix, encoded, tree) = encode(global_params, polynomial)?;
+    let v = polynomial.evaluate(z);
+
+    let (mut transcript, gamma) = evaluation_transcript(&tree.root(), z, &v, global_params.n_rows);
+    let combined_row = combine_rows(&matrix, &gamma);
+
+    let (l_vector, _) = tensor_vectors(global_params, z);
+    let eval_row = combine_rows(&matrix, &l_vector);
+
+    let columns = query_indices(
+        &mut transcript,
+        &combined_row,
+        &eval_row,
+        global_params.num_queries,
+        global_params.codeword_len(),
+    )
+    .into_iter()
+    .map(|index| {
+        Ok(LigeroColumn {
+            values: column(&encoded, index),
+            path: tree.open(index)?,
+        })
+    })
+    .collect::<Result<Vec<_>, String>>()?;
+
+    Ok((
+        LigeroProof {
+            combined_row,
+            eval_row,
+            columns,
+        },
+        v,
+    ))
+}
+
+/// Sum{i} weights_i * rows_i
+pub(crate) fn combine_rows<F: PrimeField>(rows: &[Vec<F>], weights: &[F]) -> Vec<F> {
+    let mut combined = vec![F::zero(); rows.first().map(|r| r.len()).unwrap_or(0)];
+    for (row, w) in rows.iter().zip(weights) {
+        for (c, r) in combined.iter_mut().zip(row) {
+            *c += *w * r;
+        }
+    }
+    combined
+}
+
+/// (L, R) such that f(z) = L^T * M * R
+pub(crate) fn tensor_vectors<F: PrimeField>(
+    global_params: &GlobalLigeroParams<F>,
+    z: &F,
+) -> (Vec<F>, Vec<F>) {
+    let powers = |base: F, n: usize| {
+        let mut v = Vec::with_capacity(n);
+        let mut current = F::one();
+        for _ in 0..n {
+            v.push(current);
+            current *= base;
+        }
+        v
+    };
+    let r_vector = powers(*z, global_params.n_columns);
+    let l_vector = powers(
+        z.pow([global_params.n_columns as u64]),
+        global_params.n_rows,
+    );
+    (l_vector, r_vector)
+}
@@ -0,0 +1,82 @@
+use ark_ff::PrimeField;
+
+use crate::common::utils;
+
+/// Ligero parameters. The coefficients of a polynomial of degree below n_rows * n_columns are arranged as a
+/// n_rows x n_columns matrix and each row is Reed-Solomon encoded over the roots of unity domain of size
+/// n_columns * blowup_factor.
+#[derive(Debug, PartialEq)]
+pub struct GlobalLigeroParams<F: PrimeField> {
+    pub domain: Vec<F>,
+    pub n_rows: usize,
+    pub n_columns: usize,
+    pub blowup_factor: usize,
+    pub num_queries: usize,
+}
+
+impl<F: PrimeField> GlobalLigeroParams<F> {
+    pub fn new(
+        max_degree: usize,
+        blowup_factor: usize,
+        num_queries: usize,
+    ) -> Result<Self, String> {
+        if !blowup_factor.is_power_of_two() || blowup_factor < 2 {
+            return Err("Blowup factor must be a power of two greater than one".to_string());
+        }
+        if num_queries == 0 {
+            return Err("Number of queries must be positive".to_string());
+        }
+        let n_coeffs = (max_degree + 1).next_power_of_two();
+        let column_vars = n_coeffs.trailing_zeros().div_ceil(2);
+        let n_columns = 1 << column_vars;
+        let n_rows = n_coeffs / n_columns;
+        if num_queries > n_columns * blowup_factor {
+            return Err("Number of queries is higher than the number of columns".to_string());
+        }
+        let domain = utils::compute_roots_of_unity::<F>((n_columns * blowup_factor) as u64)?;
+        Ok(GlobalLigeroParams {
+            domain,
+            n_rows,
+            n_columns,
+            blowup_factor,
+            num_queries,
+        })
+    }
+
+    /// Maximum number of coefficients that can be committed
+    pub fn len(&self) -> usize {
+        self.n_rows * self.n_columns
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.domain.is_empty()
+    }
+
+    /// Length of an encoded row
+    pub fn codeword_len(&self) -> usize {
+        self.domain.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+
+    use super::*;
+
+    #[test]
+    fn test_ligero_setup_dimensions() {
+        let global_params = GlobalLigeroParams::<Fr>::new(1000, 4, 10).unwrap();
+
+        assert_eq!(global_params.n_columns, 32);
+        assert_eq!(global_params.n_rows, 32);
+        assert_eq!(global_params.len(), 1024);
+        assert_eq!(global_params.codeword_len(), 128);
+    }
+
+    #[test]
+    fn test_ligero_setup_too_many_queries() {
+        assert!(GlobalLigeroParams::<Fr>::new(15, 2, 8).is_ok());
+        assert!(GlobalLigeroParams::<Fr>::new(15, 2, 9).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use ark_ff::PrimeField;
+
+use super::open::tensor_vectors;
+use super::setup::GlobalLigeroParams;
+use super::{evaluation_transcript, query_indices, LigeroProof};
+use crate::common::merkle::{hash_to_field, Digest, MerkleTree};
+use crate::common::utils;
+
+/// The verifier replays the transcript to get gamma and the column positions, encodes u' and u, and for every opened
+/// column c of E checks its Merkle path and that Enc(u')_c = <gamma, E_c> and Enc(u)_c = <L, E_c>. It accepts if
+/// additionally v = <u, R>
+pub fn verify<F: PrimeField>(
+    global_params: &GlobalLigeroParams<F>,
+    commitment: &Digest,
+    proof: &LigeroProof<F>,
+    z: &F,
+    v: &F,
+) -> bool {
+    if global_params.is_empty()
+        || proof.combined_row.len() != global_params.n_columns
+        || proof.eval_row.len() != global_params.n_columns
+        || proof.columns.len() != global_params.num_queries
+    {
+        return false;
+    }
+    let (mut transcript, gamma) = evaluation_transcript(commitment, z, v, global_params.n_rows);
+    let indices = query_indices(
+        &mut transcript,
+        &proof.combined_row,
+        &proof.eval_row,
+        global_params.num_queries,
+        global_params.codeword_len(),
+    );
+    let (l_vector, r_vector) = tensor_vectors(global_params, z);
+
+    let encoded_combined = match utils::fft(&proof.combined_row, &global_params.domain) {
+        Ok(encoded) => encoded,
+        Err(_) => return false,
+    };
+    let encoded_eval = match utils::fft(&proof.eval_row, &global_params.domain) {
+        Ok(encoded) => encoded,
+        Err(_) => return false,
+    };
+
+    for (column, index) in proof.columns.iter().zip(indices) {
+        if column.values.len() != global_params.n_rows
+            || !MerkleTree::verify(
+                commitment,
+                index,
+                &hash_to_field(&column.values),
+                &column.path,
+            )
+        {
+            return false;
+        }
+        if encoded_combined[index] != inner_product(&gamma, &column.values)
+            || encoded_eval[index] != inner_product(&l_vector, &column.values)
+        {
+            return false;
+        }
+    }
+
+    inner_product(&proof.eval_row, &r_vector) == *v
+}
+
+fn inner_product<F: PrimeField>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).map(|(a, b)| *a * b).sum()
+}
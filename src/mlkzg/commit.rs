@@ -0,0 +1,51 @@
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_std::Zero;
+
+use crate::mlkzg::setup::GlobalMlKzgParams;
+
+/// A multilinear polynomial f in m <= n variables is given by its evaluations f(b) over the boolean hypercube {0,1}^m.
+/// Its commitment is C_f = Sum{b} f(b) * [eq(b, tau) * G1] = f(tau) * G1. A polynomial in m < n variables is bound to the
+/// last m coordinates of tau.
+pub fn commit<P: Pairing>(
+    global_params: &GlobalMlKzgParams<P>,
+    evaluations: &[P::ScalarField],
+) -> Result<P::G1Affine, String> {
+    let level = commitment_level(global_params, evaluations.len())?;
+    commit_at_level(global_params, evaluations, level)
+}
+
+/// Level of the SRS used to commit to a polynomial with `n_evaluations` evaluations over the hypercube
+pub(crate) fn commitment_level<P: Pairing>(
+    global_params: &GlobalMlKzgParams<P>,
+    n_evaluations: usize,
+) -> Result<usize, String> {
+    if !n_evaluations.is_power_of_two() {
+        return Err("Number of evaluations must be a power of two".to_string());
+    }
+    if n_evaluations > global_params.len() {
+        return Err(
+            "Error committing to Polynomial. Number of variables is higher than the setup supports"
+                .to_string(),
+        );
+    }
+    Ok(global_params.num_vars() - ark_std::log2(n_evaluations) as usize)
+}
+
+pub(crate) fn commit_at_level<P: Pairing>(
+    global_params: &GlobalMlKzgParams<P>,
+    evaluations: &[P::ScalarField],
+    level: usize,
+) -> Result<P::G1Affine, String> {
+    let basis = global_params
+        .eq_g1_get(level)
+        .ok_or("Error committing to Polynomial. Level not available in setup")?;
+    if basis.len() != evaluations.len() {
+        return Err("Number of evaluations doesnt match the setup level".to_string());
+    }
+
+    let mut commitment = P::G1::zero();
+    for (base, eval) in basis.iter().zip(evaluations) {
+        commitment += *base * eval;
+    }
+    Ok(commitment.into_affine())
+}
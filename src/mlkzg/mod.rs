@@ -0,0 +1,4 @@
+pub mod commit;
+pub mod open;
+pub mod setup;
+pub mod verify;
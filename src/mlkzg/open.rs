@@ -0,0 +1,35 @@
+use ark_ec::pairing::Pairing;
+
+use super::commit::{commit_at_level, commitment_level};
+use super::setup::GlobalMlKzgParams;
+
+/// We want to show that f(z) = v for z in F^m. Since f is multilinear, f(X) - f(z) = Sum{i=0,i=m-1} (X_i - z_i) * q_i(X_{i+1}, ..., X_{m-1}),
+/// where q_i(X') = f_i(1, X') - f_i(0, X') and f_{i+1}(X') = f_i(z_i, X') are obtained fixing one variable at a time.
+/// The proof consists of the m commitments C_{q_i} = q_i(tau_{i+1}, ..., tau_{m-1}) * G1.
+pub fn evaluation_proof<P: Pairing>(
+    global_params: &GlobalMlKzgParams<P>,
+    evaluations: &[P::ScalarField],
+    point: &[P::ScalarField],
+) -> Result<(Vec<P::G1Affine>, P::ScalarField), String> {
+    let level = commitment_level(global_params, evaluations.len())?;
+    if point.len() != global_params.num_vars() - level {
+        return Err("Point dimension doesnt match the number of variables".to_string());
+    }
+
+    let mut proofs = Vec::with_capacity(point.len());
+    let mut f_i = evaluations.to_vec();
+    for (i, z_i) in point.iter().enumerate() {
+        let half = f_i.len() / 2;
+        let mut q_i = Vec::with_capacity(half);
+        let mut f_next = Vec::with_capacity(half);
+        for k in 0..half {
+            let (f_0, f_1) = (f_i[2 * k], f_i[2 * k + 1]);
+            q_i.push(f_1 - f_0);
+            f_next.push(f_0 + *z_i * (f_1 - f_0));
+        }
+        proofs.push(commit_at_level(global_params, &q_i, level + i + 1)?);
+        f_i = f_next;
+    }
+
+    Ok((proofs, f_i[0]))
+}
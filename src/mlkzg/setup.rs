@@ -0,0 +1,129 @@
+use ark_ec::AffineRepr;
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_std::{One, UniformRand};
+use rand::{thread_rng, CryptoRng, RngCore};
+
+/// Multilinear SRS for a secret point tau = (tau_0, ..., tau_{n-1}).
+/// `eq_g1[k]` holds [eq(b, (tau_k, ..., tau_{n-1})) * G1] for every b in {0,1}^(n-k), where the first variable of b is
+/// the least significant bit of its index. `eq_g1[0]` commits to polynomials in n variables, and `eq_g1[k]` to the
+/// quotients in the last n-k variables. `tau_g2[i]` holds [tau_i * G2]
+#[derive(Debug, PartialEq)]
+pub struct GlobalMlKzgParams<P: Pairing> {
+    pub eq_g1: Vec<Vec<P::G1Affine>>,
+    pub tau_g2: Vec<P::G2Affine>,
+}
+
+impl<P: Pairing> GlobalMlKzgParams<P> {
+    pub fn new(num_vars: usize) -> Self {
+        Self::new_with_rng(num_vars, &mut thread_rng())
+    }
+
+    /// Setup with the secret point tau drawn from `rng`, reproducible from a seeded rng
+    pub fn new_with_rng(num_vars: usize, rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        mlkzg_setup(num_vars, rng)
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.tau_g2.len()
+    }
+
+    /// Number of evaluations of the largest committable multilinear polynomial, 2^n
+    pub fn len(&self) -> usize {
+        self.eq_g1.first().map(|g| g.len()).unwrap_or(0)
+    }
+
+    pub fn eq_g1_get(&self, level: usize) -> Option<&[P::G1Affine]> {
+        self.eq_g1.get(level).map(|g| g.as_slice())
+    }
+
+    pub fn tau_g2_get(&self, n: usize) -> Option<&P::G2Affine> {
+        self.tau_g2.get(n)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        if self.eq_g1.is_empty() || self.tau_g2.is_empty() {
+            return true;
+        }
+        false
+    }
+}
+
+fn mlkzg_setup<P: Pairing>(
+    num_vars: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> GlobalMlKzgParams<P> {
+    let tau: Vec<P::ScalarField> = (0..num_vars).map(|_| P::ScalarField::rand(rng)).collect();
+
+    let g1_generator = P::G1Affine::generator();
+    let g2_generator = P::G2Affine::generator();
+
+    // eq tables are built from the last variable backwards: eq_k(b_k, b') = eq_{k+1}(b') * (b_k * tau_k + (1 - b_k) * (1 - tau_k))
+    let mut eq_tables = vec![vec![P::ScalarField::one()]];
+    for t in tau.iter().rev() {
+        let previous = eq_tables.last().unwrap();
+        let mut table = Vec::with_capacity(2 * previous.len());
+        for e in previous {
+            table.push(*e * (P::ScalarField::one() - t));
+            table.push(*e * t);
+        }
+        eq_tables.push(table);
+    }
+    eq_tables.reverse();
+
+    let eq_g1 = eq_tables
+        .iter()
+        .map(|table| {
+            let powers: Vec<P::G1> = table.iter().map(|e| g1_generator * e).collect();
+            P::G1::normalize_batch(&powers)
+        })
+        .collect();
+
+    let tau_g2 = tau
+        .iter()
+        .map(|t| (g2_generator * t).into_affine())
+        .collect();
+
+    GlobalMlKzgParams { eq_g1, tau_g2 }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Bn254;
+    use ark_bn254::G1Projective;
+    use ark_ec::PrimeGroup;
+
+    use super::*;
+
+    #[test]
+    fn test_mlkzg_setup_length() {
+        let num_vars = 6;
+        let global_params = GlobalMlKzgParams::<Bn254>::new(num_vars);
+
+        assert_eq!(global_params.num_vars(), num_vars);
+        assert_eq!(global_params.len(), 1 << num_vars);
+        for level in 0..=num_vars {
+            assert_eq!(
+                global_params.eq_g1_get(level).unwrap().len(),
+                1 << (num_vars - level)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mlkzg_setup_eq_sums_to_generator() {
+        // Sum over the hypercube of eq(b, tau) is 1 for every level
+        let num_vars = 5;
+        let global_params = GlobalMlKzgParams::<Bn254>::new(num_vars);
+        let g1_generator = G1Projective::generator();
+
+        for level in 0..=num_vars {
+            let sum: G1Projective = global_params
+                .eq_g1_get(level)
+                .unwrap()
+                .iter()
+                .map(|g| g.into_group())
+                .sum();
+            assert_eq!(sum, g1_generator);
+        }
+    }
+}
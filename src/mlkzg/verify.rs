@@ -0,0 +1,41 @@
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+
+use super::setup::GlobalMlKzgParams;
+
+/// The verifier has the trusted setup params, the commitment C_f to a multilinear polynomial in m variables, the point z,
+/// the claimed evaluation v and the quotient commitments C_{q_i} as the proof.
+/// Verifier accepts if e(C_f - v * G1, G2) = Prod{i} e(C_{q_i}, (tau_i - z_i) * G2) <=> f(X) - v = Sum{i} (X_i - z_i) * q_i(X)
+pub fn verify<P: Pairing>(
+    global_params: &GlobalMlKzgParams<P>,
+    commitment_f: &P::G1Affine,
+    proofs: &[P::G1Affine],
+    point: &[P::ScalarField],
+    v: &P::ScalarField,
+) -> bool {
+    let g1_generator = P::G1Affine::generator();
+    let g2_generator = P::G2Affine::generator();
+
+    if global_params.is_empty()
+        || proofs.len() != point.len()
+        || point.len() > global_params.num_vars()
+    {
+        return false;
+    }
+    let level = global_params.num_vars() - point.len();
+
+    // C_f - v * G1
+    let lhs_g1 = *commitment_f - g1_generator * v;
+    let lhs = P::pairing(lhs_g1, g2_generator);
+
+    // tau_i * G2 - z_i * G2
+    let rhs_g2: Vec<P::G2Affine> = point
+        .iter()
+        .enumerate()
+        .map(|(i, z_i)| {
+            (*global_params.tau_g2_get(level + i).unwrap() - g2_generator * z_i).into_affine()
+        })
+        .collect();
+    let rhs = P::multi_pairing(proofs, rhs_g2);
+
+    lhs == rhs
+}
@@ -0,0 +1,107 @@
+pub mod open;
+pub mod setup;
+pub mod verify;
+
+use ark_ff::PrimeField;
+
+use crate::common::pcs::PolynomialCommitment;
+use crate::gadgets::Evaluation;
+
+/// Selectors of a PLONK gate, q_L * a + q_R * b + q_O * c + q_M * a * b + q_C = 0 on the wires a, b, c
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gate<F: PrimeField> {
+    pub q_l: F,
+    pub q_r: F,
+    pub q_o: F,
+    pub q_m: F,
+    pub q_c: F,
+}
+
+impl<F: PrimeField> Gate<F> {
+    pub fn new(q_l: F, q_r: F, q_o: F, q_m: F, q_c: F) -> Self {
+        Gate {
+            q_l,
+            q_r,
+            q_o,
+            q_m,
+            q_c,
+        }
+    }
+
+    /// a + b = c
+    pub fn addition() -> Self {
+        Gate::new(F::one(), F::one(), -F::one(), F::zero(), F::zero())
+    }
+
+    /// a * b = c
+    pub fn multiplication() -> Self {
+        Gate::new(F::zero(), F::zero(), -F::one(), F::one(), F::zero())
+    }
+
+    /// a = value
+    pub fn constant(value: F) -> Self {
+        Gate::new(F::one(), F::zero(), F::zero(), F::zero(), -value)
+    }
+
+    /// Disabled gate, used to pad the circuit to the domain size
+    pub fn zero() -> Self {
+        Gate::new(F::zero(), F::zero(), F::zero(), F::zero(), F::zero())
+    }
+
+    pub fn evaluate(&self, a: &F, b: &F, c: &F) -> F {
+        self.q_l * a + self.q_r * b + self.q_o * c + self.q_m * a * b + self.q_c
+    }
+}
+
+/// Gates with the variables wired to their inputs a, b and output c. Wires sharing a variable are the copy
+/// constraints, enforced by the permutation argument
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circuit<F: PrimeField> {
+    pub gates: Vec<Gate<F>>,
+    pub wires: Vec<[usize; 3]>,
+    pub n_variables: usize,
+}
+
+impl<F: PrimeField> Circuit<F> {
+    pub fn new(n_variables: usize) -> Self {
+        Circuit {
+            gates: Vec::new(),
+            wires: Vec::new(),
+            n_variables,
+        }
+    }
+
+    pub fn add_gate(&mut self, gate: Gate<F>, wires: [usize; 3]) -> Result<(), String> {
+        if wires.iter().any(|w| *w >= self.n_variables) {
+            return Err("Wire refers to an unknown variable".to_string());
+        }
+        self.gates.push(gate);
+        self.wires.push(wires);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.gates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.gates.is_empty()
+    }
+}
+
+/// Commitments to the wire polynomials a(X), b(X), c(X), the permutation grand product z(X) and the quotient t(X),
+/// with the evaluations at zeta of the wires, the selectors (q_L, q_R, q_O, q_M, q_C), the permutation
+/// polynomials (sigma_1, sigma_2, sigma_3), z and t, and of z at w * zeta.
+/// The wires have no blinding factors, so the proof is not zero knowledge
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlonkProof<F: PrimeField, S: PolynomialCommitment<F>> {
+    pub commitments_wires: Vec<S::Commitment>,
+    pub commitment_z: S::Commitment,
+    pub commitment_t: S::Commitment,
+    pub wires_zeta: Vec<Evaluation<F, S>>,
+    pub selectors_zeta: Vec<Evaluation<F, S>>,
+    pub sigmas_zeta: Vec<Evaluation<F, S>>,
+    pub z_zeta: Evaluation<F, S>,
+    pub z_w_zeta: Evaluation<F, S>,
+    pub t_zeta: Evaluation<F, S>,
+}
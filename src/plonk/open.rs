@@ -0,0 +1,180 @@
+use ark_ff::PrimeField;
+
+use super::setup::{interpolate, GlobalPlonkParams};
+use super::PlonkProof;
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
+use crate::common::utils;
+use crate::gadgets::{shift, Evaluation};
+
+/// Proves that `witness`, one value per variable, satisfies the circuit.
+/// 1. Commits to the wire polynomials a(X), b(X), c(X) interpolating the values on the wires of every gate.
+/// 2. With challenges beta, gamma commits to the grand product z(w^0) = 1,
+///    z(w^(i+1)) = z(w^i) * Prod{j} (v_j(w^i) + beta * k_j * w^i + gamma) / (v_j(w^i) + beta * sigma_j(w^i) + gamma)
+/// 3. With challenge alpha commits to the quotient t(X) = (gate(X) + alpha * perm(X) + alpha^2 * L_0(X) * (z(X) - 1)) / Z_H(X),
+///    where gate(X) = q_L * a + q_R * b + q_O * c + q_M * a * b + q_C and
+///    perm(X) = z(X) * Prod{j} (v_j + beta * k_j * X + gamma) - z(w * X) * Prod{j} (v_j + beta * sigma_j + gamma).
+///    The numerator has degree up to 4n - 4, so it's evaluated over the coset g * H' of a domain H' of size 4n, where
+///    Z_H doesnt vanish, divided pointwise and interpolated back.
+/// 4. Opens every polynomial at the challenge zeta and z at w * zeta
+pub fn prove<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    global_params: &GlobalPlonkParams<F, S>,
+    witness: &[F],
+    transcript: &mut T,
+) -> Result<PlonkProof<F, S>, String> {
+    let circuit = &global_params.circuit;
+    let pcs_params = &global_params.pcs_params;
+    let domain = &global_params.domain;
+    let n = global_params.len();
+    if witness.len() != circuit.n_variables {
+        return Err("Witness doesnt assign every variable".to_string());
+    }
+
+    let wire_values: Vec<Vec<F>> = (0..3)
+        .map(|j| {
+            circuit
+                .wires
+                .iter()
+                .map(|wires| witness[wires[j]])
+                .collect()
+        })
+        .collect();
+    for (i, gate) in circuit.gates.iter().enumerate() {
+        if !gate
+            .evaluate(&wire_values[0][i], &wire_values[1][i], &wire_values[2][i])
+            .is_zero()
+        {
+            return Err(format!("Gate {} isnt satisfied by the witness", i));
+        }
+    }
+
+    global_params.append_to_transcript(transcript);
+    let wire_polys = wire_values
+        .iter()
+        .map(|values| interpolate(values, domain))
+        .collect::<Result<Vec<_>, String>>()?;
+    let commitments_wires = wire_polys
+        .iter()
+        .map(|p| S::commit(pcs_params, p))
+        .collect::<Result<Vec<_>, String>>()?;
+    for commitment in &commitments_wires {
+        transcript.append_serializable(b"plonk_commitment_wire", commitment);
+    }
+    let beta = transcript.challenge_scalar(b"plonk_beta");
+    let gamma = transcript.challenge_scalar(b"plonk_gamma");
+
+    let mut numerators = vec![F::one(); n];
+    let mut denominators = vec![F::one(); n];
+    for ((values, shift), sigma_values) in wire_values
+        .iter()
+        .zip(&global_params.shifts)
+        .zip(&global_params.sigma_values)
+    {
+        for (i, (value, sigma)) in values.iter().zip(sigma_values).enumerate() {
+            numerators[i] *= *value + beta * shift * domain[i] + gamma;
+            denominators[i] *= *value + beta * sigma + gamma;
+        }
+    }
+    if denominators.iter().any(|d| d.is_zero()) {
+        return Err("Permutation denominator vanishes".to_string());
+    }
+    ark_ff::batch_inversion(&mut denominators);
+    let mut z_values = Vec::with_capacity(n);
+    z_values.push(F::one());
+    for i in 0..n - 1 {
+        z_values.push(z_values[i] * numerators[i] * denominators[i]);
+    }
+    let z_poly = interpolate(&z_values, domain)?;
+    let commitment_z = S::commit(pcs_params, &z_poly)?;
+    transcript.append_serializable(b"plonk_commitment_z", &commitment_z);
+    let alpha = transcript.challenge_scalar(b"plonk_alpha");
+
+    let t_poly = quotient(global_params, &wire_polys, &z_poly, &beta, &gamma, &alpha)?;
+    let commitment_t = S::commit(pcs_params, &t_poly)?;
+    transcript.append_serializable(b"plonk_commitment_t", &commitment_t);
+    let zeta = transcript.challenge_scalar(b"plonk_zeta");
+
+    let open_all = |polys: &[Polynomial<F>]| {
+        polys
+            .iter()
+            .map(|p| Evaluation::open(pcs_params, p, &zeta))
+            .collect::<Result<Vec<_>, String>>()
+    };
+    Ok(PlonkProof {
+        commitments_wires,
+        commitment_z,
+        commitment_t,
+        wires_zeta: open_all(&wire_polys)?,
+        selectors_zeta: open_all(&global_params.selectors)?,
+        sigmas_zeta: open_all(&global_params.sigmas)?,
+        z_zeta: Evaluation::open(pcs_params, &z_poly, &zeta)?,
+        z_w_zeta: Evaluation::open(pcs_params, &z_poly, &(domain[1] * zeta))?,
+        t_zeta: Evaluation::open(pcs_params, &t_poly, &zeta)?,
+    })
+}
+
+fn quotient<F: PrimeField, S: PolynomialCommitment<F>>(
+    global_params: &GlobalPlonkParams<F, S>,
+    wire_polys: &[Polynomial<F>],
+    z_poly: &Polynomial<F>,
+    beta: &F,
+    gamma: &F,
+    alpha: &F,
+) -> Result<Polynomial<F>, String> {
+    let n = global_params.len();
+    let extended_domain = utils::compute_roots_of_unity::<F>(4 * n as u64)?;
+    let g = F::GENERATOR;
+    let coset_evaluations =
+        |polynomial: &Polynomial<F>| utils::fft(shift(polynomial, &g).coeffs(), &extended_domain);
+
+    let wires = wire_polys
+        .iter()
+        .map(coset_evaluations)
+        .collect::<Result<Vec<_>, String>>()?;
+    let selectors = global_params
+        .selectors
+        .iter()
+        .map(coset_evaluations)
+        .collect::<Result<Vec<_>, String>>()?;
+    let sigmas = global_params
+        .sigmas
+        .iter()
+        .map(coset_evaluations)
+        .collect::<Result<Vec<_>, String>>()?;
+    let z = coset_evaluations(z_poly)?;
+    let z_w = coset_evaluations(&shift(z_poly, &global_params.domain[1]))?;
+    let mut l_first = vec![F::zero(); n];
+    l_first[0] = F::one();
+    let l_first = coset_evaluations(&interpolate(&l_first, &global_params.domain)?)?;
+
+    let mut vanishing_inverses: Vec<F> = extended_domain
+        .iter()
+        .map(|x| (g * x).pow([n as u64]) - F::one())
+        .collect();
+    ark_ff::batch_inversion(&mut vanishing_inverses);
+
+    let mut t_evals = Vec::with_capacity(4 * n);
+    for (i, x) in extended_domain.iter().enumerate() {
+        let (a, b, c) = (wires[0][i], wires[1][i], wires[2][i]);
+        let gate = selectors[0][i] * a
+            + selectors[1][i] * b
+            + selectors[2][i] * c
+            + selectors[3][i] * a * b
+            + selectors[4][i];
+        let mut perm_numerator = z[i];
+        let mut perm_denominator = z_w[i];
+        for j in 0..3 {
+            let value = wires[j][i] + gamma;
+            perm_numerator *= value + *beta * global_params.shifts[j] * g * x;
+            perm_denominator *= value + *beta * sigmas[j][i];
+        }
+        let numerator = gate
+            + *alpha * (perm_numerator - perm_denominator)
+            + *alpha * alpha * l_first[i] * (z[i] - F::one());
+        t_evals.push(numerator * vanishing_inverses[i]);
+    }
+
+    let t_poly = interpolate(&t_evals, &extended_domain)?;
+    Ok(shift(&t_poly, &g.inverse().unwrap()))
+}
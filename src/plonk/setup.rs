@@ -0,0 +1,140 @@
+use ark_ff::{FftField, PrimeField};
+
+use super::{Circuit, Gate};
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
+use crate::common::utils;
+
+/// Preprocessed circuit: the circuit padded with disabled gates to the domain H = [1, w, ..., w^(n-1)], the selector
+/// polynomials q_L, q_R, q_O, q_M, q_C and the permutation polynomials sigma_1, sigma_2, sigma_3 with their
+/// commitments. Wire j of gate i is labelled k_j * w^i with the coset shifts k = (1, g, g^2), g a generator of the
+/// multiplicative group, and sigma_j(w^i) is the label of the next wire holding the same variable
+pub struct GlobalPlonkParams<F: PrimeField, S: PolynomialCommitment<F>> {
+    pub pcs_params: S::Params,
+    pub circuit: Circuit<F>,
+    pub domain: Vec<F>,
+    pub shifts: [F; 3],
+    pub selectors: Vec<Polynomial<F>>,
+    pub sigmas: Vec<Polynomial<F>>,
+    pub sigma_values: Vec<Vec<F>>,
+    pub commitments_selectors: Vec<S::Commitment>,
+    pub commitments_sigmas: Vec<S::Commitment>,
+}
+
+impl<F: PrimeField, S: PolynomialCommitment<F>> GlobalPlonkParams<F, S> {
+    pub fn new(pcs_params: S::Params, circuit: &Circuit<F>) -> Result<Self, String> {
+        plonk_setup(pcs_params, circuit)
+    }
+
+    pub fn len(&self) -> usize {
+        self.domain.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.domain.is_empty()
+    }
+
+    /// Binds the transcript to the preprocessed circuit
+    pub(crate) fn append_to_transcript<T: Transcript<F>>(&self, transcript: &mut T) {
+        for commitment in &self.commitments_selectors {
+            transcript.append_serializable(b"plonk_commitment_selector", commitment);
+        }
+        for commitment in &self.commitments_sigmas {
+            transcript.append_serializable(b"plonk_commitment_sigma", commitment);
+        }
+    }
+}
+
+fn plonk_setup<F: PrimeField, S: PolynomialCommitment<F>>(
+    pcs_params: S::Params,
+    circuit: &Circuit<F>,
+) -> Result<GlobalPlonkParams<F, S>, String> {
+    if circuit.is_empty() || circuit.wires.len() != circuit.len() {
+        return Err("Circuit has no gates or wires for every gate".to_string());
+    }
+    if circuit
+        .wires
+        .iter()
+        .flatten()
+        .any(|w| *w >= circuit.n_variables)
+    {
+        return Err("Wire refers to an unknown variable".to_string());
+    }
+    let n = circuit.len().max(2).next_power_of_two();
+    // The quotient has degree 3n - 4
+    if S::max_degree(&pcs_params) < 3 * n {
+        return Err("Commitment parameters dont support the degree of the quotient".to_string());
+    }
+
+    let mut circuit = circuit.clone();
+    circuit.gates.resize(n, Gate::zero());
+    circuit.wires.resize(n, [0; 3]);
+    let domain = utils::compute_roots_of_unity::<F>(n as u64)?;
+    let g = F::GENERATOR;
+    let shifts = [F::one(), g, g * g];
+
+    let selector_columns: [fn(&Gate<F>) -> F; 5] = [
+        |gate| gate.q_l,
+        |gate| gate.q_r,
+        |gate| gate.q_o,
+        |gate| gate.q_m,
+        |gate| gate.q_c,
+    ];
+    let selectors = selector_columns
+        .iter()
+        .map(|column| {
+            let values: Vec<F> = circuit.gates.iter().map(column).collect();
+            interpolate(&values, &domain)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    // Positions j * n + i of each variable, every position is mapped to the next one of the same variable
+    let mut positions = vec![Vec::new(); circuit.n_variables];
+    for j in 0..3 {
+        for (i, wires) in circuit.wires.iter().enumerate() {
+            positions[wires[j]].push(j * n + i);
+        }
+    }
+    let mut sigma_values = vec![vec![F::zero(); n]; 3];
+    for cycle in positions {
+        for (k, position) in cycle.iter().enumerate() {
+            let next = cycle[(k + 1) % cycle.len()];
+            sigma_values[position / n][position % n] = shifts[next / n] * domain[next % n];
+        }
+    }
+    let sigmas = sigma_values
+        .iter()
+        .map(|values| interpolate(values, &domain))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let commitments_selectors = selectors
+        .iter()
+        .map(|p| S::commit(&pcs_params, p))
+        .collect::<Result<Vec<_>, String>>()?;
+    let commitments_sigmas = sigmas
+        .iter()
+        .map(|p| S::commit(&pcs_params, p))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(GlobalPlonkParams {
+        pcs_params,
+        circuit,
+        domain,
+        shifts,
+        selectors,
+        sigmas,
+        sigma_values,
+        commitments_selectors,
+        commitments_sigmas,
+    })
+}
+
+pub(crate) fn interpolate<F: FftField>(
+    values: &[F],
+    domain: &[F],
+) -> Result<Polynomial<F>, String> {
+    Ok(Polynomial::from_vector_coefficients(utils::ifft(
+        values, domain,
+    )?))
+}
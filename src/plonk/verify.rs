@@ -0,0 +1,83 @@
+use ark_ff::PrimeField;
+
+use super::setup::GlobalPlonkParams;
+use super::PlonkProof;
+use crate::common::pcs::PolynomialCommitment;
+use crate::common::transcript::Transcript;
+use crate::gadgets::Evaluation;
+
+/// Replays the challenges, verifies the openings and checks the quotient identity at zeta,
+/// gate + alpha * perm + alpha^2 * L_0(zeta) * (z - 1) = t * (zeta^n - 1), with L_0(zeta) = (zeta^n - 1) / (n * (zeta - 1))
+pub fn verify<F: PrimeField, S: PolynomialCommitment<F>, T: Transcript<F>>(
+    global_params: &GlobalPlonkParams<F, S>,
+    proof: &PlonkProof<F, S>,
+    transcript: &mut T,
+) -> bool {
+    let pcs_params = &global_params.pcs_params;
+    let n = global_params.len();
+    if n < 2
+        || proof.commitments_wires.len() != 3
+        || proof.wires_zeta.len() != 3
+        || proof.selectors_zeta.len() != global_params.commitments_selectors.len()
+        || proof.sigmas_zeta.len() != global_params.commitments_sigmas.len()
+    {
+        return false;
+    }
+
+    global_params.append_to_transcript(transcript);
+    for commitment in &proof.commitments_wires {
+        transcript.append_serializable(b"plonk_commitment_wire", commitment);
+    }
+    let beta = transcript.challenge_scalar(b"plonk_beta");
+    let gamma = transcript.challenge_scalar(b"plonk_gamma");
+    transcript.append_serializable(b"plonk_commitment_z", &proof.commitment_z);
+    let alpha = transcript.challenge_scalar(b"plonk_alpha");
+    transcript.append_serializable(b"plonk_commitment_t", &proof.commitment_t);
+    let zeta = transcript.challenge_scalar(b"plonk_zeta");
+
+    let verify_all = |evaluations: &[Evaluation<F, S>], commitments: &[S::Commitment]| {
+        evaluations
+            .iter()
+            .zip(commitments)
+            .all(|(evaluation, commitment)| evaluation.verify(pcs_params, commitment, &zeta))
+    };
+    if !(verify_all(&proof.wires_zeta, &proof.commitments_wires)
+        && verify_all(&proof.selectors_zeta, &global_params.commitments_selectors)
+        && verify_all(&proof.sigmas_zeta, &global_params.commitments_sigmas)
+        && proof.z_zeta.verify(pcs_params, &proof.commitment_z, &zeta)
+        && proof.z_w_zeta.verify(
+            pcs_params,
+            &proof.commitment_z,
+            &(global_params.domain[1] * zeta),
+        )
+        && proof.t_zeta.verify(pcs_params, &proof.commitment_t, &zeta))
+    {
+        return false;
+    }
+
+    let wires: Vec<F> = proof.wires_zeta.iter().map(|e| e.value).collect();
+    let q: Vec<F> = proof.selectors_zeta.iter().map(|e| e.value).collect();
+    let gate =
+        q[0] * wires[0] + q[1] * wires[1] + q[2] * wires[2] + q[3] * wires[0] * wires[1] + q[4];
+
+    let mut perm_numerator = proof.z_zeta.value;
+    let mut perm_denominator = proof.z_w_zeta.value;
+    for ((wire, shift), sigma) in wires
+        .iter()
+        .zip(&global_params.shifts)
+        .zip(&proof.sigmas_zeta)
+    {
+        perm_numerator *= *wire + beta * shift * zeta + gamma;
+        perm_denominator *= *wire + beta * sigma.value + gamma;
+    }
+
+    let vanishing = zeta.pow([n as u64]) - F::one();
+    let l_first = match (F::from(n as u64) * (zeta - F::one())).inverse() {
+        Some(inverse) => vanishing * inverse,
+        None => return false,
+    };
+
+    gate + alpha * (perm_numerator - perm_denominator)
+        + alpha * alpha * l_first * (proof.z_zeta.value - F::one())
+        == proof.t_zeta.value * vanishing
+}
@@ -0,0 +1,24 @@
+use ark_ec::pairing::Pairing;
+
+use super::setup::GlobalPlookupParams;
+use crate::common::polynomial::Polynomial;
+use crate::common::utils;
+use crate::kzg::commit::commit;
+
+/// Interpolates the witness f_0, ..., f_(k-1) over the first k points of the domain and commits to it. The remaining
+/// points take the first table entry, so they are always in the table
+pub fn commit_witness<P: Pairing>(
+    global_params: &GlobalPlookupParams<P>,
+    witness: &[P::ScalarField],
+) -> Result<(Polynomial<P::ScalarField>, P::G1Affine), String> {
+    if witness.len() > global_params.max_witness_len() {
+        return Err("Witness is longer than the table domain".to_string());
+    }
+    let mut values = witness.to_vec();
+    values.resize(global_params.len(), global_params.table[0]);
+
+    let polynomial =
+        Polynomial::from_vector_coefficients(utils::ifft(&values, &global_params.domain)?);
+    let commitment = commit(&global_params.kzg_params, &polynomial)?;
+    Ok((polynomial, commitment))
+}
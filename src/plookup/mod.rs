@@ -0,0 +1,33 @@
+pub mod commit;
+pub mod open;
+pub mod setup;
+pub mod verify;
+
+use ark_ec::pairing::Pairing;
+
+use crate::common::polynomial::Polynomial;
+
+/// Opening of a polynomial at a set of points with `kzg::open::batch_evaluation_proof`, where r(X) interpolates the
+/// evaluations at the points
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiOpening<P: Pairing> {
+    pub proof: P::G1Affine,
+    pub r_poly: Polynomial<P::ScalarField>,
+}
+
+/// Plookup (Gabizon-Williamson) proof that the values of the witness f(X) are contained in the table t(X). h1(X) and
+/// h2(X) are the two halves of the sorted merge s of witness and table, z(X) the grand product and q(X) the quotient.
+/// f and q are opened at the challenge zeta and the rest at zeta and w * zeta
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlookupProof<P: Pairing> {
+    pub commitment_h1: P::G1Affine,
+    pub commitment_h2: P::G1Affine,
+    pub commitment_z: P::G1Affine,
+    pub commitment_q: P::G1Affine,
+    pub opening_f: MultiOpening<P>,
+    pub opening_t: MultiOpening<P>,
+    pub opening_h1: MultiOpening<P>,
+    pub opening_h2: MultiOpening<P>,
+    pub opening_z: MultiOpening<P>,
+    pub opening_q: MultiOpening<P>,
+}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use ark_ec::pairing::Pairing;
+use ark_ff::{FftField, Field, One};
+
+use super::setup::GlobalPlookupParams;
+use super::{MultiOpening, PlookupProof};
+use crate::common::polynomial::Polynomial;
+use crate::common::transcript::Transcript;
+use crate::common::utils;
+use crate::gadgets::{divide_by_vanishing, shift};
+use crate::kzg::{commit::commit, open::batch_evaluation_proof};
+
+/// Proves that the values f_0, ..., f_(n-2) of the committed witness are in the table t_0, ..., t_(n-1).
+/// The sorted merge s of f and t, ordered as t, is split in h1 = s[0..n] and h2 = s[n-1..2n-1]. With the challenges
+/// beta, gamma, the grand product z(w^0) = 1,
+/// z(w^(i+1)) = z(w^i) * (1 + beta) * (gamma + f_i) * (gamma * (1 + beta) + t_i + beta * t_(i+1)) /
+///     ((gamma * (1 + beta) + h1_i + beta * h1_(i+1)) * (gamma * (1 + beta) + h2_i + beta * h2_(i+1)))
+/// comes back to z(w^(n-1)) = 1 iff f is contained in t and s is its sorted merge. The constraints on H are
+/// L_0(X) * (z(X) - 1) = 0, L_(n-1)(X) * (z(X) - 1) = 0, L_(n-1)(X) * (h1(X) - h2(w * X)) = 0 and the step of z
+/// multiplied by (X - w^(n-1)), combined with powers of alpha into q(X) * Z_H(X)
+pub fn prove<P: Pairing, T: Transcript<P::ScalarField>>(
+    global_params: &GlobalPlookupParams<P>,
+    witness_poly: &Polynomial<P::ScalarField>,
+    commitment_f: &P::G1Affine,
+    transcript: &mut T,
+) -> Result<PlookupProof<P>, String> {
+    let kzg_params = &global_params.kzg_params;
+    let domain = &global_params.domain;
+    let n = global_params.len();
+    if witness_poly.degree() >= n {
+        return Err("Witness polynomial degree is higher than the domain size".to_string());
+    }
+    let w = domain[1];
+    let table = &global_params.table;
+    let witness = utils::fft(witness_poly.coeffs(), domain)?;
+
+    let sorted = sorted_merge(&witness[..n - 1], table)?;
+    let h1_poly = interpolate(&sorted[..n], domain)?;
+    let h2_poly = interpolate(&sorted[n - 1..], domain)?;
+    let commitment_h1 = commit(kzg_params, &h1_poly)?;
+    let commitment_h2 = commit(kzg_params, &h2_poly)?;
+    transcript.append_serializable(b"plookup_commitment_t", &global_params.commitment_t);
+    transcript.append_serializable(b"plookup_commitment_f", commitment_f);
+    transcript.append_serializable(b"plookup_commitment_h1", &commitment_h1);
+    transcript.append_serializable(b"plookup_commitment_h2", &commitment_h2);
+    let beta = transcript.challenge_scalar(b"plookup_beta");
+    let gamma = transcript.challenge_scalar(b"plookup_gamma");
+
+    let one_plus_beta = P::ScalarField::one() + beta;
+    let gamma_beta = gamma * one_plus_beta;
+    let mut numerators = Vec::with_capacity(n - 1);
+    let mut denominators = Vec::with_capacity(n - 1);
+    for i in 0..n - 1 {
+        numerators.push(
+            one_plus_beta * (gamma + witness[i]) * (gamma_beta + table[i] + beta * table[i + 1]),
+        );
+        denominators.push(
+            (gamma_beta + sorted[i] + beta * sorted[i + 1])
+                * (gamma_beta + sorted[n - 1 + i] + beta * sorted[n + i]),
+        );
+    }
+    ark_ff::batch_inversion(&mut denominators);
+    let mut z_values = Vec::with_capacity(n);
+    z_values.push(P::ScalarField::one());
+    for (numerator, denominator_inverse) in numerators.iter().zip(&denominators) {
+        let z = *z_values.last().unwrap() * numerator * denominator_inverse;
+        z_values.push(z);
+    }
+    if z_values[n - 1] != P::ScalarField::one() {
+        return Err("Grand product doesnt come back to one".to_string());
+    }
+    let z_poly = interpolate(&z_values, domain)?;
+    let commitment_z = commit(kzg_params, &z_poly)?;
+    transcript.append_serializable(b"plookup_commitment_z", &commitment_z);
+    let alpha = transcript.challenge_scalar(b"plookup_alpha");
+
+    let t_poly = &global_params.table_poly;
+    let l_first_poly = lagrange_polynomial(0, domain)?;
+    let l_last_poly = lagrange_polynomial(n - 1, domain)?;
+    let z_minus_one = z_poly.clone() - constant(P::ScalarField::one());
+    let step_poly = z_poly.clone()
+        * constant(one_plus_beta)
+        * (witness_poly.clone() + constant(gamma))
+        * (t_poly.clone() + shift(t_poly, &w) * constant(beta) + constant(gamma_beta))
+        - shift(&z_poly, &w)
+            * (h1_poly.clone() + shift(&h1_poly, &w) * constant(beta) + constant(gamma_beta))
+            * (h2_poly.clone() + shift(&h2_poly, &w) * constant(beta) + constant(gamma_beta));
+    let constraint_poly = l_first_poly * z_minus_one.clone()
+        + Polynomial::from_vector_coefficients(vec![-alpha * domain[n - 1], alpha]) * step_poly
+        + l_last_poly.clone()
+            * constant(alpha * alpha)
+            * (h1_poly.clone() - shift(&h2_poly, &w) + z_minus_one * constant(alpha));
+    let (q_poly, r_poly) = divide_by_vanishing(&constraint_poly, n)?;
+    if !r_poly.is_zero() {
+        return Err("Constraints dont vanish on the domain".to_string());
+    }
+    let commitment_q = commit(kzg_params, &q_poly)?;
+    transcript.append_serializable(b"plookup_commitment_q", &commitment_q);
+    let zeta = transcript.challenge_scalar(b"plookup_zeta");
+
+    let points = [zeta, w * zeta];
+    Ok(PlookupProof {
+        commitment_h1,
+        commitment_h2,
+        commitment_z,
+        commitment_q,
+        opening_f: open(global_params, witness_poly, &points[..1])?,
+        opening_t: open(global_params, t_poly, &points)?,
+        opening_h1: open(global_params, &h1_poly, &points)?,
+        opening_h2: open(global_params, &h2_poly, &points)?,
+        opening_z: open(global_params, &z_poly, &points)?,
+        opening_q: open(global_params, &q_poly, &points[..1])?,
+    })
+}
+
+/// Merges the witness into the table, each witness value right after the first entry of the table equal to it, so
+/// that consecutive pairs of s are either repeated values or consecutive pairs of t
+fn sorted_merge<F: Field>(witness: &[F], table: &[F]) -> Result<Vec<F>, String> {
+    let mut counts: HashMap<F, usize> = HashMap::new();
+    for value in witness {
+        *counts.entry(*value).or_default() += 1;
+    }
+    let mut sorted = Vec::with_capacity(witness.len() + table.len());
+    for value in table {
+        sorted.push(*value);
+        if let Some(count) = counts.remove(value) {
+            sorted.extend(std::iter::repeat_n(*value, count));
+        }
+    }
+    if !counts.is_empty() {
+        return Err("Witness value is not in the table".to_string());
+    }
+    Ok(sorted)
+}
+
+fn open<P: Pairing>(
+    global_params: &GlobalPlookupParams<P>,
+    polynomial: &Polynomial<P::ScalarField>,
+    points: &[P::ScalarField],
+) -> Result<MultiOpening<P>, String> {
+    let (proof, r_poly) = batch_evaluation_proof(&global_params.kzg_params, polynomial, points)?;
+    Ok(MultiOpening { proof, r_poly })
+}
+
+fn interpolate<F: FftField>(values: &[F], domain: &[F]) -> Result<Polynomial<F>, String> {
+    Ok(Polynomial::from_vector_coefficients(utils::ifft(
+        values, domain,
+    )?))
+}
+
+/// L_i(X), equal to one at w^i and zero on the rest of the domain
+fn lagrange_polynomial<F: FftField>(i: usize, domain: &[F]) -> Result<Polynomial<F>, String> {
+    let mut values = vec![F::zero(); domain.len()];
+    values[i] = F::one();
+    interpolate(&values, domain)
+}
+
+fn constant<F: Field>(value: F) -> Polynomial<F> {
+    Polynomial::from_vector_coefficients(vec![value])
+}
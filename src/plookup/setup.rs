@@ -0,0 +1,69 @@
+use ark_ec::pairing::Pairing;
+use rand::{thread_rng, CryptoRng, RngCore};
+
+use crate::common::polynomial::Polynomial;
+use crate::common::utils;
+use crate::kzg::{commit::commit, setup::GlobalKzgParams};
+
+/// KZG parameters, the domain H = [1, w, ..., w^(n-1)] and the preprocessed table t(X) with t(w^i) = t_i. The table is
+/// padded with its last entry up to a power of two n, which doesnt change its set of values, and witnesses hold up
+/// to n - 1 values
+#[derive(Debug, PartialEq)]
+pub struct GlobalPlookupParams<P: Pairing> {
+    pub kzg_params: GlobalKzgParams<P>,
+    pub domain: Vec<P::ScalarField>,
+    pub table: Vec<P::ScalarField>,
+    pub table_poly: Polynomial<P::ScalarField>,
+    pub commitment_t: P::G1Affine,
+}
+
+impl<P: Pairing> GlobalPlookupParams<P> {
+    pub fn new(table: &[P::ScalarField]) -> Result<Self, String> {
+        Self::new_with_rng(table, &mut thread_rng())
+    }
+
+    /// Setup with the KZG secret s drawn from `rng`, reproducible from a seeded rng
+    pub fn new_with_rng(
+        table: &[P::ScalarField],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<Self, String> {
+        plookup_setup(table, rng)
+    }
+
+    pub fn len(&self) -> usize {
+        self.domain.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.domain.is_empty()
+    }
+
+    /// Maximum number of values of a witness
+    pub fn max_witness_len(&self) -> usize {
+        self.len().saturating_sub(1)
+    }
+}
+
+fn plookup_setup<P: Pairing>(
+    table: &[P::ScalarField],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<GlobalPlookupParams<P>, String> {
+    let last = *table.last().ok_or("Table is empty")?;
+    let n = table.len().max(2).next_power_of_two();
+    let mut table = table.to_vec();
+    table.resize(n, last);
+
+    let domain = utils::compute_roots_of_unity::<P::ScalarField>(n as u64)?;
+    // The quotient has degree 2n - 2
+    let kzg_params = GlobalKzgParams::<P>::new_with_rng(2 * n, rng);
+    let table_poly = Polynomial::from_vector_coefficients(utils::ifft(&table, &domain)?);
+    let commitment_t = commit(&kzg_params, &table_poly)?;
+
+    Ok(GlobalPlookupParams {
+        kzg_params,
+        domain,
+        table,
+        table_poly,
+        commitment_t,
+    })
+}
@@ -0,0 +1,127 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::{Field, One};
+
+use super::setup::GlobalPlookupParams;
+use super::{MultiOpening, PlookupProof};
+use crate::common::transcript::Transcript;
+use crate::kzg::verify::batch_verify;
+
+/// Replays the challenges, verifies the openings at zeta and w * zeta and checks the combined constraints at zeta:
+/// L_0 * (z - 1) + alpha * (zeta - w^(n-1)) * step + alpha^2 * L_(n-1) * (h1 - h2(w * zeta)) + alpha^3 * L_(n-1) * (z - 1)
+/// = q * (zeta^n - 1), where L_i(zeta) = w^i * (zeta^n - 1) / (n * (zeta - w^i))
+pub fn verify<P: Pairing, T: Transcript<P::ScalarField>>(
+    global_params: &GlobalPlookupParams<P>,
+    commitment_f: &P::G1Affine,
+    proof: &PlookupProof<P>,
+    transcript: &mut T,
+) -> bool {
+    let domain = &global_params.domain;
+    let n = global_params.len();
+    if n < 2 {
+        return false;
+    }
+    let w = domain[1];
+    let w_last = domain[n - 1];
+
+    transcript.append_serializable(b"plookup_commitment_t", &global_params.commitment_t);
+    transcript.append_serializable(b"plookup_commitment_f", commitment_f);
+    transcript.append_serializable(b"plookup_commitment_h1", &proof.commitment_h1);
+    transcript.append_serializable(b"plookup_commitment_h2", &proof.commitment_h2);
+    let beta = transcript.challenge_scalar(b"plookup_beta");
+    let gamma = transcript.challenge_scalar(b"plookup_gamma");
+    transcript.append_serializable(b"plookup_commitment_z", &proof.commitment_z);
+    let alpha = transcript.challenge_scalar(b"plookup_alpha");
+    transcript.append_serializable(b"plookup_commitment_q", &proof.commitment_q);
+    let zeta = transcript.challenge_scalar(b"plookup_zeta");
+
+    let points = [zeta, w * zeta];
+    let single_point = &points[..1];
+    if !(verify_opening(global_params, commitment_f, &proof.opening_f, single_point)
+        && verify_opening(
+            global_params,
+            &global_params.commitment_t,
+            &proof.opening_t,
+            &points,
+        )
+        && verify_opening(
+            global_params,
+            &proof.commitment_h1,
+            &proof.opening_h1,
+            &points,
+        )
+        && verify_opening(
+            global_params,
+            &proof.commitment_h2,
+            &proof.opening_h2,
+            &points,
+        )
+        && verify_opening(
+            global_params,
+            &proof.commitment_z,
+            &proof.opening_z,
+            &points,
+        )
+        && verify_opening(
+            global_params,
+            &proof.commitment_q,
+            &proof.opening_q,
+            single_point,
+        ))
+    {
+        return false;
+    }
+    let f = proof.opening_f.r_poly.evaluate(&zeta);
+    let (t, t_w) = evaluate_pair(&proof.opening_t, &points);
+    let (h1, h1_w) = evaluate_pair(&proof.opening_h1, &points);
+    let (h2, h2_w) = evaluate_pair(&proof.opening_h2, &points);
+    let (z, z_w) = evaluate_pair(&proof.opening_z, &points);
+    let q = proof.opening_q.r_poly.evaluate(&zeta);
+
+    let vanishing = zeta.pow([n as u64]) - P::ScalarField::one();
+    let n_field = P::ScalarField::from(n as u64);
+    let (l_first, l_last) = match (
+        (n_field * (zeta - P::ScalarField::one())).inverse(),
+        (n_field * (zeta - w_last)).inverse(),
+    ) {
+        (Some(first), Some(last)) => (vanishing * first, w_last * vanishing * last),
+        _ => return false,
+    };
+
+    let one_plus_beta = P::ScalarField::one() + beta;
+    let gamma_beta = gamma * one_plus_beta;
+    let step = z * one_plus_beta * (gamma + f) * (gamma_beta + t + beta * t_w)
+        - z_w * (gamma_beta + h1 + beta * h1_w) * (gamma_beta + h2 + beta * h2_w);
+    let constraint = l_first * (z - P::ScalarField::one())
+        + alpha * (zeta - w_last) * step
+        + alpha * alpha * l_last * (h1 - h2_w + alpha * (z - P::ScalarField::one()));
+
+    constraint == q * vanishing
+}
+
+fn verify_opening<P: Pairing>(
+    global_params: &GlobalPlookupParams<P>,
+    commitment: &P::G1Affine,
+    opening: &MultiOpening<P>,
+    points: &[P::ScalarField],
+) -> bool {
+    matches!(
+        batch_verify(
+            &global_params.kzg_params,
+            commitment,
+            &opening.proof,
+            points,
+            &opening.r_poly
+        ),
+        Ok(true)
+    )
+}
+
+fn evaluate_pair<P: Pairing>(
+    opening: &MultiOpening<P>,
+    points: &[P::ScalarField; 2],
+) -> (P::ScalarField, P::ScalarField) {
+    (
+        opening.r_poly.evaluate(&points[0]),
+        opening.r_poly.evaluate(&points[1]),
+    )
+}
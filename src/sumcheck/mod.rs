@@ -0,0 +1,56 @@
+pub mod open;
+pub mod verify;
+
+use ark_ff::PrimeField;
+
+/// Round polynomials g_i(X) as their evaluations at X = 0, ..., d and the evaluations f_j(r) of every factor at the
+/// point r given by the round challenges
+#[derive(Debug, Clone, PartialEq)]
+pub struct SumCheckProof<F: PrimeField> {
+    pub round_evaluations: Vec<Vec<F>>,
+    pub final_evaluations: Vec<F>,
+}
+
+/// Evaluates at r the polynomial of degree d given by its evaluations at 0, ..., d, with the Lagrange basis
+/// L_i(r) = Prod{j != i} (r - j) / (i - j)
+pub(crate) fn interpolate_at<F: PrimeField>(evaluations: &[F], r: &F) -> F {
+    let mut result = F::zero();
+    for (i, e_i) in evaluations.iter().enumerate() {
+        let mut numerator = F::one();
+        let mut denominator = F::one();
+        for j in (0..evaluations.len()).filter(|j| *j != i) {
+            numerator *= *r - F::from(j as u64);
+            denominator *= F::from(i as u64) - F::from(j as u64);
+        }
+        result += *e_i
+            * numerator
+            * denominator
+                .inverse()
+                .expect("Interpolation points are distinct");
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::UniformRand;
+    use rand::thread_rng;
+
+    use super::interpolate_at;
+    use crate::common::polynomial::Polynomial;
+
+    #[test]
+    fn test_interpolate_at() {
+        let mut rng = thread_rng();
+        let poly = Polynomial::<Fr>::from_random_coefficients(4);
+        let evaluations: Vec<Fr> = (0..5_u64).map(|i| poly.evaluate(&Fr::from(i))).collect();
+        let r = Fr::rand(&mut rng);
+
+        assert_eq!(interpolate_at(&evaluations, &r), poly.evaluate(&r));
+        assert_eq!(
+            interpolate_at(&evaluations, &Fr::from(3_u64)),
+            evaluations[3]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use ark_ff::PrimeField;
+
+use super::SumCheckProof;
+use crate::common::multilinear::MultilinearPolynomial;
+use crate::common::transcript::Transcript;
+
+/// Proves Sum{b in {0,1}^n} f_1(b) * ... * f_d(b) = s for multilinear f_j in n variables. In round i, with the first
+/// i - 1 variables fixed to the previous challenges, the prover sends the degree d polynomial
+/// g_i(X) = Sum{b} Prod{j} f_j(r_1, ..., r_{i-1}, X, b) as its evaluations at X = 0, ..., d and fixes X to the challenge
+/// r_i. The sum is reduced to the claim Prod{j} f_j(r) = g_n(r_n), so the proof ends with the evaluations f_j(r), to be
+/// opened at the returned point r with the commitment scheme of the f_j
+pub fn prove<F: PrimeField, T: Transcript<F>>(
+    polynomials: &[MultilinearPolynomial<F>],
+    sum: &F,
+    transcript: &mut T,
+) -> Result<(SumCheckProof<F>, Vec<F>), String> {
+    if polynomials.is_empty() {
+        return Err("Sum-check needs at least one polynomial".to_string());
+    }
+    let num_vars = polynomials[0].num_vars();
+    if polynomials.iter().any(|p| p.num_vars() != num_vars) {
+        return Err("Polynomials must have the same number of variables".to_string());
+    }
+    let claimed: F = (0..1 << num_vars)
+        .map(|b| {
+            polynomials
+                .iter()
+                .map(|p| p.evaluations()[b])
+                .product::<F>()
+        })
+        .sum();
+    if claimed != *sum {
+        return Err("Claimed sum doesnt match the polynomials".to_string());
+    }
+
+    transcript.append_scalar(b"sumcheck_sum", sum);
+    let degree = polynomials.len();
+    let mut polynomials = polynomials.to_vec();
+    let mut round_evaluations = Vec::with_capacity(num_vars);
+    let mut point = Vec::with_capacity(num_vars);
+    for _ in 0..num_vars {
+        let evaluations = round_polynomial(&polynomials, degree);
+        transcript.append_scalars(b"sumcheck_round", &evaluations);
+        let r = transcript.challenge_scalar(b"sumcheck_challenge");
+        polynomials = polynomials.iter().map(|p| p.fix_variable(&r)).collect();
+        round_evaluations.push(evaluations);
+        point.push(r);
+    }
+
+    let final_evaluations: Vec<F> = polynomials.iter().map(|p| p.evaluations()[0]).collect();
+    transcript.append_scalars(b"sumcheck_final", &final_evaluations);
+
+    Ok((
+        SumCheckProof {
+            round_evaluations,
+            final_evaluations,
+        },
+        point,
+    ))
+}
+
+/// Evaluations of g(X) = Sum{b} Prod{j} f_j(X, b) at X = 0, ..., d. Along the first variable f_j(X, b) is the line
+/// through f_j(0, b) and f_j(1, b), so its values at consecutive integers differ by f_j(1, b) - f_j(0, b)
+fn round_polynomial<F: PrimeField>(
+    polynomials: &[MultilinearPolynomial<F>],
+    degree: usize,
+) -> Vec<F> {
+    let mut evaluations = vec![F::zero(); degree + 1];
+    let half = polynomials[0].len() / 2;
+    let mut values = vec![F::zero(); polynomials.len()];
+    let mut steps = vec![F::zero(); polynomials.len()];
+    for k in 0..half {
+        for (j, p) in polynomials.iter().enumerate() {
+            values[j] = p.evaluations()[2 * k];
+            steps[j] = p.evaluations()[2 * k + 1] - values[j];
+        }
+        for evaluation in evaluations.iter_mut() {
+            *evaluation += values.iter().product::<F>();
+            for (value, step) in values.iter_mut().zip(&steps) {
+                *value += step;
+            }
+        }
+    }
+    evaluations
+}
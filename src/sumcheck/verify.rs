@@ -0,0 +1,44 @@
+use ark_ff::PrimeField;
+
+use super::{interpolate_at, SumCheckProof};
+use crate::common::transcript::Transcript;
+
+/// Checks every round, g_i(0) + g_i(1) = g_{i-1}(r_{i-1}) starting from g_1(0) + g_1(1) = s, and the final claim
+/// g_n(r_n) = Prod{j} f_j(r). Returns the point r, at which the evaluations f_j(r) of the proof still have to be
+/// verified against the commitments to the f_j, or None if the proof is rejected
+pub fn verify<F: PrimeField, T: Transcript<F>>(
+    num_vars: usize,
+    degree: usize,
+    sum: &F,
+    proof: &SumCheckProof<F>,
+    transcript: &mut T,
+) -> Option<Vec<F>> {
+    if proof.round_evaluations.len() != num_vars
+        || proof.final_evaluations.len() != degree
+        || proof
+            .round_evaluations
+            .iter()
+            .any(|evaluations| evaluations.len() != degree + 1)
+    {
+        return None;
+    }
+
+    transcript.append_scalar(b"sumcheck_sum", sum);
+    let mut claim = *sum;
+    let mut point = Vec::with_capacity(num_vars);
+    for evaluations in &proof.round_evaluations {
+        if evaluations[0] + evaluations[1] != claim {
+            return None;
+        }
+        transcript.append_scalars(b"sumcheck_round", evaluations);
+        let r = transcript.challenge_scalar(b"sumcheck_challenge");
+        claim = interpolate_at(evaluations, &r);
+        point.push(r);
+    }
+
+    if proof.final_evaluations.iter().product::<F>() != claim {
+        return None;
+    }
+    transcript.append_scalars(b"sumcheck_final", &proof.final_evaluations);
+    Some(point)
+}
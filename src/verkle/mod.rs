@@ -0,0 +1,31 @@
+pub mod open;
+pub mod setup;
+pub mod tree;
+pub mod verify;
+
+use ark_ec::pairing::Pairing;
+
+use tree::VerkleKey;
+
+/// Internal node commitments along the path of a key, from the child of the root down, and the leaf found at the end
+/// of the path. The leaf is the key itself for membership, a different key sharing the path or None (empty slot) for
+/// non-membership
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerklePath<P: Pairing> {
+    pub commitments: Vec<P::G1Affine>,
+    pub leaf: Option<(VerkleKey, P::ScalarField)>,
+}
+
+/// Single KZG multiproof for all openings f_j(z_j) = y_j of the paths: D is the commitment to
+/// g(X) = Sum{j} r^j * (f_j(X) - y_j) / (X - z_j) and `proof` opens h(X) - g(X) at t, where h(X) = Sum{j} r^j * f_j(X) / (t - z_j)
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerkleMultiProof<P: Pairing> {
+    pub commitment_d: P::G1Affine,
+    pub proof: P::G1Affine,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerkleProof<P: Pairing> {
+    pub paths: Vec<VerklePath<P>>,
+    pub multiproof: VerkleMultiProof<P>,
+}
@@ -0,0 +1,111 @@
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{Field, One, Zero};
+
+use super::setup::{GlobalVerkleParams, VERKLE_WIDTH};
+use super::tree::{InternalNode, VerkleKey, VerkleNode, VerkleTree};
+use super::{VerkleMultiProof, VerklePath, VerkleProof};
+use crate::common::polynomial::{Polynomial, SparsePolynomial};
+use crate::common::transcript::{Sha256Transcript, Transcript};
+use crate::common::utils;
+use crate::kzg::{commit::commit, open::evaluation_proof};
+
+pub(crate) const MULTIPROOF_LABEL: &[u8] = b"verkle_multiproof";
+
+/// Proves the values of `keys`, present or not, with their paths and a single multiproof of all path openings
+pub fn prove<P: Pairing>(
+    global_params: &GlobalVerkleParams<P>,
+    tree: &VerkleTree<P>,
+    keys: &[VerkleKey],
+) -> Result<VerkleProof<P>, String> {
+    if keys.is_empty() {
+        return Err("No keys to prove".to_string());
+    }
+    let mut paths = Vec::with_capacity(keys.len());
+    let mut openings = Vec::new();
+    for key in keys {
+        let mut node = &tree.root;
+        let mut commitments = Vec::new();
+        let mut leaf = None;
+        for byte in key {
+            let index = *byte as usize;
+            openings.push((node, index));
+            match &node.children[index] {
+                Some(VerkleNode::Internal(child)) => {
+                    commitments.push(child.commitment.into_affine());
+                    node = child;
+                }
+                Some(VerkleNode::Leaf(leaf_key, value)) => {
+                    leaf = Some((*leaf_key, *value));
+                    break;
+                }
+                None => break,
+            }
+        }
+        paths.push(VerklePath { commitments, leaf });
+    }
+
+    let multiproof = multiproof(global_params, &openings)?;
+    Ok(VerkleProof { paths, multiproof })
+}
+
+/// Opens f_j(w^i_j) = y_j for every node j with a single KZG opening: g(X) = Sum{j} r^j * (f_j(X) - y_j) / (X - w^i_j) is
+/// committed in D, and h(X) - g(X) with h(X) = Sum{j} r^j * f_j(X) / (t - w^i_j) is opened at t
+pub(crate) fn multiproof<P: Pairing>(
+    global_params: &GlobalVerkleParams<P>,
+    openings: &[(&InternalNode<P>, usize)],
+) -> Result<VerkleMultiProof<P>, String> {
+    let mut transcript = Sha256Transcript::<P::ScalarField>::new(MULTIPROOF_LABEL);
+    for (node, index) in openings {
+        transcript.append_serializable(b"C", &node.commitment.into_affine());
+        transcript.append_scalar(b"z", &global_params.domain[*index]);
+        transcript.append_scalar(b"y", &node.evaluations[*index]);
+    }
+    let r = transcript.challenge_scalar(b"r");
+
+    let polynomials = openings
+        .iter()
+        .map(|(node, _)| utils::ifft(&node.evaluations, &global_params.domain))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut g_coeffs = vec![P::ScalarField::zero(); VERKLE_WIDTH];
+    let mut r_j = P::ScalarField::one();
+    for ((_, index), coeffs) in openings.iter().zip(&polynomials) {
+        // Quotient of f(X) - f(z) by X - z, the remainder f(z) is dropped
+        let (quotient, _) = Polynomial::div_sparse(
+            Polynomial::from_vector_coefficients(coeffs.clone()),
+            &SparsePolynomial::monomial_from_coefficient(global_params.domain[*index]),
+        )
+        .ok_or("Error in polynomial division")?;
+        for (g, q) in g_coeffs.iter_mut().zip(quotient.coeffs()) {
+            *g += r_j * q;
+        }
+        r_j *= r;
+    }
+    let g_poly = Polynomial::from_vector_coefficients(g_coeffs.clone());
+    let commitment_d = commit(&global_params.kzg_params, &g_poly)?;
+
+    transcript.append_serializable(b"D", &commitment_d);
+    let t = transcript.challenge_scalar(b"t");
+
+    let mut h_minus_g_coeffs: Vec<P::ScalarField> = g_coeffs.iter().map(|g| -*g).collect();
+    let mut r_j = P::ScalarField::one();
+    for ((_, index), coeffs) in openings.iter().zip(&polynomials) {
+        let scalar = r_j
+            * (t - global_params.domain[*index])
+                .inverse()
+                .ok_or("Challenge t is in the domain")?;
+        for (h, f) in h_minus_g_coeffs.iter_mut().zip(coeffs) {
+            *h += scalar * f;
+        }
+        r_j *= r;
+    }
+    let h_minus_g_poly = Polynomial::from_vector_coefficients(h_minus_g_coeffs);
+
+    // Opening only empty nodes gives h(X) - g(X) = 0, whose proof is the identity
+    let (proof, _) = evaluation_proof(&global_params.kzg_params, &h_minus_g_poly, &t)?;
+
+    Ok(VerkleMultiProof {
+        commitment_d,
+        proof,
+    })
+}
@@ -0,0 +1,100 @@
+use ark_ec::{pairing::Pairing, CurveGroup};
+use rand::{thread_rng, CryptoRng, RngCore};
+
+use crate::common::utils;
+use crate::kzg::setup::GlobalKzgParams;
+
+/// Number of children of an internal node, one per value of a key byte
+pub const VERKLE_WIDTH: usize = 256;
+
+/// KZG parameters for polynomials of degree < 256 together with the Lagrange basis L_i(s) * G over the domain
+/// [1, w, ..., w^255], so a node is committed directly from its children as C = Sum{i=0,i=255} v_i * L_i(s) * G
+#[derive(Debug, PartialEq)]
+pub struct GlobalVerkleParams<P: Pairing> {
+    pub kzg_params: GlobalKzgParams<P>,
+    pub lagrange_g1: Vec<P::G1Affine>,
+    pub domain: Vec<P::ScalarField>,
+}
+
+impl<P: Pairing> GlobalVerkleParams<P> {
+    pub fn new() -> Self {
+        Self::new_with_rng(&mut thread_rng())
+    }
+
+    /// Setup with the KZG secret s drawn from `rng`, reproducible from a seeded rng
+    pub fn new_with_rng(rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        verkle_setup(rng)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lagrange_g1.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lagrange_g1.is_empty()
+    }
+
+    pub fn lagrange_get(&self, n: usize) -> Option<&P::G1Affine> {
+        self.lagrange_g1.get(n)
+    }
+
+    pub fn domain_get(&self, n: usize) -> Option<&P::ScalarField> {
+        self.domain.get(n)
+    }
+}
+
+impl<P: Pairing> Default for GlobalVerkleParams<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn verkle_setup<P: Pairing>(rng: &mut (impl RngCore + CryptoRng)) -> GlobalVerkleParams<P> {
+    let kzg_params = GlobalKzgParams::<P>::new_with_rng(VERKLE_WIDTH - 1, rng);
+    let domain = utils::compute_roots_of_unity::<P::ScalarField>(VERKLE_WIDTH as u64)
+        .expect("Verkle width is a power of two");
+
+    // L_i(s) * G = 1/n * Sum{j} w^(-ij) * s^j * G, the inverse FFT of the powers of s
+    let powers_of_g1: Vec<P::G1> = kzg_params.g1_iter().map(|g| (*g).into()).collect();
+    let lagrange_g1 = P::G1::normalize_batch(
+        &utils::ifft(&powers_of_g1, &domain).expect("SRS length matches the domain size"),
+    );
+
+    GlobalVerkleParams {
+        kzg_params,
+        lagrange_g1,
+        domain,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::{Bn254, Fr};
+    use ark_ec::CurveGroup;
+    use ark_std::{UniformRand, Zero};
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::common::polynomial::Polynomial;
+    use crate::kzg::commit::commit;
+
+    #[test]
+    fn test_verkle_setup_lagrange_basis() {
+        let mut rng = thread_rng();
+        let global_params = GlobalVerkleParams::<Bn254>::new();
+        let evaluations: Vec<Fr> = (0..VERKLE_WIDTH).map(|_| Fr::rand(&mut rng)).collect();
+
+        let mut lagrange_commitment = <Bn254 as Pairing>::G1::zero();
+        for (v, l) in evaluations.iter().zip(global_params.lagrange_g1.iter()) {
+            lagrange_commitment += *l * v;
+        }
+        let coeffs = utils::ifft(&evaluations, &global_params.domain).unwrap();
+        let polynomial = Polynomial::from_vector_coefficients(coeffs);
+
+        assert_eq!(global_params.len(), VERKLE_WIDTH);
+        assert_eq!(
+            lagrange_commitment.into_affine(),
+            commit(&global_params.kzg_params, &polynomial).unwrap()
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::CanonicalSerialize;
+use sha2::{Digest as _, Sha256};
+
+use super::setup::{GlobalVerkleParams, VERKLE_WIDTH};
+use crate::common::merkle::hash_to_field;
+
+pub type VerkleKey = [u8; 32];
+
+/// 256-ary key-value tree. Each internal node commits to the field values of its children, child i at w^i, where an
+/// empty slot is 0, a leaf is the hash of its key and value and an internal node is the hash of its commitment. Leaves
+/// are stored at the shallowest depth where their key prefix is unique
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerkleTree<P: Pairing> {
+    pub(crate) root: InternalNode<P>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum VerkleNode<P: Pairing> {
+    Internal(InternalNode<P>),
+    Leaf(VerkleKey, P::ScalarField),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct InternalNode<P: Pairing> {
+    pub(crate) children: Vec<Option<VerkleNode<P>>>,
+    pub(crate) evaluations: Vec<P::ScalarField>,
+    pub(crate) commitment: P::G1,
+}
+
+impl<P: Pairing> VerkleTree<P> {
+    pub fn new() -> Self {
+        Self {
+            root: InternalNode::new(),
+        }
+    }
+
+    pub fn root(&self) -> P::G1Affine {
+        self.root.commitment.into_affine()
+    }
+
+    pub fn get(&self, key: &VerkleKey) -> Option<P::ScalarField> {
+        let mut node = &self.root;
+        for byte in key {
+            match &node.children[*byte as usize] {
+                Some(VerkleNode::Internal(child)) => node = child,
+                Some(VerkleNode::Leaf(leaf_key, value)) if leaf_key == key => return Some(*value),
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Inserts or overwrites the value of a key. Only the commitments along its path change, each one by
+    /// (v'_i - v_i) * L_i(s) * G
+    pub fn insert(
+        &mut self,
+        global_params: &GlobalVerkleParams<P>,
+        key: VerkleKey,
+        value: P::ScalarField,
+    ) -> Result<(), String> {
+        if global_params.len() != VERKLE_WIDTH {
+            return Err("Verkle params width doesnt match the tree width".to_string());
+        }
+        self.root.insert(global_params, key, value, 0)
+    }
+
+    /// Overwrites the value of a key that is already in the tree
+    pub fn update(
+        &mut self,
+        global_params: &GlobalVerkleParams<P>,
+        key: VerkleKey,
+        value: P::ScalarField,
+    ) -> Result<(), String> {
+        if self.get(&key).is_none() {
+            return Err("Key not found".to_string());
+        }
+        self.insert(global_params, key, value)
+    }
+}
+
+impl<P: Pairing> Default for VerkleTree<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Pairing> InternalNode<P> {
+    fn new() -> Self {
+        Self {
+            children: (0..VERKLE_WIDTH).map(|_| None).collect(),
+            evaluations: vec![P::ScalarField::zero(); VERKLE_WIDTH],
+            commitment: P::G1::zero(),
+        }
+    }
+
+    fn insert(
+        &mut self,
+        global_params: &GlobalVerkleParams<P>,
+        key: VerkleKey,
+        value: P::ScalarField,
+        depth: usize,
+    ) -> Result<(), String> {
+        if depth >= key.len() {
+            return Err("Key path exceeds the tree depth".to_string());
+        }
+        let index = key[depth] as usize;
+        let child = match self.children[index].take() {
+            Some(VerkleNode::Internal(mut node)) => {
+                node.insert(global_params, key, value, depth + 1)?;
+                VerkleNode::Internal(node)
+            }
+            Some(VerkleNode::Leaf(leaf_key, leaf_value)) if leaf_key != key => {
+                // Both keys share the prefix up to this depth, push them one level down
+                let mut node = InternalNode::new();
+                node.insert(global_params, leaf_key, leaf_value, depth + 1)?;
+                node.insert(global_params, key, value, depth + 1)?;
+                VerkleNode::Internal(node)
+            }
+            _ => VerkleNode::Leaf(key, value),
+        };
+
+        let evaluation = child.to_field();
+        let delta = evaluation - self.evaluations[index];
+        self.commitment += *global_params.lagrange_get(index).unwrap() * delta;
+        self.evaluations[index] = evaluation;
+        self.children[index] = Some(child);
+        Ok(())
+    }
+}
+
+impl<P: Pairing> VerkleNode<P> {
+    pub(crate) fn to_field(&self) -> P::ScalarField {
+        match self {
+            VerkleNode::Internal(node) => commitment_to_field::<P>(&node.commitment.into_affine()),
+            VerkleNode::Leaf(key, value) => leaf_to_field::<P::ScalarField>(key, value),
+        }
+    }
+}
+
+/// Value of an internal node in its parent, the hash of its commitment
+pub(crate) fn commitment_to_field<P: Pairing>(commitment: &P::G1Affine) -> P::ScalarField {
+    let mut bytes = Vec::with_capacity(commitment.compressed_size());
+    commitment
+        .serialize_compressed(&mut bytes)
+        .expect("Serializing into a vector doesnt fail");
+    let mut hasher = Sha256::new();
+    hasher.update(b"verkle_node");
+    hasher.update(bytes);
+    P::ScalarField::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+/// Value of a leaf in its parent, the hash of its key (as two 128 bit halves) and value
+pub(crate) fn leaf_to_field<F: PrimeField>(key: &VerkleKey, value: &F) -> F {
+    let key_lo = F::from_le_bytes_mod_order(&key[..16]);
+    let key_hi = F::from_le_bytes_mod_order(&key[16..]);
+    hash_to_field(&[key_lo, key_hi, *value])
+}
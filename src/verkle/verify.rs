@@ -0,0 +1,102 @@
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{Field, One, Zero};
+
+use super::open::MULTIPROOF_LABEL;
+use super::setup::GlobalVerkleParams;
+use super::tree::{commitment_to_field, leaf_to_field, VerkleKey};
+use super::{VerkleMultiProof, VerkleProof};
+use crate::common::transcript::{Sha256Transcript, Transcript};
+use crate::kzg::verify::verify as kzg_verify;
+
+/// Verifies the value of each key against the root commitment: Some(v) for membership and None for non-membership.
+/// The verifier rebuilds the opened values of every path, the hash of the next commitment and finally the leaf or 0
+/// for an empty slot, and checks all of them with the multiproof
+pub fn verify<P: Pairing>(
+    global_params: &GlobalVerkleParams<P>,
+    root: &P::G1Affine,
+    keys: &[VerkleKey],
+    values: &[Option<P::ScalarField>],
+    proof: &VerkleProof<P>,
+) -> bool {
+    if keys.is_empty() || keys.len() != values.len() || keys.len() != proof.paths.len() {
+        return false;
+    }
+
+    let mut openings = Vec::new();
+    for ((key, value), path) in keys.iter().zip(values).zip(&proof.paths) {
+        let depth = path.commitments.len() + 1;
+        if depth > key.len() {
+            return false;
+        }
+        let valid_leaf = match (value, &path.leaf) {
+            (Some(value), Some((leaf_key, leaf_value))) => leaf_key == key && leaf_value == value,
+            (None, Some((leaf_key, _))) => leaf_key != key && leaf_key[..depth] == key[..depth],
+            (None, None) => true,
+            (Some(_), None) => false,
+        };
+        if !valid_leaf {
+            return false;
+        }
+
+        let mut commitment = *root;
+        for (i, byte) in key.iter().take(depth).enumerate() {
+            let evaluation = match path.commitments.get(i) {
+                Some(child) => commitment_to_field::<P>(child),
+                None => path
+                    .leaf
+                    .map(|(leaf_key, leaf_value)| leaf_to_field(&leaf_key, &leaf_value))
+                    .unwrap_or_else(P::ScalarField::zero),
+            };
+            openings.push((commitment, *byte as usize, evaluation));
+            if let Some(child) = path.commitments.get(i) {
+                commitment = *child;
+            }
+        }
+    }
+
+    verify_multiproof(global_params, &openings, &proof.multiproof)
+}
+
+/// The verifier computes E = Sum{j} r^j / (t - z_j) * C_j, the evaluation h(t) - g(t) = Sum{j} r^j * y_j / (t - z_j)
+/// and checks the KZG opening of E - D at t
+pub(crate) fn verify_multiproof<P: Pairing>(
+    global_params: &GlobalVerkleParams<P>,
+    openings: &[(P::G1Affine, usize, P::ScalarField)],
+    multiproof: &VerkleMultiProof<P>,
+) -> bool {
+    let mut transcript = Sha256Transcript::<P::ScalarField>::new(MULTIPROOF_LABEL);
+    for (commitment, index, evaluation) in openings {
+        let z = match global_params.domain_get(*index) {
+            Some(z) => z,
+            None => return false,
+        };
+        transcript.append_serializable(b"C", commitment);
+        transcript.append_scalar(b"z", z);
+        transcript.append_scalar(b"y", evaluation);
+    }
+    let r = transcript.challenge_scalar(b"r");
+    transcript.append_serializable(b"D", &multiproof.commitment_d);
+    let t = transcript.challenge_scalar(b"t");
+
+    let mut commitment_e = P::G1::zero();
+    let mut evaluation = P::ScalarField::zero();
+    let mut r_j = P::ScalarField::one();
+    for (commitment, index, y) in openings {
+        let scalar = match (t - global_params.domain[*index]).inverse() {
+            Some(inverse) => r_j * inverse,
+            None => return false,
+        };
+        commitment_e += *commitment * scalar;
+        evaluation += scalar * y;
+        r_j *= r;
+    }
+
+    let commitment = (commitment_e - multiproof.commitment_d).into_affine();
+    kzg_verify(
+        &global_params.kzg_params,
+        &commitment,
+        &multiproof.proof,
+        &t,
+        &evaluation,
+    )
+}
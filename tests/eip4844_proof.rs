@@ -0,0 +1,150 @@
+use ark_bls12_381::{Bls12_381, Fr, G1Affine};
+use ark_ec::AffineRepr;
+use ark_std::UniformRand;
+use rand::thread_rng;
+
+use pcs::eip4844::{
+    bytes_to_field,
+    commit::{blob_to_kzg_commitment, blob_to_polynomial},
+    evaluate_polynomial_in_evaluation_form, field_to_bytes,
+    open::{compute_blob_kzg_proof, compute_kzg_proof},
+    point_to_bytes,
+    setup::load_trusted_setup,
+    verify::{verify_blob_kzg_proof, verify_blob_kzg_proof_batch, verify_kzg_proof},
+    BYTES_PER_BLOB, FIELD_ELEMENTS_PER_BLOB,
+};
+use pcs::kzg::setup::GlobalKzgParams;
+
+// Mainnet KZG ceremony output, as distributed with c-kzg-4844
+const TRUSTED_SETUP: &str = include_str!("data/eip4844/trusted_setup.txt");
+
+// Known answer tests of c-kzg-4844 (src/test/tests.c), computed with the mainnet trusted setup
+const COMMITMENT_FIELD_ELEMENT: &str =
+    "14629a3a39f7b854e6aa49aa2edb450267eac2c14bb2d4f97a0b81a3f57055ad";
+const EXPECTED_COMMITMENT: &str = "91a5e1c143820d2e7bec38a5404c5145807cb88c0abbbecbcb4bccc83a4b417326e337574cff43303f8a6648ecbee7ac";
+const PROOF_FIELD_ELEMENT: &str =
+    "69386e69dbae0357b399b8d645a57a3062dfbe00bd8e97170b9bdd6bc6168a13";
+const PROOF_INPUT_VALUE: &str = "03ea4fb841b4f9e01aa917c5e40dbd67efb4b8d4d9052069595f0647feba320d";
+const EXPECTED_PROOF: &str = "b21f8f9b85e52fd9c4a6d4fb4e9a27ebdc5a09c3f5ca17f6bcd85c26f04953b0e6925607aaebed1087e5cc2fe4b2b356";
+const POINT_AT_INFINITY: &str = "c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn trusted_setup() -> GlobalKzgParams<Bls12_381> {
+    load_trusted_setup(TRUSTED_SETUP).expect("Error loading trusted setup")
+}
+
+fn blob_with_first_element(hex: &str) -> Vec<u8> {
+    let mut blob = vec![0u8; BYTES_PER_BLOB];
+    blob[..32].copy_from_slice(&decode_hex(hex));
+    blob
+}
+
+fn random_blob() -> Vec<u8> {
+    let mut rng = thread_rng();
+    (0..FIELD_ELEMENTS_PER_BLOB)
+        .flat_map(|_| field_to_bytes(&Fr::rand(&mut rng)))
+        .collect()
+}
+
+#[test]
+fn test_eip4844_trusted_setup() {
+    let global_params = trusted_setup();
+
+    assert_eq!(global_params.len(), FIELD_ELEMENTS_PER_BLOB);
+    assert_eq!(global_params.powers_of_g2.len(), 65);
+    assert_eq!(global_params.powers_of_g1[0], G1Affine::generator());
+}
+
+#[test]
+fn test_eip4844_blob_to_kzg_commitment_vector() {
+    let global_params = trusted_setup();
+
+    let blob = blob_with_first_element(COMMITMENT_FIELD_ELEMENT);
+    let commitment = blob_to_kzg_commitment(&global_params, &blob).unwrap();
+    assert_eq!(point_to_bytes(&commitment), decode_hex(EXPECTED_COMMITMENT));
+
+    let zero_blob = vec![0u8; BYTES_PER_BLOB];
+    let commitment = blob_to_kzg_commitment(&global_params, &zero_blob).unwrap();
+    assert_eq!(point_to_bytes(&commitment), decode_hex(POINT_AT_INFINITY));
+}
+
+#[test]
+fn test_eip4844_compute_kzg_proof_vector() {
+    let global_params = trusted_setup();
+    let blob = blob_with_first_element(PROOF_FIELD_ELEMENT);
+    let z: Fr = bytes_to_field(&decode_hex(PROOF_INPUT_VALUE)).unwrap();
+
+    let (proof, y) = compute_kzg_proof(&global_params, &blob, &z).unwrap();
+    let evaluations = blob_to_polynomial::<Fr>(&blob).unwrap();
+    assert_eq!(point_to_bytes(&proof), decode_hex(EXPECTED_PROOF));
+    assert_eq!(y, evaluate_polynomial_in_evaluation_form(&evaluations, &z));
+
+    let commitment = blob_to_kzg_commitment(&global_params, &blob).unwrap();
+    assert!(verify_kzg_proof(
+        &global_params,
+        &commitment,
+        &z,
+        &y,
+        &proof
+    ));
+    assert!(!verify_kzg_proof(
+        &global_params,
+        &commitment,
+        &z,
+        &(y + Fr::from(1u64)),
+        &proof
+    ));
+}
+
+#[test]
+fn test_eip4844_blob_kzg_proof() {
+    let global_params = trusted_setup();
+    let blob = random_blob();
+
+    let commitment = blob_to_kzg_commitment(&global_params, &blob).unwrap();
+    let proof = compute_blob_kzg_proof(&global_params, &blob, &commitment).unwrap();
+    assert!(verify_blob_kzg_proof(&global_params, &blob, &commitment, &proof).unwrap());
+
+    let other_blob = random_blob();
+    assert!(!verify_blob_kzg_proof(&global_params, &other_blob, &commitment, &proof).unwrap());
+    assert!(!verify_blob_kzg_proof(&global_params, &blob, &commitment, &commitment).unwrap());
+
+    // Field elements must be canonical and blobs have a fixed size
+    let mut invalid_blob = blob.clone();
+    invalid_blob[..32].copy_from_slice(&[0xff; 32]);
+    assert!(verify_blob_kzg_proof(&global_params, &invalid_blob, &commitment, &proof).is_err());
+    assert!(blob_to_kzg_commitment(&global_params, &blob[32..]).is_err());
+}
+
+#[test]
+fn test_eip4844_blob_kzg_proof_batch() {
+    let global_params = trusted_setup();
+    let blobs: Vec<Vec<u8>> = (0..3).map(|_| random_blob()).collect();
+    let commitments: Vec<G1Affine> = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment(&global_params, blob).unwrap())
+        .collect();
+    let proofs: Vec<G1Affine> = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| compute_blob_kzg_proof(&global_params, blob, commitment).unwrap())
+        .collect();
+
+    assert!(verify_blob_kzg_proof_batch(&global_params, &blobs, &commitments, &proofs).unwrap());
+    assert!(verify_blob_kzg_proof_batch(&global_params, &[], &[], &[]).unwrap());
+
+    let mut wrong_proofs = proofs.clone();
+    wrong_proofs.swap(0, 1);
+    assert!(
+        !verify_blob_kzg_proof_batch(&global_params, &blobs, &commitments, &wrong_proofs).unwrap()
+    );
+    assert!(
+        verify_blob_kzg_proof_batch(&global_params, &blobs, &commitments, &proofs[..2]).is_err()
+    );
+}
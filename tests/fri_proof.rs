@@ -0,0 +1,139 @@
+use ark_ff::PrimeField;
+
+use pcs::common::polynomial::Polynomial;
+use pcs::curves::pasta::{Fp, Fq};
+use pcs::fri::{commit::commit, open::evaluation_proof, setup::GlobalFriParams, verify::verify};
+use rand::thread_rng;
+
+#[macro_use]
+mod helpers;
+
+fn test_fri_proof<F: PrimeField>() {
+    let mut rng = thread_rng();
+    let degree = 100;
+
+    let poly = Polynomial::<F>::from_random_coefficients(degree);
+    let global_params = GlobalFriParams::<F>::new(degree, 4, 30).unwrap();
+
+    let point_z = F::rand(&mut rng);
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+
+    let (proof, eval_z) = evaluation_proof(&global_params, &poly, &point_z)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(&global_params, &poly_commitment, &proof, &point_z, &eval_z);
+
+    assert_eq!(eval_z, poly.evaluate(&point_z));
+    assert!(result, "FRI commitment verification failed");
+}
+
+fn test_fri_proof_constant_polynomial<F: PrimeField>() {
+    let mut rng = thread_rng();
+
+    let poly = Polynomial::<F>::from_random_coefficients(0);
+    let global_params = GlobalFriParams::<F>::new(0, 2, 4).unwrap();
+
+    let point_z = F::rand(&mut rng);
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+
+    let (proof, eval_z) = evaluation_proof(&global_params, &poly, &point_z)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(&global_params, &poly_commitment, &proof, &point_z, &eval_z);
+
+    assert!(result, "FRI commitment verification failed");
+}
+
+fn test_fri_wrong_evaluation<F: PrimeField>() {
+    let mut rng = thread_rng();
+    let degree = 63;
+
+    let poly = Polynomial::<F>::from_random_coefficients(degree);
+    let global_params = GlobalFriParams::<F>::new(degree, 8, 20).unwrap();
+
+    let point_z = F::rand(&mut rng);
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+
+    let (proof, eval_z) = evaluation_proof(&global_params, &poly, &point_z)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(
+        &global_params,
+        &poly_commitment,
+        &proof,
+        &point_z,
+        &(eval_z + F::one()),
+    );
+
+    assert!(!result, "FRI commitment verification should fail");
+}
+
+fn test_fri_tampered_proof<F: PrimeField>() {
+    let mut rng = thread_rng();
+    let degree = 63;
+
+    let poly = Polynomial::<F>::from_random_coefficients(degree);
+    let global_params = GlobalFriParams::<F>::new(degree, 4, 20).unwrap();
+
+    let point_z = F::rand(&mut rng);
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+
+    let (proof, eval_z) = evaluation_proof(&global_params, &poly, &point_z)
+        .expect("Error evaluatiing polynomial proof");
+
+    // The folding challenges and query positions are bound to every layer root, so changing one changes them all
+    let mut wrong_proof = proof.clone();
+    wrong_proof.layer_roots[0][0] ^= 1;
+    assert!(!verify(
+        &global_params,
+        &poly_commitment,
+        &wrong_proof,
+        &point_z,
+        &eval_z
+    ));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.final_value += F::one();
+    assert!(!verify(
+        &global_params,
+        &poly_commitment,
+        &wrong_proof,
+        &point_z,
+        &eval_z
+    ));
+
+    // Queries answered for another point dont open the positions the verifier asks for
+    let other_z = point_z + F::one();
+    let (other_proof, _) = evaluation_proof(&global_params, &poly, &other_z)
+        .expect("Error evaluatiing polynomial proof");
+    let mut wrong_proof = proof;
+    wrong_proof.queries = other_proof.queries;
+    assert!(!verify(
+        &global_params,
+        &poly_commitment,
+        &wrong_proof,
+        &point_z,
+        &eval_z
+    ));
+}
+
+fn test_fri_commit_degree_too_high<F: PrimeField>() {
+    let degree = 63;
+
+    let poly = Polynomial::<F>::from_random_coefficients(degree + 1);
+    let global_params = GlobalFriParams::<F>::new(degree, 4, 20).unwrap();
+
+    assert!(commit(&global_params, &poly).is_err());
+}
+
+curve_tests!(bn254: ark_bn254::Fr, bls12_381: ark_bls12_381::Fr, pallas: Fp, vesta: Fq; [
+    test_fri_proof,
+    test_fri_proof_constant_polynomial,
+    test_fri_wrong_evaluation,
+    test_fri_tampered_proof,
+    test_fri_commit_degree_too_high,
+]);
@@ -1,24 +1,42 @@
-use ark_bn254::{Bn254, Fr};
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ff::PrimeField;
 use ark_std::UniformRand;
 
+use pcs::common::pcs::PolynomialCommitment;
+use pcs::common::transcript::Sha256Transcript;
 use pcs::common::{polynomial::Polynomial, utils};
-use pcs::kzg::{commit::commit, open::evaluation_proof, setup::GlobalKzgParams, verify::verify};
+use pcs::curves::pasta::{PallasProjective, VestaProjective};
+use pcs::curves::{IpaCurve, KzgCurve};
+use pcs::gadgets::{
+    permutation_check::{prove_permutation_check, verify_permutation_check},
+    product_check::{prove_product_check, verify_product_check},
+    sum_check::{prove_sum_check, verify_sum_check},
+    zero_test::{prove_zero_test, verify_zero_test},
+};
+use pcs::ipa::Ipa;
+use pcs::kzg::{commit::commit, open::evaluation_proof, verify::verify, Kzg};
 use rand::thread_rng;
 
+#[macro_use]
+mod helpers;
+
+const LABEL: &[u8] = b"gadgets";
+const DOMAIN_SIZE: u64 = 16;
+
 /// proof that q(X) = f(X)/z(X). where Z is a the vanishing polynomial of omega
-#[test]
-fn test_kzg_zero_test_on_omega() {
+fn test_kzg_zero_test_on_omega<P: KzgCurve>() {
     let mut rng = thread_rng();
     let order = 16;
     let q_degree = 100;
-    let omega = utils::compute_roots_of_unity::<Fr>(order).unwrap();
+    let omega = utils::compute_roots_of_unity::<P::ScalarField>(order).unwrap();
     let vanishing_poly = Polynomial::from_monomial_coefficients(omega);
-    let global_params = GlobalKzgParams::<Bn254>::new(2 * q_degree);
+    let global_params = P::kzg_setup(2 * q_degree);
 
     let q_poly = Polynomial::from_random_coefficients(q_degree);
     let polynomial = q_poly.clone() * vanishing_poly.clone();
 
-    let point_u = Fr::rand(&mut rng);
+    let point_u = P::ScalarField::rand(&mut rng);
 
     let commit_f = commit(&global_params, &polynomial).unwrap();
     let eval_qu = q_poly.evaluate(&point_u);
@@ -31,3 +49,257 @@ fn test_kzg_zero_test_on_omega() {
     assert_eq!(eval_fu, eval_qu * eval_zu);
     assert!(result, "Polynomial commitment verification failed");
 }
+
+/// Polynomial taking `evaluations` over the domain, plus a random multiple of Z_H so its degree exceeds the domain size
+fn polynomial_from_evaluations<F: PrimeField>(evaluations: &[F], domain: &[F]) -> Polynomial<F> {
+    let vanishing_poly = Polynomial::from_monomial_coefficients(domain.to_vec());
+    Polynomial::from_vector_coefficients(utils::ifft(evaluations, domain).unwrap())
+        + Polynomial::from_random_coefficients(10) * vanishing_poly
+}
+
+fn check_zero_test<F: PrimeField, S: PolynomialCommitment<F>>(params: &S::Params) {
+    let domain = utils::compute_roots_of_unity::<F>(DOMAIN_SIZE).unwrap();
+    let vanishing_poly = Polynomial::from_monomial_coefficients(domain.clone());
+    let polynomial = Polynomial::from_random_coefficients(20) * vanishing_poly;
+    let commitment = S::commit(params, &polynomial).unwrap();
+
+    let proof = prove_zero_test::<F, S, _>(
+        params,
+        &domain,
+        &polynomial,
+        &commitment,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error proving zero test");
+    assert!(verify_zero_test(
+        params,
+        &domain,
+        &commitment,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let other_poly = polynomial + Polynomial::from_vector_coefficients(vec![F::one()]);
+    let other_commitment = S::commit(params, &other_poly).unwrap();
+    assert!(prove_zero_test::<F, S, _>(
+        params,
+        &domain,
+        &other_poly,
+        &other_commitment,
+        &mut Sha256Transcript::new(LABEL)
+    )
+    .is_err());
+    assert!(!verify_zero_test(
+        params,
+        &domain,
+        &other_commitment,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof;
+    wrong_proof.q_r.value += F::one();
+    assert!(!verify_zero_test(
+        params,
+        &domain,
+        &commitment,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+}
+
+fn check_sum_check<F: PrimeField, S: PolynomialCommitment<F>>(params: &S::Params) {
+    let domain = utils::compute_roots_of_unity::<F>(DOMAIN_SIZE).unwrap();
+    let polynomial = Polynomial::from_random_coefficients(40);
+    let sum: F = domain.iter().map(|a| polynomial.evaluate(a)).sum();
+    let commitment = S::commit(params, &polynomial).unwrap();
+
+    let proof = prove_sum_check::<F, S, _>(
+        params,
+        &domain,
+        &polynomial,
+        &commitment,
+        &sum,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error proving sum check");
+    assert!(verify_sum_check(
+        params,
+        &domain,
+        &commitment,
+        &sum,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let wrong_sum = sum + F::one();
+    assert!(prove_sum_check::<F, S, _>(
+        params,
+        &domain,
+        &polynomial,
+        &commitment,
+        &wrong_sum,
+        &mut Sha256Transcript::new(LABEL)
+    )
+    .is_err());
+    assert!(!verify_sum_check(
+        params,
+        &domain,
+        &commitment,
+        &wrong_sum,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof;
+    wrong_proof.g_shifted_r = wrong_proof.g_r.clone();
+    assert!(!verify_sum_check(
+        params,
+        &domain,
+        &commitment,
+        &sum,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+}
+
+fn check_product_check<F: PrimeField, S: PolynomialCommitment<F>>(params: &S::Params) {
+    let mut rng = thread_rng();
+    let domain = utils::compute_roots_of_unity::<F>(DOMAIN_SIZE).unwrap();
+    let mut evaluations: Vec<F> = (1..DOMAIN_SIZE).map(|_| F::rand(&mut rng)).collect();
+    let product: F = evaluations.iter().product();
+    evaluations.push(product.inverse().unwrap());
+    let polynomial = polynomial_from_evaluations(&evaluations, &domain);
+    let commitment = S::commit(params, &polynomial).unwrap();
+
+    let proof = prove_product_check::<F, S, _>(
+        params,
+        &domain,
+        &polynomial,
+        &commitment,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error proving product check");
+    assert!(verify_product_check(
+        params,
+        &domain,
+        &commitment,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    evaluations[0].double_in_place();
+    let other_poly = polynomial_from_evaluations(&evaluations, &domain);
+    let other_commitment = S::commit(params, &other_poly).unwrap();
+    assert!(prove_product_check::<F, S, _>(
+        params,
+        &domain,
+        &other_poly,
+        &other_commitment,
+        &mut Sha256Transcript::new(LABEL)
+    )
+    .is_err());
+    assert!(!verify_product_check(
+        params,
+        &domain,
+        &other_commitment,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof;
+    wrong_proof.t_last.value += F::one();
+    assert!(!verify_product_check(
+        params,
+        &domain,
+        &commitment,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+}
+
+fn check_permutation_check<F: PrimeField, S: PolynomialCommitment<F>>(params: &S::Params) {
+    let mut rng = thread_rng();
+    let domain = utils::compute_roots_of_unity::<F>(DOMAIN_SIZE).unwrap();
+    let f_evals: Vec<F> = (0..DOMAIN_SIZE).map(|_| F::rand(&mut rng)).collect();
+    let mut g_evals = f_evals.clone();
+    g_evals.reverse();
+    g_evals.swap(0, 5);
+    let f_poly = polynomial_from_evaluations(&f_evals, &domain);
+    let g_poly = polynomial_from_evaluations(&g_evals, &domain);
+    let commitment_f = S::commit(params, &f_poly).unwrap();
+    let commitment_g = S::commit(params, &g_poly).unwrap();
+
+    let proof = prove_permutation_check::<F, S, _>(
+        params,
+        &domain,
+        &f_poly,
+        &g_poly,
+        &commitment_f,
+        &commitment_g,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error proving permutation check");
+    assert!(verify_permutation_check(
+        params,
+        &domain,
+        &commitment_f,
+        &commitment_g,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    g_evals[3] = f_evals[4];
+    let other_poly = polynomial_from_evaluations(&g_evals, &domain);
+    let other_commitment = S::commit(params, &other_poly).unwrap();
+    assert!(prove_permutation_check::<F, S, _>(
+        params,
+        &domain,
+        &f_poly,
+        &other_poly,
+        &commitment_f,
+        &other_commitment,
+        &mut Sha256Transcript::new(LABEL)
+    )
+    .is_err());
+    assert!(!verify_permutation_check(
+        params,
+        &domain,
+        &commitment_f,
+        &other_commitment,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof;
+    wrong_proof.g_wx = None;
+    assert!(!verify_permutation_check(
+        params,
+        &domain,
+        &commitment_f,
+        &commitment_g,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+}
+
+fn test_kzg_gadgets<P: KzgCurve>() {
+    let global_params = P::kzg_setup(63);
+    check_zero_test::<_, Kzg<P>>(&global_params);
+    check_sum_check::<_, Kzg<P>>(&global_params);
+    check_product_check::<_, Kzg<P>>(&global_params);
+    check_permutation_check::<_, Kzg<P>>(&global_params);
+}
+
+fn test_ipa_gadgets<P: IpaCurve>() {
+    let global_params = P::ipa_setup(63);
+    check_zero_test::<_, Ipa<P>>(&global_params);
+    check_sum_check::<_, Ipa<P>>(&global_params);
+    check_product_check::<_, Ipa<P>>(&global_params);
+    check_permutation_check::<_, Ipa<P>>(&global_params);
+}
+
+curve_tests!(bn254: Bn254, bls12_381: Bls12_381; [test_kzg_zero_test_on_omega, test_kzg_gadgets]);
+curve_tests!(ipa_bn254: ark_bn254::G1Projective, pallas: PallasProjective, vesta: VestaProjective; [
+    test_ipa_gadgets,
+]);
@@ -1,60 +1,88 @@
-#![allow(dead_code)]
+#![allow(dead_code, unused_macros)]
 
-use ark_bn254::Fr;
-use ark_bn254::G1Projective;
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
 use ark_std::One;
 use ark_std::UniformRand;
 
 use pcs::common::polynomial::Polynomial;
-use rand::thread_rng;
+use rand::{thread_rng, CryptoRng, RngCore};
+
+/// Runs generic test functions once per curve, each one in a module named after the curve, e.g.
+/// curve_tests!(bn254: Bn254, bls12_381: Bls12_381; [test_a, test_b]) adds the tests bn254::test_a, bn254::test_b,
+/// bls12_381::test_a and bls12_381::test_b
+macro_rules! curve_tests {
+    (@curve $curve:ident, $ty:ty, [$($test:ident),* $(,)?]) => {
+        mod $curve {
+            #[allow(unused_imports)]
+            use super::*;
+            $(
+                #[test]
+                fn $test() {
+                    super::$test::<$ty>();
+                }
+            )*
+        }
+    };
+    ($($curve:ident: $ty:ty),+ $(,)?; $tests:tt) => {
+        $( curve_tests!(@curve $curve, $ty, $tests); )+
+    };
+}
 
 #[derive(Debug, Clone)]
-pub(crate) struct SplitIvcIpaAccumulatorInput {
-    pub batched_polys_data: Option<BatchedPolynomialData>,
-    pub commitment: G1Projective,
-    pub x_value: Fr,
-    pub f_x: Fr,
-    pub l_r_group: Vec<(G1Projective, G1Projective)>,
-    pub g_m: G1Projective,
-    pub a_m: Fr,
-    pub u_group: G1Projective,
-    pub u_values: Vec<Fr>,
-    pub rho_values: Vec<Fr>,
+pub(crate) struct SplitIvcIpaAccumulatorInput<P: CurveGroup> {
+    pub batched_polys_data: Option<BatchedPolynomialData<P>>,
+    pub commitment: P,
+    pub x_value: P::ScalarField,
+    pub f_x: P::ScalarField,
+    pub l_r_group: Vec<(P, P)>,
+    pub g_m: P,
+    pub a_m: P::ScalarField,
+    pub u_group: P,
+    pub u_values: Vec<P::ScalarField>,
+    pub rho_values: Vec<P::ScalarField>,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct BatchedPolynomialData {
-    pub polys: Vec<Polynomial<Fr>>,
-    pub z_poly: Polynomial<Fr>,
-    pub zi_polys: Vec<Polynomial<Fr>>,
-    pub commitments: Vec<G1Projective>,
+pub(crate) struct BatchedPolynomialData<P: CurveGroup> {
+    pub polys: Vec<Polynomial<P::ScalarField>>,
+    pub z_poly: Polynomial<P::ScalarField>,
+    pub zi_polys: Vec<Polynomial<P::ScalarField>>,
+    pub commitments: Vec<P>,
 }
 
-pub(crate) fn generate_polynomials(
+pub(crate) fn generate_polynomials<P: CurveGroup>(
     n_polys: usize,
     degree: usize,
     n_omegas: usize,
-) -> BatchedPolynomialData {
-    let mut rng = thread_rng();
+) -> BatchedPolynomialData<P> {
+    generate_polynomials_with_rng(n_polys, degree, n_omegas, &mut thread_rng())
+}
 
+/// `generate_polynomials` with the polynomials and their roots drawn from `rng`
+pub(crate) fn generate_polynomials_with_rng<P: CurveGroup>(
+    n_polys: usize,
+    degree: usize,
+    n_omegas: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> BatchedPolynomialData<P> {
     let mut polys = Vec::with_capacity(n_polys);
-    let mut omegas: Vec<Vec<Fr>> = vec![vec![]];
-    let mut zi_polys: Vec<Polynomial<Fr>> = vec![];
-    let mut z_poly = Polynomial::<Fr>::from_vector_coefficients(vec![Fr::one()]);
+    let mut omegas: Vec<Vec<P::ScalarField>> = vec![vec![]];
+    let mut zi_polys: Vec<Polynomial<P::ScalarField>> = vec![];
+    let mut z_poly = Polynomial::from_vector_coefficients(vec![P::ScalarField::one()]);
 
     for i in 0..n_polys {
-        omegas.push((0..n_omegas).map(|_| Fr::rand(&mut rng)).collect());
-        zi_polys.push(Polynomial::<Fr>::from_monomial_coefficients(
-            omegas[i].clone(),
-        ));
-        polys.push(Polynomial::<Fr>::from_random_coefficients(degree) * zi_polys[i].clone());
+        omegas.push((0..n_omegas).map(|_| P::ScalarField::rand(rng)).collect());
+        zi_polys.push(Polynomial::from_monomial_coefficients(omegas[i].clone()));
+        polys
+            .push(Polynomial::from_random_coefficients_with_rng(degree, rng) * zi_polys[i].clone());
         z_poly = z_poly * zi_polys[i].clone();
     }
 
-    for i in 0..n_polys {
-        let t = Polynomial::<Fr>::div_polynomials(z_poly.clone(), &zi_polys[i]).unwrap();
+    for zi_poly in zi_polys.iter_mut() {
+        let t = Polynomial::div_polynomials(z_poly.clone(), zi_poly).unwrap();
         assert!(t.1.is_zero());
-        zi_polys[i] = t.0;
+        *zi_poly = t.0;
     }
     BatchedPolynomialData {
         polys,
@@ -63,3 +91,20 @@ pub(crate) fn generate_polynomials(
         commitments: vec![],
     }
 }
+
+// Evaluates the multilinear extension as Sum{b} f(b) * eq(b, z)
+pub(crate) fn evaluate_multilinear<F: PrimeField>(evaluations: &[F], point: &[F]) -> F {
+    evaluations
+        .iter()
+        .enumerate()
+        .map(|(b, f_b)| {
+            point.iter().enumerate().fold(*f_b, |acc, (i, z_i)| {
+                if (b >> i) & 1 == 1 {
+                    acc * z_i
+                } else {
+                    acc * (F::one() - z_i)
+                }
+            })
+        })
+        .sum()
+}
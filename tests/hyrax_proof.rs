@@ -0,0 +1,121 @@
+use ark_ec::CurveGroup;
+use ark_std::{One, UniformRand};
+
+use pcs::curves::pasta::{PallasProjective, VestaProjective};
+use pcs::curves::IpaCurve;
+use pcs::ipa::{
+    hyrax::{commit, evaluation_proof, matrix_dimensions, verify, HyraxProof},
+    setup::GlobalIpaParams,
+};
+use rand::thread_rng;
+
+#[macro_use]
+mod helpers;
+use helpers::*;
+
+struct HyraxOpening<P: CurveGroup> {
+    global_params: GlobalIpaParams<P>,
+    row_commitments: Vec<P>,
+    evaluations: Vec<P::ScalarField>,
+    point: Vec<P::ScalarField>,
+    proof: HyraxProof<P>,
+    eval_r: P::ScalarField,
+}
+
+/// Commits to random evaluations in `num_vars` variables and opens them at a random point
+fn open_random<P: IpaCurve>(num_vars: usize) -> HyraxOpening<P> {
+    let mut rng = thread_rng();
+    let (_, n_columns) = matrix_dimensions(num_vars);
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let point: Vec<P::ScalarField> = (0..num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = P::ipa_setup(n_columns - 1);
+
+    let row_commitments = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proof, eval_r) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    HyraxOpening {
+        global_params,
+        row_commitments,
+        evaluations,
+        point,
+        proof,
+        eval_r,
+    }
+}
+
+fn test_hyrax_proof<P: IpaCurve>() {
+    let num_vars = 12;
+    let opening = open_random::<P>(num_vars);
+
+    let result = verify(
+        &opening.global_params,
+        &opening.row_commitments,
+        &opening.proof,
+        &opening.point,
+        &opening.eval_r,
+    );
+
+    assert_eq!(opening.row_commitments.len(), matrix_dimensions(num_vars).0);
+    assert_eq!(
+        opening.eval_r,
+        evaluate_multilinear(&opening.evaluations, &opening.point)
+    );
+    assert!(result, "Hyrax commitment verification failed");
+}
+
+fn test_hyrax_proof_odd_variables<P: IpaCurve>() {
+    let opening = open_random::<P>(7);
+
+    let result = verify(
+        &opening.global_params,
+        &opening.row_commitments,
+        &opening.proof,
+        &opening.point,
+        &opening.eval_r,
+    );
+
+    assert_eq!(
+        opening.eval_r,
+        evaluate_multilinear(&opening.evaluations, &opening.point)
+    );
+    assert!(result, "Hyrax commitment verification failed");
+}
+
+fn test_hyrax_wrong_evaluation<P: IpaCurve>() {
+    let opening = open_random::<P>(6);
+    let verify_proof = |proof: &HyraxProof<P>, eval_r: &P::ScalarField| {
+        verify(
+            &opening.global_params,
+            &opening.row_commitments,
+            proof,
+            &opening.point,
+            eval_r,
+        )
+    };
+
+    assert!(
+        !verify_proof(&opening.proof, &(opening.eval_r + P::ScalarField::one())),
+        "Hyrax commitment verification should fail"
+    );
+
+    let mut wrong_proof = opening.proof.clone();
+    wrong_proof.l_r_group[0].1 += P::generator();
+    assert!(!verify_proof(&wrong_proof, &opening.eval_r));
+
+    let mut wrong_proof = opening.proof.clone();
+    wrong_proof.a_0 += P::ScalarField::one();
+    assert!(!verify_proof(&wrong_proof, &opening.eval_r));
+}
+
+curve_tests!(bn254: ark_bn254::G1Projective, pallas: PallasProjective, vesta: VestaProjective; [
+    test_hyrax_proof,
+    test_hyrax_proof_odd_variables,
+    test_hyrax_wrong_evaluation,
+]);
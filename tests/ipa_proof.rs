@@ -1,30 +1,38 @@
-use ark_bn254::{Fr, G1Projective};
+use ark_ec::CurveGroup;
 use ark_std::UniformRand;
 
 use ark_std::Zero;
+use pcs::common::pcs::PolynomialCommitment;
 use pcs::common::polynomial::Polynomial;
+use pcs::common::poseidon::PoseidonParams;
+use pcs::common::transcript::{PoseidonTranscript, Sha256Transcript, Transcript};
+use pcs::curves::pasta::{PallasProjective, VestaProjective};
+use pcs::curves::IpaCurve;
 use pcs::ipa::commit::batch_commit;
 use pcs::ipa::verify::compute_s;
 use pcs::ipa::{
     commit::commit,
-    open::{batch_evaluation_proof, evaluation_proof},
+    open::{
+        batch_evaluation_proof, evaluation_proof, evaluation_proof_with_rng, inner_product_argument,
+    },
     setup::GlobalIpaParams,
-    verify::{batch_verify, verify},
+    verify::{batch_verify, verify, verify_inner_product_argument},
+    Ipa,
 };
-use rand::thread_rng;
+use rand::{rngs::StdRng, thread_rng, SeedableRng};
 
+#[macro_use]
 mod helpers;
 use helpers::*;
 
-#[test]
-fn test_ipa_proof() {
+fn test_ipa_proof<P: IpaCurve>() {
     let mut rng = thread_rng();
     let degree = 127;
 
-    let poly = Polynomial::<Fr>::from_random_coefficients(degree);
-    let global_params = GlobalIpaParams::<G1Projective>::new(degree);
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients(degree);
+    let global_params = P::ipa_setup(degree);
 
-    let point_x = Fr::rand(&mut rng);
+    let point_x = P::ScalarField::rand(&mut rng);
 
     let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
 
@@ -45,17 +53,31 @@ fn test_ipa_proof() {
     );
 
     assert!(result, "Polynomial commitment verification failed");
+
+    // A zero challenge is rejected instead of inverted
+    let mut wrong_u_values = u_values.clone();
+    wrong_u_values[0] = P::ScalarField::zero();
+    assert!(!verify(
+        &global_params,
+        &poly_commitment,
+        &f_x,
+        &point_x,
+        &l_r_group,
+        &a_m,
+        &None,
+        &wrong_u_values,
+        &u_group_element,
+    ));
 }
 
 // emulates a plonk proof with multiple polynomials and multiple commitments
-#[test]
-fn test_ipa_batch_proof() {
+fn test_ipa_batch_proof<P: IpaCurve>() {
     let mut rng = thread_rng();
     let n_polys = 200;
     let n_omegas = 20;
     let degree = 127 - n_omegas;
 
-    let global_params = GlobalIpaParams::<G1Projective>::new(4 * degree);
+    let global_params = P::ipa_setup(4 * degree);
 
     let mut batched_polys_data = generate_polynomials(n_polys, degree, n_omegas);
 
@@ -73,7 +95,7 @@ fn test_ipa_batch_proof() {
     )
     .unwrap();
 
-    let point_x = Fr::rand(&mut rng);
+    let point_x = P::ScalarField::rand(&mut rng);
     let (a_m, _g_m, l_r_group, u_values, u_group_element) = batch_evaluation_proof(
         &global_params,
         &batched_polys_data.polys,
@@ -92,7 +114,7 @@ fn test_ipa_batch_proof() {
         &batched_polys_data.z_poly,
         &batched_polys_data.zi_polys,
         &point_x,
-        &Fr::zero(),
+        &P::ScalarField::zero(),
         &l_r_group,
         &a_m,
         &None,
@@ -103,16 +125,15 @@ fn test_ipa_batch_proof() {
     assert!(result, "Polynomial batch commitment verification failed");
 }
 
-#[test]
-fn test_ipa_split_ivc() {
+fn test_ipa_split_ivc<P: IpaCurve>() {
     let mut rng = thread_rng();
     let n_polys = 200;
     let n_omegas = 20;
     let degree = 127 - n_omegas;
     let n_iterations = 1;
 
-    let global_params = GlobalIpaParams::<G1Projective>::new(4 * degree);
-    let mut proofs: Vec<SplitIvcIpaAccumulatorInput> = Vec::with_capacity(n_iterations);
+    let global_params = P::ipa_setup(4 * degree);
+    let mut proofs: Vec<SplitIvcIpaAccumulatorInput<P>> = Vec::with_capacity(n_iterations);
 
     let mut batched_polys_data = generate_polynomials(n_polys, degree, n_omegas);
 
@@ -130,7 +151,7 @@ fn test_ipa_split_ivc() {
     )
     .unwrap();
 
-    let x_value = Fr::rand(&mut rng);
+    let x_value = P::ScalarField::rand(&mut rng);
     let (a_m, g_m, l_r_group, u_values, u_group) = batch_evaluation_proof(
         &global_params,
         &batched_polys_data.polys,
@@ -146,7 +167,7 @@ fn test_ipa_split_ivc() {
         batched_polys_data: Some(batched_polys_data),
         commitment: q_commit,
         x_value,
-        f_x: Fr::zero(),
+        f_x: P::ScalarField::zero(),
         l_r_group,
         g_m,
         a_m,
@@ -161,14 +182,22 @@ fn test_ipa_split_ivc() {
 
     // Accumulator phase
 
-    let zero = Fr::zero();
-    let mut accumulator_inputs: [Option<SplitIvcIpaAccumulatorInput>; 2] = [None, None];
+    let zero = P::ScalarField::zero();
+    let poseidon_params = PoseidonParams::<P::ScalarField>::new(3).unwrap();
+    let mut accumulator_inputs: [Option<SplitIvcIpaAccumulatorInput<P>>; 2] = [None, None];
     for i in 0..=n_iterations {
         accumulator_inputs[0] = proofs.get(i).cloned();
-        let alpha = Fr::rand(&mut rng);
-        let acc_x_value = Fr::rand(&mut rng);
-        let mut acc_commitment = G1Projective::zero();
-        let mut acc_s: Vec<Fr> = vec![];
+        // accumulation challenges are derived from the inputs with an algebraic transcript
+        let mut transcript =
+            PoseidonTranscript::new(poseidon_params.clone(), b"split_ivc_accumulator");
+        for input in accumulator_inputs.iter().flatten() {
+            transcript.append_serializable(b"g_m", &input.g_m);
+            transcript.append_scalar(b"x_value", &input.x_value);
+        }
+        let alpha = transcript.challenge_scalar(b"alpha");
+        let acc_x_value = transcript.challenge_scalar(b"acc_x_value");
+        let mut acc_commitment = P::zero();
+        let mut acc_s: Vec<P::ScalarField> = vec![];
         // succint check on input[0] and [1]
         if let Some(input) = &accumulator_inputs[0] {
             let batched_polys_data = input.batched_polys_data.as_ref().unwrap();
@@ -204,15 +233,15 @@ fn test_ipa_split_ivc() {
                 &input.u_group,
             );
             assert!(succint_verification2);
-            acc_commitment = acc_commitment + input.g_m * alpha;
+            acc_commitment += input.g_m * alpha;
             let s = compute_s(&input.u_values, 1 << input.l_r_group.len());
             acc_s = s
                 .iter()
                 .enumerate()
-                .map(|(idx, s1)| *s1 * alpha + acc_s.get(idx).unwrap_or_else(|| &zero))
+                .map(|(idx, s1)| *s1 * alpha + acc_s.get(idx).unwrap_or(&zero))
                 .collect();
         }
-        let poly_s = Polynomial::<Fr>::from_vector_coefficients(acc_s);
+        let poly_s = Polynomial::<P::ScalarField>::from_vector_coefficients(acc_s);
         let acc_v_value = poly_s.evaluate(&acc_x_value);
 
         let (acc_a_m, acc_g_m, acc_l_r_group, _f_x, acc_u_values, acc_u_group_element) =
@@ -248,3 +277,185 @@ fn test_ipa_split_ivc() {
     );
     assert!(final_verification);
 }
+
+fn test_inner_product_argument<P: IpaCurve>() {
+    let mut rng = thread_rng();
+    let n = 64;
+    let global_params = P::ipa_setup(2 * n - 1);
+    let (g, h) = global_params.g_coeffs().split_at(n);
+    let u = global_params.h_get();
+
+    let a: Vec<P::ScalarField> = (0..n).map(|_| P::ScalarField::rand(&mut rng)).collect();
+    let b: Vec<P::ScalarField> = (0..n).map(|_| P::ScalarField::rand(&mut rng)).collect();
+    let (proof, commitment, c) =
+        inner_product_argument(&a, &b, g, h, &u, &mut Sha256Transcript::new(b"ipa"))
+            .expect("Error computing inner product argument");
+
+    let expected_commitment: P = a.iter().zip(g).map(|(a, g)| *g * a).sum::<P>()
+        + b.iter().zip(h).map(|(b, h)| *h * b).sum::<P>();
+    let expected_c: P::ScalarField = a.iter().zip(&b).map(|(a, b)| *a * b).sum();
+    assert_eq!(commitment, expected_commitment);
+    assert_eq!(c, expected_c);
+    assert_eq!(proof.l_r_group.len(), 6);
+
+    let result = verify_inner_product_argument(
+        g,
+        h,
+        &u,
+        &commitment,
+        &c,
+        n,
+        &proof,
+        &mut Sha256Transcript::new(b"ipa"),
+    );
+    assert!(result, "Inner product argument verification failed");
+
+    let wrong_c = c + P::ScalarField::from(1u64);
+    let result = verify_inner_product_argument(
+        g,
+        h,
+        &u,
+        &commitment,
+        &wrong_c,
+        n,
+        &proof,
+        &mut Sha256Transcript::new(b"ipa"),
+    );
+    assert!(!result, "Wrong inner product should not verify");
+
+    // Swapping the generators of a and b changes the committed vectors
+    let result = verify_inner_product_argument(
+        h,
+        g,
+        &u,
+        &commitment,
+        &c,
+        n,
+        &proof,
+        &mut Sha256Transcript::new(b"ipa"),
+    );
+    assert!(!result, "Proof should not verify for other generators");
+
+    // The number of rounds must match the length of the vectors
+    let mut long_proof = proof.clone();
+    long_proof.l_r_group.push(proof.l_r_group[0]);
+    for (proof, n) in [(&proof, n / 2), (&proof, n - 1), (&long_proof, n)] {
+        let result = verify_inner_product_argument(
+            g,
+            h,
+            &u,
+            &commitment,
+            &c,
+            n,
+            proof,
+            &mut Sha256Transcript::new(b"ipa"),
+        );
+        assert!(!result, "Proof should not verify for another vector length");
+    }
+}
+
+fn test_inner_product_argument_invalid_inputs<P: IpaCurve>() {
+    let mut rng = thread_rng();
+    let global_params = P::ipa_setup(15);
+    let (g, h) = global_params.g_coeffs().split_at(8);
+    let u = global_params.h_get();
+    let a: Vec<P::ScalarField> = (0..6).map(|_| P::ScalarField::rand(&mut rng)).collect();
+    let b: Vec<P::ScalarField> = (0..8).map(|_| P::ScalarField::rand(&mut rng)).collect();
+
+    let mut transcript = Sha256Transcript::new(b"ipa");
+    assert!(inner_product_argument(&a, &b[..6], g, h, &u, &mut transcript).is_err());
+    assert!(inner_product_argument(&a[..4], &b, g, h, &u, &mut transcript).is_err());
+    assert!(inner_product_argument(&b, &b, &g[..4], h, &u, &mut transcript).is_err());
+}
+
+fn test_ipa_proof_from_seed<P: CurveGroup>() {
+    let degree = 63;
+    let prove = |seed: u64| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let global_params = GlobalIpaParams::<P>::new_with_rng(degree, &mut rng);
+        let poly =
+            Polynomial::<P::ScalarField>::from_random_coefficients_with_rng(degree, &mut rng);
+        let point_x = P::ScalarField::rand(&mut rng);
+        let commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+        let (a_m, g_m, l_r_group, f_x, u_values, u_group_element) =
+            evaluation_proof_with_rng(&global_params, &poly, &point_x, &mut rng)
+                .expect("Error evaluatiing polynomial proof");
+        assert!(verify(
+            &global_params,
+            &commitment,
+            &f_x,
+            &point_x,
+            &l_r_group,
+            &a_m,
+            &None,
+            &u_values,
+            &u_group_element,
+        ));
+        (global_params, commitment, a_m, g_m, l_r_group, u_values)
+    };
+
+    assert_eq!(prove(7), prove(7));
+    assert_ne!(prove(7).1, prove(8).1);
+}
+
+fn test_ipa_backend<P: IpaCurve>() {
+    let mut rng = thread_rng();
+    let global_params = P::ipa_setup(63);
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients(20);
+    let point_x = P::ScalarField::rand(&mut rng);
+
+    let commitment = Ipa::<P>::commit(&global_params, &poly).expect("Error commiting Polynomial");
+    let (proof, f_x) =
+        Ipa::<P>::open(&global_params, &poly, &point_x).expect("Error opening Polynomial");
+
+    assert_eq!(f_x, poly.evaluate(&point_x));
+    assert_eq!(proof.l_r_group.len(), 6);
+    assert!(Ipa::<P>::verify(
+        &global_params,
+        &commitment,
+        &proof,
+        &point_x,
+        &f_x
+    ));
+    assert!(!Ipa::<P>::verify(
+        &global_params,
+        &commitment,
+        &proof,
+        &point_x,
+        &(f_x + P::ScalarField::from(1u64))
+    ));
+
+    // The challenges are bound to L and R, so changing any of them changes every later challenge
+    let mut wrong_proof = proof.clone();
+    wrong_proof.l_r_group[0].0 += P::generator();
+    assert!(!Ipa::<P>::verify(
+        &global_params,
+        &commitment,
+        &wrong_proof,
+        &point_x,
+        &f_x
+    ));
+
+    // Proofs must have log2(max_degree + 1) rounds, however many L,R pairs they carry
+    for n_rounds in [0, 5, 7, 64, 100] {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.l_r_group.resize(n_rounds, proof.l_r_group[0]);
+        assert!(!Ipa::<P>::verify(
+            &global_params,
+            &commitment,
+            &wrong_proof,
+            &point_x,
+            &f_x
+        ));
+    }
+}
+
+curve_tests!(bn254: ark_bn254::G1Projective, pallas: PallasProjective, vesta: VestaProjective; [
+    test_ipa_proof,
+    test_ipa_batch_proof,
+    test_ipa_split_ivc,
+    test_inner_product_argument,
+    test_inner_product_argument_invalid_inputs,
+    test_ipa_proof_from_seed,
+    test_ipa_backend,
+]);
@@ -0,0 +1,202 @@
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::{One, Zero};
+use ark_std::UniformRand;
+use proptest::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+use pcs::common::polynomial::Polynomial;
+use pcs::curves::pasta::PallasProjective;
+use pcs::ipa::{
+    commit::{batch_commit_with_rng, commit},
+    open::{batch_evaluation_proof_with_rng, evaluation_proof_with_rng},
+    setup::GlobalIpaParams,
+    verify::{batch_verify, verify},
+};
+
+mod helpers;
+use helpers::{generate_polynomials_with_rng, BatchedPolynomialData};
+
+/// The index of a tampered L or R element and whether it is L, whether a[0] is tampered, whether f(x) is tampered
+type Tampering = (Option<(usize, bool)>, bool, bool);
+
+/// Every way of tampering with the IPA proof: one L or R element, a[0] or f(x)
+fn tamperings(n_rounds: usize) -> Vec<Tampering> {
+    let mut tamperings: Vec<_> = (0..n_rounds)
+        .flat_map(|i| {
+            [
+                (Some((i, true)), false, false),
+                (Some((i, false)), false, false),
+            ]
+        })
+        .collect();
+    tamperings.push((None, true, false));
+    tamperings.push((None, false, true));
+    tamperings
+}
+
+fn tamper_l_r<P: CurveGroup + PrimeGroup>(
+    l_r_group: &[(P, P)],
+    tampered: Option<(usize, bool)>,
+) -> Vec<(P, P)> {
+    let mut l_r_group = l_r_group.to_vec();
+    if let Some((i, is_l)) = tampered {
+        if is_l {
+            l_r_group[i].0 += P::generator();
+        } else {
+            l_r_group[i].1 += P::generator();
+        }
+    }
+    l_r_group
+}
+
+/// Generators for polynomials of degree `degree`, whose coefficients are padded to a power of two
+fn padded_params<P: CurveGroup>(degree: usize, rng: &mut StdRng) -> GlobalIpaParams<P> {
+    GlobalIpaParams::new_with_rng((degree + 1).next_power_of_two() - 1, rng)
+}
+
+/// Opens a random polynomial of degree `degree` at a random point, checks that the opening verifies and that changing
+/// any L or R, a[0] or f(x) makes `verify` reject
+fn check_ipa_proof<P: CurveGroup + PrimeGroup>(
+    degree: usize,
+    seed: u64,
+) -> Result<(), TestCaseError> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let global_params = padded_params::<P>(degree, &mut rng);
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients_with_rng(degree, &mut rng);
+    let point_x = P::ScalarField::rand(&mut rng);
+
+    let commitment = commit(&global_params, &poly).unwrap();
+    let (a_0, _, l_r_group, f_x, u_values, u_group) =
+        evaluation_proof_with_rng(&global_params, &poly, &point_x, &mut rng).unwrap();
+    let one = P::ScalarField::one();
+
+    prop_assert_eq!(f_x, poly.evaluate(&point_x));
+    prop_assert!(verify(
+        &global_params,
+        &commitment,
+        &f_x,
+        &point_x,
+        &l_r_group,
+        &a_0,
+        &None,
+        &u_values,
+        &u_group,
+    ));
+    for (tampered_l_r, tampered_a_0, tampered_f_x) in tamperings(l_r_group.len()) {
+        let accepted = verify(
+            &global_params,
+            &commitment,
+            &if tampered_f_x { f_x + one } else { f_x },
+            &point_x,
+            &tamper_l_r(&l_r_group, tampered_l_r),
+            &if tampered_a_0 { a_0 + one } else { a_0 },
+            &None,
+            &u_values,
+            &u_group,
+        );
+        prop_assert!(
+            !accepted,
+            "Tampered L/R {:?}, a_0 {}, f_x {}",
+            tampered_l_r,
+            tampered_a_0,
+            tampered_f_x
+        );
+    }
+    Ok(())
+}
+
+/// Batch opens `n_polys` random polynomials of degree `degree`, each vanishing at one random point, checks that the batch opening verifies and that changing any L or R,
+/// a[0] or f(x) makes `batch_verify` reject
+fn check_ipa_batch_proof<P: CurveGroup + PrimeGroup>(
+    degree: usize,
+    n_polys: usize,
+    seed: u64,
+) -> Result<(), TestCaseError> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let global_params = padded_params::<P>(degree, &mut rng);
+    let BatchedPolynomialData {
+        polys,
+        z_poly,
+        zi_polys,
+        ..
+    } = generate_polynomials_with_rng::<P>(n_polys, degree - 1, 1, &mut rng);
+    let point_x = P::ScalarField::rand(&mut rng);
+
+    let commitments: Vec<P> = polys
+        .iter()
+        .map(|p| commit(&global_params, p).unwrap())
+        .collect();
+    let (commitment_q, q_poly, rho) =
+        batch_commit_with_rng(&global_params, &polys, &z_poly, &zi_polys, &mut rng).unwrap();
+    let (a_0, _, l_r_group, u_values, u_group) = batch_evaluation_proof_with_rng(
+        &global_params,
+        &polys,
+        &q_poly,
+        &z_poly,
+        &zi_polys,
+        &rho,
+        &point_x,
+        &mut rng,
+    )
+    .unwrap();
+    let zero = P::ScalarField::zero();
+    let one = P::ScalarField::one();
+    let accepts =
+        |commitment_q: &P, l_r_group: &[(P, P)], a_0: &P::ScalarField, f_x: P::ScalarField| {
+            batch_verify(
+                &global_params,
+                &commitments,
+                commitment_q,
+                &z_poly,
+                &zi_polys,
+                &point_x,
+                &f_x,
+                l_r_group,
+                a_0,
+                &None,
+                &u_values,
+                &rho,
+                &u_group,
+            )
+        };
+
+    prop_assert!(accepts(&commitment_q, &l_r_group, &a_0, zero));
+    prop_assert!(!accepts(
+        &(commitment_q + P::generator()),
+        &l_r_group,
+        &a_0,
+        zero
+    ));
+    for (tampered_l_r, tampered_a_0, tampered_f_x) in tamperings(l_r_group.len()) {
+        let accepted = accepts(
+            &commitment_q,
+            &tamper_l_r(&l_r_group, tampered_l_r),
+            &if tampered_a_0 { a_0 + one } else { a_0 },
+            if tampered_f_x { one } else { zero },
+        );
+        prop_assert!(
+            !accepted,
+            "Tampered L/R {:?}, a_0 {}, f_x {}",
+            tampered_l_r,
+            tampered_a_0,
+            tampered_f_x
+        );
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+
+    #[test]
+    fn test_ipa_proof_properties(degree in 1_usize..64, seed in any::<u64>()) {
+        check_ipa_proof::<ark_bn254::G1Projective>(degree, seed)?;
+        check_ipa_proof::<PallasProjective>(degree, seed)?;
+    }
+
+    #[test]
+    fn test_ipa_batch_proof_properties(degree in 1_usize..64, n_polys in 1_usize..6, seed in any::<u64>()) {
+        check_ipa_batch_proof::<ark_bn254::G1Projective>(degree, n_polys, seed)?;
+        check_ipa_batch_proof::<PallasProjective>(degree, n_polys, seed)?;
+    }
+}
@@ -0,0 +1,216 @@
+use ark_ec::PrimeGroup;
+use ark_ff::{AdditiveGroup, PrimeField};
+use ark_std::One;
+
+use pcs::common::polynomial::Polynomial;
+use pcs::curves::pasta::{Fp, Fq, PallasProjective, PastaCycle, VestaProjective};
+use pcs::ivc::{
+    accumulate::{accumulate, verify_accumulation},
+    open::prove,
+    setup::{GlobalIvcParams, PastaIvcParams},
+    verify::{decide, verify},
+    IpaAccumulator,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn witnesses<F: PrimeField>(n_steps: usize, degree: usize) -> Vec<Polynomial<F>> {
+    (0..n_steps)
+        .map(|_| Polynomial::from_random_coefficients(degree))
+        .collect()
+}
+
+#[test]
+fn test_ivc_pasta() {
+    let degree = 63;
+    let n_steps: usize = 7;
+    let global_params = PastaIvcParams::new(degree).unwrap();
+    let witnesses_1 = witnesses::<Fq>(n_steps.div_ceil(2), degree);
+    let witnesses_2 = witnesses::<Fp>(n_steps / 2, degree);
+
+    let proof = prove(&global_params, &witnesses_1, &witnesses_2).expect("Error running IVC");
+
+    assert_eq!(proof.openings_1.len(), 4);
+    assert_eq!(proof.openings_2.len(), 3);
+    for (opening, witness) in proof.openings_1.iter().zip(&witnesses_1) {
+        assert_eq!(opening.f_x, witness.evaluate(&opening.x_value));
+    }
+    assert!(verify(&global_params, &proof), "IVC verification failed");
+}
+
+#[test]
+fn test_ivc_even_steps_and_different_degrees() {
+    let global_params = PastaIvcParams::new(127).unwrap();
+    let witnesses_1 = vec![
+        Polynomial::<Fq>::from_random_coefficients(127),
+        Polynomial::<Fq>::from_random_coefficients(15),
+    ];
+    let witnesses_2 = vec![
+        Polynomial::<Fp>::from_random_coefficients(31),
+        Polynomial::<Fp>::from_random_coefficients(127),
+    ];
+
+    let proof = prove(&global_params, &witnesses_1, &witnesses_2).expect("Error running IVC");
+
+    assert!(verify(&global_params, &proof), "IVC verification failed");
+}
+
+#[test]
+fn test_ivc_rejects_tampering() {
+    let degree = 31;
+    let global_params = GlobalIvcParams::<PastaCycle>::new(degree).unwrap();
+    let proof = prove(
+        &global_params,
+        &witnesses::<Fq>(2, degree),
+        &witnesses::<Fp>(2, degree),
+    )
+    .unwrap();
+    assert!(verify(&global_params, &proof));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.openings_2[1].f_x += Fp::one();
+    assert!(!verify(&global_params, &wrong_proof));
+
+    // The opening point of each step is bound to the accumulator of the other curve
+    let mut wrong_proof = proof.clone();
+    wrong_proof.openings_1[1].x_value += Fq::one();
+    assert!(!verify(&global_params, &wrong_proof));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.accumulations_1[0].f_x += Fq::one();
+    assert!(!verify(&global_params, &wrong_proof));
+
+    let mut wrong_proof = proof.clone();
+    let last = wrong_proof.accumulations_2.len() - 1;
+    wrong_proof.accumulations_2[last].g_m += VestaProjective::generator();
+    assert!(!verify(&global_params, &wrong_proof));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.openings_1[0].l_r_group[2].1 += PallasProjective::generator();
+    assert!(!verify(&global_params, &wrong_proof));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.openings_2[0]
+        .l_r_group
+        .push(proof.openings_2[0].l_r_group[0]);
+    assert!(!verify(&global_params, &wrong_proof));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.accumulations_2.pop();
+    assert!(!verify(&global_params, &wrong_proof));
+}
+
+#[test]
+fn test_ivc_from_seed() {
+    let degree = 15;
+    let prove_from_seed = |seed: u64| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let global_params = PastaIvcParams::new_with_rng(degree, &mut rng).unwrap();
+        let witnesses_1 = vec![Polynomial::<Fq>::from_random_coefficients_with_rng(
+            degree, &mut rng,
+        )];
+        let witnesses_2 = vec![Polynomial::<Fp>::from_random_coefficients_with_rng(
+            degree, &mut rng,
+        )];
+        let proof = prove(&global_params, &witnesses_1, &witnesses_2).unwrap();
+        assert!(verify(&global_params, &proof));
+        (global_params, proof)
+    };
+
+    assert_eq!(prove_from_seed(7), prove_from_seed(7));
+    assert_ne!(prove_from_seed(7).1, prove_from_seed(8).1);
+}
+
+#[test]
+fn test_ivc_invalid_steps() {
+    let global_params = PastaIvcParams::new(15).unwrap();
+
+    assert!(prove(&global_params, &witnesses::<Fq>(1, 15), &[]).is_err());
+    assert!(prove(
+        &global_params,
+        &witnesses::<Fq>(3, 15),
+        &witnesses::<Fp>(1, 15)
+    )
+    .is_err());
+    assert!(prove(
+        &global_params,
+        &witnesses::<Fq>(1, 15),
+        &witnesses::<Fp>(2, 15)
+    )
+    .is_err());
+}
+
+#[test]
+fn test_ipa_accumulation_and_decider() {
+    let degree = 63;
+    let global_params = PastaIvcParams::new(degree).unwrap();
+    let proof = prove(
+        &global_params,
+        &witnesses::<Fq>(1, degree),
+        &witnesses::<Fp>(1, degree),
+    )
+    .unwrap();
+
+    // Accumulating the same opening twice on Pallas
+    let opening = &proof.openings_1[0];
+    let (accumulator, accumulation) = accumulate(
+        &global_params.ipa_1,
+        &global_params.poseidon_2,
+        None,
+        opening,
+    )
+    .unwrap();
+    assert_eq!(
+        verify_accumulation(
+            &global_params.ipa_1,
+            &global_params.poseidon_2,
+            None,
+            opening,
+            &accumulation
+        ),
+        Some(accumulator.clone())
+    );
+    let (new_accumulator, new_accumulation) = accumulate(
+        &global_params.ipa_1,
+        &global_params.poseidon_2,
+        Some(&accumulator),
+        opening,
+    )
+    .unwrap();
+    assert_eq!(
+        verify_accumulation(
+            &global_params.ipa_1,
+            &global_params.poseidon_2,
+            Some(&accumulator),
+            opening,
+            &new_accumulation
+        ),
+        Some(new_accumulator.clone())
+    );
+    assert!(verify_accumulation(
+        &global_params.ipa_1,
+        &global_params.poseidon_2,
+        None,
+        opening,
+        &new_accumulation
+    )
+    .is_none());
+
+    // The IPA challenges are drawn from the transcript, so the L,R vectors can't be chosen after them
+    let mut wrong_opening = opening.clone();
+    wrong_opening.l_r_group[0].0 += PallasProjective::generator();
+    assert!(accumulate(
+        &global_params.ipa_1,
+        &global_params.poseidon_2,
+        None,
+        &wrong_opening,
+    )
+    .is_err());
+    assert!(decide(&global_params.ipa_1, &accumulator));
+    assert!(decide(&global_params.ipa_1, &new_accumulator));
+
+    let wrong_accumulator = IpaAccumulator {
+        g_m: accumulator.g_m.double(),
+        u_values: accumulator.u_values.clone(),
+    };
+    assert!(!decide(&global_params.ipa_1, &wrong_accumulator));
+}
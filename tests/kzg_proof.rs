@@ -1,24 +1,29 @@
-use ark_bn254::{Bn254, Fr};
-use ark_std::UniformRand;
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_std::{UniformRand, Zero};
 
 use pcs::common::polynomial::Polynomial;
+use pcs::curves::KzgCurve;
 use pcs::kzg::{
-    commit::commit,
-    open::{batch_evaluation_proof, evaluation_proof},
+    commit::{commit, commit_with_degree_bound},
+    open::{batch_evaluation_proof, evaluation_proof, evaluation_proof_with_degree_bound},
     setup::GlobalKzgParams,
-    verify::{batch_verify, verify},
+    verify::{batch_verify, verify, verify_degree_bound, verify_with_degree_bound},
 };
-use rand::thread_rng;
+use rand::{rngs::StdRng, thread_rng, SeedableRng};
 
-#[test]
-fn test_kzg_proof() {
+#[macro_use]
+mod helpers;
+
+fn test_kzg_proof<P: KzgCurve>() {
     let mut rng = thread_rng();
     let degree = 100;
 
-    let poly = Polynomial::<Fr>::from_random_coefficients(degree);
-    let global_params = GlobalKzgParams::<Bn254>::new(degree);
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients(degree);
+    let global_params = P::kzg_setup(degree);
 
-    let point_u = Fr::rand(&mut rng);
+    let point_u = P::ScalarField::rand(&mut rng);
 
     let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
 
@@ -30,18 +35,17 @@ fn test_kzg_proof() {
     assert!(result, "Polynomial commitment verification failed");
 }
 
-#[test]
-fn test_kzg_batch_proof() {
+fn test_kzg_batch_proof<P: KzgCurve>() {
     let mut rng = thread_rng();
     let degree = 100;
     let n_commits = 10;
     let mut points_u = Vec::with_capacity(n_commits);
 
-    let poly = Polynomial::<Fr>::from_random_coefficients(degree);
-    let global_params = GlobalKzgParams::<Bn254>::new(degree);
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients(degree);
+    let global_params = P::kzg_setup(degree);
 
     for _ in 0..n_commits {
-        points_u.push(Fr::rand(&mut rng));
+        points_u.push(P::ScalarField::rand(&mut rng));
     }
 
     let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
@@ -54,3 +58,141 @@ fn test_kzg_batch_proof() {
 
     assert!(result, "Polynomial commitment batch verification failed");
 }
+
+fn test_kzg_commit_degree_equal_to_number_of_powers<P: KzgCurve>() {
+    let degree = 10;
+    let global_params = P::kzg_setup(degree);
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients(global_params.len());
+
+    assert!(commit(&global_params, &poly).is_err());
+}
+
+fn test_kzg_degree_bound_proof<P: KzgCurve>() {
+    let mut rng = thread_rng();
+    let max_degree = 100;
+    let degree_bound = 40;
+
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients(degree_bound);
+    let global_params = P::kzg_setup(max_degree);
+
+    let point_u = P::ScalarField::rand(&mut rng);
+
+    let (poly_commitment, shifted_commitment) =
+        commit_with_degree_bound(&global_params, &poly, degree_bound)
+            .expect("Error commiting Polynomial");
+
+    let (proof, eval_u) =
+        evaluation_proof_with_degree_bound(&global_params, &poly, &point_u, degree_bound)
+            .expect("Error evaluatiing polynomial proof");
+
+    let result = verify_with_degree_bound(
+        &global_params,
+        &poly_commitment,
+        &shifted_commitment,
+        &proof,
+        &point_u,
+        &eval_u,
+        degree_bound,
+    );
+
+    assert!(
+        result,
+        "Polynomial commitment degree bound verification failed"
+    );
+}
+
+fn test_kzg_degree_bound_exceeded<P: KzgCurve>() {
+    let max_degree = 100;
+    let degree_bound = 40;
+
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients(degree_bound + 1);
+    let global_params = P::kzg_setup(max_degree);
+
+    assert!(commit_with_degree_bound(&global_params, &poly, degree_bound).is_err());
+    assert!(commit_with_degree_bound(&global_params, &poly, max_degree + 1).is_err());
+}
+
+fn test_kzg_degree_bound_rejects_tighter_bound<P: KzgCurve>() {
+    let max_degree = 100;
+    let degree_bound = 40;
+
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients(degree_bound);
+    let global_params = P::kzg_setup(max_degree);
+
+    let (poly_commitment, shifted_commitment) =
+        commit_with_degree_bound(&global_params, &poly, degree_bound)
+            .expect("Error commiting Polynomial");
+
+    assert!(verify_degree_bound(
+        &global_params,
+        &poly_commitment,
+        &shifted_commitment,
+        degree_bound
+    ));
+    assert!(!verify_degree_bound(
+        &global_params,
+        &poly_commitment,
+        &shifted_commitment,
+        degree_bound - 1
+    ));
+}
+
+fn test_kzg_proof_from_seed<P: Pairing>() {
+    let degree = 31;
+    let prove = |seed: u64| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let global_params = GlobalKzgParams::<P>::new_with_rng(degree, &mut rng);
+        let poly =
+            Polynomial::<P::ScalarField>::from_random_coefficients_with_rng(degree, &mut rng);
+        let point_u = P::ScalarField::rand(&mut rng);
+        let commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+        let (proof, value) = evaluation_proof(&global_params, &poly, &point_u)
+            .expect("Error evaluatiing polynomial proof");
+        assert!(verify(
+            &global_params,
+            &commitment,
+            &proof,
+            &point_u,
+            &value
+        ));
+        (global_params, commitment, proof)
+    };
+
+    assert_eq!(prove(7), prove(7));
+    assert_ne!(prove(7).1, prove(8).1);
+}
+
+fn test_kzg_zero_polynomial<P: KzgCurve>() {
+    let mut rng = thread_rng();
+    let global_params = P::kzg_setup(10);
+    let poly = Polynomial::<P::ScalarField>::from_vector_coefficients(vec![]);
+    let points_u: Vec<P::ScalarField> = (0..3).map(|_| P::ScalarField::rand(&mut rng)).collect();
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+    let (proof, eval_u) = evaluation_proof(&global_params, &poly, &points_u[0])
+        .expect("Error evaluatiing polynomial proof");
+    assert!(proof.is_zero() && eval_u.is_zero());
+    assert!(verify(
+        &global_params,
+        &poly_commitment,
+        &proof,
+        &points_u[0],
+        &eval_u
+    ));
+
+    let (proof, r_poly) = batch_evaluation_proof(&global_params, &poly, &points_u)
+        .expect("Error evaluatiing polynomial proof");
+    assert!(proof.is_zero() && r_poly.is_zero());
+    assert!(batch_verify(&global_params, &poly_commitment, &proof, &points_u, &r_poly).unwrap());
+}
+
+curve_tests!(bn254: Bn254, bls12_381: Bls12_381; [
+    test_kzg_proof,
+    test_kzg_batch_proof,
+    test_kzg_commit_degree_equal_to_number_of_powers,
+    test_kzg_degree_bound_proof,
+    test_kzg_degree_bound_exceeded,
+    test_kzg_degree_bound_rejects_tighter_bound,
+    test_kzg_proof_from_seed,
+    test_kzg_zero_polynomial,
+]);
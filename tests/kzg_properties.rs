@@ -0,0 +1,122 @@
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_std::{One, UniformRand};
+use proptest::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+use pcs::common::polynomial::Polynomial;
+use pcs::kzg::{
+    commit::commit,
+    open::{batch_evaluation_proof, evaluation_proof},
+    setup::GlobalKzgParams,
+    verify::{batch_verify, verify},
+};
+
+/// Opens a random polynomial of the given degree at a random point, checks that the opening verifies and that
+/// changing the proof point, the evaluation, the point or the commitment makes `verify` reject
+fn check_kzg_proof<P: Pairing>(degree: usize, seed: u64) -> Result<(), TestCaseError> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let global_params = GlobalKzgParams::<P>::new_with_rng(degree, &mut rng);
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients_with_rng(degree, &mut rng);
+    let point_u = P::ScalarField::rand(&mut rng);
+
+    let commitment = commit(&global_params, &poly).unwrap();
+    let (proof, value) = evaluation_proof(&global_params, &poly, &point_u).unwrap();
+    let one = P::ScalarField::one();
+    let shift = |point: &P::G1Affine| (*point + P::G1Affine::generator()).into_affine();
+
+    prop_assert_eq!(value, poly.evaluate(&point_u));
+    prop_assert!(verify(
+        &global_params,
+        &commitment,
+        &proof,
+        &point_u,
+        &value
+    ));
+    prop_assert!(!verify(
+        &global_params,
+        &commitment,
+        &shift(&proof),
+        &point_u,
+        &value
+    ));
+    prop_assert!(!verify(
+        &global_params,
+        &commitment,
+        &proof,
+        &point_u,
+        &(value + one)
+    ));
+    prop_assert!(!verify(
+        &global_params,
+        &commitment,
+        &proof,
+        &(point_u + one),
+        &value
+    ));
+    prop_assert!(!verify(
+        &global_params,
+        &shift(&commitment),
+        &proof,
+        &point_u,
+        &value
+    ));
+    Ok(())
+}
+
+/// Opens a random polynomial at `n_points` random points, checks that the batch opening verifies and that changing the
+/// proof point or any coefficient of r(X) makes `batch_verify` reject
+fn check_kzg_batch_proof<P: Pairing>(
+    degree: usize,
+    n_points: usize,
+    seed: u64,
+) -> Result<(), TestCaseError> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let global_params = GlobalKzgParams::<P>::new_with_rng(degree, &mut rng);
+    let poly = Polynomial::<P::ScalarField>::from_random_coefficients_with_rng(degree, &mut rng);
+    let points_u: Vec<P::ScalarField> = (0..n_points)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+
+    let commitment = commit(&global_params, &poly).unwrap();
+    let (proof, r_poly) = batch_evaluation_proof(&global_params, &poly, &points_u).unwrap();
+    let accepts = |proof: &P::G1Affine, r_poly: &Polynomial<P::ScalarField>| {
+        matches!(
+            batch_verify(&global_params, &commitment, proof, &points_u, r_poly),
+            Ok(true)
+        )
+    };
+
+    for u in &points_u {
+        prop_assert_eq!(r_poly.evaluate(u), poly.evaluate(u));
+    }
+    prop_assert!(accepts(&proof, &r_poly));
+    prop_assert!(!accepts(
+        &(proof + P::G1Affine::generator()).into_affine(),
+        &r_poly
+    ));
+    for i in 0..r_poly.coeffs().len() {
+        let mut coeffs = r_poly.coeffs().to_vec();
+        coeffs[i] += P::ScalarField::one();
+        let tampered = Polynomial::from_vector_coefficients(coeffs);
+        prop_assert!(!accepts(&proof, &tampered), "Tampered coefficient {}", i);
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+
+    #[test]
+    fn test_kzg_proof_properties(degree in 1_usize..48, seed in any::<u64>()) {
+        check_kzg_proof::<Bn254>(degree, seed)?;
+        check_kzg_proof::<Bls12_381>(degree, seed)?;
+    }
+
+    #[test]
+    fn test_kzg_batch_proof_properties(degree in 8_usize..48, n_points in 1_usize..8, seed in any::<u64>()) {
+        check_kzg_batch_proof::<Bn254>(degree, n_points, seed)?;
+        check_kzg_batch_proof::<Bls12_381>(degree, n_points, seed)?;
+    }
+}
@@ -0,0 +1,132 @@
+use ark_ff::PrimeField;
+
+use pcs::common::polynomial::Polynomial;
+use pcs::curves::pasta::{Fp, Fq};
+use pcs::ligero::{
+    commit::commit, open::evaluation_proof, setup::GlobalLigeroParams, verify::verify,
+};
+use rand::thread_rng;
+
+#[macro_use]
+mod helpers;
+
+fn test_ligero_proof<F: PrimeField>() {
+    let mut rng = thread_rng();
+    let degree = 1000;
+
+    let poly = Polynomial::<F>::from_random_coefficients(degree);
+    let global_params = GlobalLigeroParams::<F>::new(degree, 4, 30).unwrap();
+
+    let point_z = F::rand(&mut rng);
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+
+    let (proof, eval_z) = evaluation_proof(&global_params, &poly, &point_z)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(&global_params, &poly_commitment, &proof, &point_z, &eval_z);
+
+    assert_eq!(eval_z, poly.evaluate(&point_z));
+    assert!(result, "Ligero commitment verification failed");
+}
+
+fn test_ligero_wrong_evaluation<F: PrimeField>() {
+    let mut rng = thread_rng();
+    let degree = 255;
+
+    let poly = Polynomial::<F>::from_random_coefficients(degree);
+    let global_params = GlobalLigeroParams::<F>::new(degree, 4, 20).unwrap();
+
+    let point_z = F::rand(&mut rng);
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+
+    let (proof, eval_z) = evaluation_proof(&global_params, &poly, &point_z)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(
+        &global_params,
+        &poly_commitment,
+        &proof,
+        &point_z,
+        &(eval_z + F::one()),
+    );
+
+    assert!(!result, "Ligero commitment verification should fail");
+}
+
+fn test_ligero_tampered_eval_row<F: PrimeField>() {
+    let mut rng = thread_rng();
+    let degree = 255;
+
+    let poly = Polynomial::<F>::from_random_coefficients(degree);
+    let global_params = GlobalLigeroParams::<F>::new(degree, 4, 20).unwrap();
+
+    let point_z = F::rand(&mut rng);
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+
+    let (mut proof, _) = evaluation_proof(&global_params, &poly, &point_z)
+        .expect("Error evaluatiing polynomial proof");
+    proof.eval_row[0] += F::one();
+    let forged_eval = proof
+        .eval_row
+        .iter()
+        .enumerate()
+        .map(|(j, u)| *u * point_z.pow([j as u64]))
+        .sum::<F>();
+
+    let result = verify(
+        &global_params,
+        &poly_commitment,
+        &proof,
+        &point_z,
+        &forged_eval,
+    );
+
+    assert!(!result, "Ligero commitment verification should fail");
+}
+
+fn test_ligero_tampered_columns<F: PrimeField>() {
+    let mut rng = thread_rng();
+    let degree = 255;
+
+    let poly = Polynomial::<F>::from_random_coefficients(degree);
+    let global_params = GlobalLigeroParams::<F>::new(degree, 4, 20).unwrap();
+
+    let point_z = F::rand(&mut rng);
+
+    let poly_commitment = commit(&global_params, &poly).expect("Error commiting Polynomial");
+
+    let (proof, eval_z) = evaluation_proof(&global_params, &poly, &point_z)
+        .expect("Error evaluatiing polynomial proof");
+
+    // gamma and the column positions are bound to u', so changing it changes which columns must be opened
+    let mut wrong_proof = proof.clone();
+    wrong_proof.combined_row[0] += F::one();
+    assert!(!verify(
+        &global_params,
+        &poly_commitment,
+        &wrong_proof,
+        &point_z,
+        &eval_z
+    ));
+
+    // One column opened num_queries times doesnt answer the distinct positions the verifier asks for
+    let mut wrong_proof = proof;
+    wrong_proof.columns = vec![wrong_proof.columns[0].clone(); global_params.num_queries];
+    assert!(!verify(
+        &global_params,
+        &poly_commitment,
+        &wrong_proof,
+        &point_z,
+        &eval_z
+    ));
+}
+
+curve_tests!(bn254: ark_bn254::Fr, bls12_381: ark_bls12_381::Fr, pallas: Fp, vesta: Fq; [
+    test_ligero_proof,
+    test_ligero_wrong_evaluation,
+    test_ligero_tampered_eval_row,
+    test_ligero_tampered_columns,
+]);
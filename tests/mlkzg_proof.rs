@@ -0,0 +1,108 @@
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
+use ark_std::{One, UniformRand};
+
+use pcs::mlkzg::{
+    commit::commit, open::evaluation_proof, setup::GlobalMlKzgParams, verify::verify,
+};
+use rand::thread_rng;
+
+#[macro_use]
+mod helpers;
+use helpers::*;
+
+fn test_mlkzg_proof<P: Pairing>() {
+    let mut rng = thread_rng();
+    let num_vars = 8;
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let point: Vec<P::ScalarField> = (0..num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = GlobalMlKzgParams::<P>::new(num_vars);
+
+    let commitment = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proofs, eval_z) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(&global_params, &commitment, &proofs, &point, &eval_z);
+
+    assert_eq!(eval_z, evaluate_multilinear(&evaluations, &point));
+    assert_eq!(proofs.len(), num_vars);
+    assert!(result, "Multilinear commitment verification failed");
+}
+
+fn test_mlkzg_proof_fewer_variables<P: Pairing>() {
+    let mut rng = thread_rng();
+    let num_vars = 8;
+    let poly_vars = 5;
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << poly_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let point: Vec<P::ScalarField> = (0..poly_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = GlobalMlKzgParams::<P>::new(num_vars);
+
+    let commitment = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proofs, eval_z) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(&global_params, &commitment, &proofs, &point, &eval_z);
+
+    assert!(result, "Multilinear commitment verification failed");
+}
+
+fn test_mlkzg_wrong_evaluation<P: Pairing>() {
+    let mut rng = thread_rng();
+    let num_vars = 6;
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let point: Vec<P::ScalarField> = (0..num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = GlobalMlKzgParams::<P>::new(num_vars);
+
+    let commitment = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proofs, eval_z) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(
+        &global_params,
+        &commitment,
+        &proofs,
+        &point,
+        &(eval_z + P::ScalarField::one()),
+    );
+
+    assert!(!result, "Multilinear commitment verification should fail");
+}
+
+fn test_mlkzg_commit_too_many_variables<P: Pairing>() {
+    let mut rng = thread_rng();
+    let num_vars = 4;
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << (num_vars + 1))
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = GlobalMlKzgParams::<P>::new(num_vars);
+
+    assert!(commit(&global_params, &evaluations).is_err());
+    assert!(commit(&global_params, &evaluations[..3]).is_err());
+}
+
+curve_tests!(bn254: Bn254, bls12_381: Bls12_381; [
+    test_mlkzg_proof,
+    test_mlkzg_proof_fewer_variables,
+    test_mlkzg_wrong_evaluation,
+    test_mlkzg_commit_too_many_variables,
+]);
@@ -0,0 +1,109 @@
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ff::PrimeField;
+
+use pcs::common::pcs::PolynomialCommitment;
+use pcs::common::transcript::Sha256Transcript;
+use pcs::curves::pasta::{PallasProjective, VestaProjective};
+use pcs::curves::{IpaCurve, KzgCurve};
+use pcs::ipa::Ipa;
+use pcs::kzg::Kzg;
+use pcs::plonk::{open::prove, setup::GlobalPlonkParams, verify::verify, Circuit, Gate};
+
+#[macro_use]
+mod helpers;
+
+const LABEL: &[u8] = b"plonk";
+
+/// x^3 + x + 5 = out, with variables x, x^2, x^3, x^3 + x and out
+fn cubic_circuit<F: PrimeField>(out: u64) -> Circuit<F> {
+    let mut circuit = Circuit::new(5);
+    circuit.add_gate(Gate::multiplication(), [0, 0, 1]).unwrap();
+    circuit.add_gate(Gate::multiplication(), [1, 0, 2]).unwrap();
+    circuit.add_gate(Gate::addition(), [2, 0, 3]).unwrap();
+    let add_five = Gate::new(F::one(), F::zero(), -F::one(), F::zero(), F::from(5u64));
+    circuit.add_gate(add_five, [3, 0, 4]).unwrap();
+    circuit
+        .add_gate(Gate::constant(F::from(out)), [4, 0, 0])
+        .unwrap();
+    circuit
+}
+
+fn cubic_witness<F: PrimeField>(x: u64) -> Vec<F> {
+    let x = F::from(x);
+    vec![
+        x,
+        x * x,
+        x * x * x,
+        x * x * x + x,
+        x * x * x + x + F::from(5u64),
+    ]
+}
+
+fn check_cubic_circuit<F: PrimeField, S: PolynomialCommitment<F>>(setup: impl Fn() -> S::Params) {
+    let global_params = GlobalPlonkParams::<F, S>::new(setup(), &cubic_circuit(35)).unwrap();
+    assert_eq!(global_params.len(), 8);
+
+    let proof = prove(
+        &global_params,
+        &cubic_witness(3),
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error proving circuit");
+    assert!(
+        verify(&global_params, &proof, &mut Sha256Transcript::new(LABEL)),
+        "PLONK verification failed"
+    );
+
+    assert!(prove(
+        &global_params,
+        &cubic_witness(2),
+        &mut Sha256Transcript::new(LABEL)
+    )
+    .is_err());
+
+    let other_params = GlobalPlonkParams::<F, S>::new(setup(), &cubic_circuit(36)).unwrap();
+    assert!(!verify(
+        &other_params,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.wires_zeta[0].value += F::one();
+    assert!(!verify(
+        &global_params,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof;
+    wrong_proof.commitments_wires.swap(0, 1);
+    assert!(!verify(
+        &global_params,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+}
+
+fn test_plonk_kzg<P: KzgCurve>() {
+    check_cubic_circuit::<_, Kzg<P>>(|| P::kzg_setup(32));
+}
+
+fn test_plonk_ipa<P: IpaCurve>() {
+    check_cubic_circuit::<_, Ipa<P>>(|| P::ipa_setup(31));
+}
+
+fn test_plonk_invalid_setup<P: KzgCurve>() {
+    // The quotient of a circuit of 8 gates needs degree 24
+    assert!(GlobalPlonkParams::<_, Kzg<P>>::new(P::kzg_setup(16), &cubic_circuit(35)).is_err());
+    assert!(GlobalPlonkParams::<_, Kzg<P>>::new(P::kzg_setup(32), &Circuit::new(1)).is_err());
+    assert!(Circuit::<P::ScalarField>::new(2)
+        .add_gate(Gate::addition(), [0, 1, 2])
+        .is_err());
+}
+
+curve_tests!(bn254: Bn254, bls12_381: Bls12_381; [test_plonk_kzg, test_plonk_invalid_setup]);
+curve_tests!(ipa_bn254: ark_bn254::G1Projective, pallas: PallasProjective, vesta: VestaProjective; [
+    test_plonk_ipa,
+]);
@@ -0,0 +1,147 @@
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
+use ark_std::One;
+use rand::{rngs::StdRng, thread_rng, Rng, SeedableRng};
+
+use pcs::common::transcript::Sha256Transcript;
+use pcs::plookup::{
+    commit::commit_witness, open::prove, setup::GlobalPlookupParams, verify::verify,
+};
+
+#[macro_use]
+mod helpers;
+
+const LABEL: &[u8] = b"plookup";
+
+fn range_table<P: Pairing>(size: u64) -> Vec<P::ScalarField> {
+    (0..size).map(P::ScalarField::from).collect()
+}
+
+fn random_witness<P: Pairing>(table: &[P::ScalarField], len: usize) -> Vec<P::ScalarField> {
+    let mut rng = thread_rng();
+    (0..len)
+        .map(|_| table[rng.gen_range(0..table.len())])
+        .collect()
+}
+
+fn test_plookup_witness_in_table<P: Pairing>() {
+    let table = range_table::<P>(16);
+    let global_params = GlobalPlookupParams::<P>::new(&table).unwrap();
+    let witness = random_witness::<P>(&table, global_params.max_witness_len());
+
+    let (witness_poly, commitment_f) = commit_witness(&global_params, &witness).unwrap();
+    let proof = prove(
+        &global_params,
+        &witness_poly,
+        &commitment_f,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error proving lookup");
+
+    assert!(
+        verify(
+            &global_params,
+            &commitment_f,
+            &proof,
+            &mut Sha256Transcript::new(LABEL)
+        ),
+        "Plookup verification failed"
+    );
+}
+
+fn test_plookup_short_witness_and_padded_table<P: Pairing>() {
+    let table: Vec<P::ScalarField> = [7u64, 3, 3, 100, 42, 9, 11, 0, 5, 1000]
+        .into_iter()
+        .map(P::ScalarField::from)
+        .collect();
+    let global_params = GlobalPlookupParams::<P>::new(&table).unwrap();
+    assert_eq!(global_params.len(), 16);
+    let witness = [table[3], table[3], table[9], table[1], table[0], table[3]];
+
+    let (witness_poly, commitment_f) = commit_witness(&global_params, &witness).unwrap();
+    let proof = prove(
+        &global_params,
+        &witness_poly,
+        &commitment_f,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .unwrap();
+
+    assert!(verify(
+        &global_params,
+        &commitment_f,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+}
+
+fn test_plookup_rejects_value_outside_table<P: Pairing>() {
+    let table = range_table::<P>(8);
+    let global_params = GlobalPlookupParams::<P>::new(&table).unwrap();
+    let mut witness = random_witness::<P>(&table, 7);
+    let (witness_poly, commitment_f) = commit_witness(&global_params, &witness).unwrap();
+    let proof = prove(
+        &global_params,
+        &witness_poly,
+        &commitment_f,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .unwrap();
+
+    witness[2] = P::ScalarField::from(8u64);
+    let (wrong_poly, wrong_commitment) = commit_witness(&global_params, &witness).unwrap();
+    assert!(prove(
+        &global_params,
+        &wrong_poly,
+        &wrong_commitment,
+        &mut Sha256Transcript::new(LABEL)
+    )
+    .is_err());
+    assert!(!verify(
+        &global_params,
+        &wrong_commitment,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.opening_q.r_poly = wrong_proof.opening_q.r_poly
+        + pcs::common::polynomial::Polynomial::from_vector_coefficients(
+            vec![P::ScalarField::one()],
+        );
+    assert!(!verify(
+        &global_params,
+        &commitment_f,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof;
+    wrong_proof.commitment_h1 = wrong_proof.commitment_h2;
+    assert!(!verify(
+        &global_params,
+        &commitment_f,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    assert!(commit_witness(&global_params, &random_witness::<P>(&table, 8)).is_err());
+}
+
+fn test_plookup_setup_from_seed<P: Pairing>() {
+    let table = range_table::<P>(16);
+    let setup = |seed: u64| {
+        GlobalPlookupParams::<P>::new_with_rng(&table, &mut StdRng::seed_from_u64(seed)).unwrap()
+    };
+
+    assert_eq!(setup(7), setup(7));
+    assert_ne!(setup(7).commitment_t, setup(8).commitment_t);
+}
+
+curve_tests!(bn254: Bn254, bls12_381: Bls12_381; [
+    test_plookup_witness_in_table,
+    test_plookup_short_witness_and_padded_table,
+    test_plookup_rejects_value_outside_table,
+    test_plookup_setup_from_seed,
+]);
@@ -0,0 +1,200 @@
+use ark_std::{One, UniformRand};
+
+use pcs::common::transcript::Sha256Transcript;
+use pcs::curves::pasta::{PallasProjective, VestaProjective};
+use pcs::curves::IpaCurve;
+use pcs::ipa::range_proof::{commit_value, prove, verify};
+use rand::thread_rng;
+
+#[macro_use]
+mod helpers;
+
+const LABEL: &[u8] = b"range_proof";
+
+fn test_range_proof<P: IpaCurve>() {
+    let mut rng = thread_rng();
+    let n_bits = 32;
+    let global_params = P::ipa_setup(2 * n_bits + 1);
+    let value = 3_000_000_000u64;
+    let blinding = P::ScalarField::rand(&mut rng);
+
+    let (proof, commitments) = prove(
+        &global_params,
+        &[value],
+        &[blinding],
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error computing range proof");
+
+    let result = verify(
+        &global_params,
+        &commitments,
+        &proof,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    );
+
+    assert_eq!(
+        commitments[0],
+        commit_value(&global_params, value, &blinding, n_bits, 1).unwrap()
+    );
+    assert_eq!(proof.inner_product_proof.l_r_group.len(), 5);
+    assert!(result, "Range proof verification failed");
+}
+
+fn test_range_proof_boundaries<P: IpaCurve>() {
+    let mut rng = thread_rng();
+    let n_bits = 8;
+    let global_params = P::ipa_setup(2 * n_bits + 1);
+
+    for value in [0u64, 255] {
+        let (proof, commitments) = prove(
+            &global_params,
+            &[value],
+            &[P::ScalarField::rand(&mut rng)],
+            n_bits,
+            &mut Sha256Transcript::new(LABEL),
+        )
+        .expect("Error computing range proof");
+
+        assert!(verify(
+            &global_params,
+            &commitments,
+            &proof,
+            n_bits,
+            &mut Sha256Transcript::new(LABEL),
+        ));
+    }
+
+    let result = prove(
+        &global_params,
+        &[256],
+        &[P::ScalarField::rand(&mut rng)],
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    );
+    assert!(result.is_err(), "Out of range value should not be proven");
+}
+
+fn test_aggregated_range_proof<P: IpaCurve>() {
+    let mut rng = thread_rng();
+    let n_bits = 16;
+    let values = [0u64, 1, 40_000, 65_535];
+    let n_values = values.len();
+    let global_params = P::ipa_setup(2 * n_bits * n_values + 1);
+    let blindings: Vec<P::ScalarField> = (0..n_values)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+
+    let (proof, commitments) = prove(
+        &global_params,
+        &values,
+        &blindings,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error computing range proof");
+
+    let result = verify(
+        &global_params,
+        &commitments,
+        &proof,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    );
+
+    // log2(n * m) rounds of the inner product argument
+    assert_eq!(proof.inner_product_proof.l_r_group.len(), 6);
+    assert!(result, "Aggregated range proof verification failed");
+}
+
+fn test_range_proof_rejects_tampering<P: IpaCurve>() {
+    let mut rng = thread_rng();
+    let n_bits = 16;
+    let values = [7u64, 12_345];
+    let global_params = P::ipa_setup(2 * n_bits * values.len() + 1);
+    let blindings: Vec<P::ScalarField> = (0..values.len())
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+
+    let (proof, commitments) = prove(
+        &global_params,
+        &values,
+        &blindings,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    )
+    .expect("Error computing range proof");
+
+    // Commitment to a different value
+    let mut wrong_commitments = commitments.clone();
+    wrong_commitments[1] += global_params.h_get();
+    assert!(!verify(
+        &global_params,
+        &wrong_commitments,
+        &proof,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    ));
+
+    // Commitment to a value out of range, blinded as the original one
+    let out_of_range = commit_value(&global_params, 1 << 16, &blindings[1], n_bits, 2).unwrap();
+    assert!(!verify(
+        &global_params,
+        &[commitments[0], out_of_range],
+        &proof,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    ));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.t_hat += P::ScalarField::one();
+    assert!(!verify(
+        &global_params,
+        &commitments,
+        &wrong_proof,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    ));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.inner_product_proof.a_0 += P::ScalarField::one();
+    assert!(!verify(
+        &global_params,
+        &commitments,
+        &wrong_proof,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    ));
+
+    // Different transcript domain separator
+    assert!(!verify(
+        &global_params,
+        &commitments,
+        &proof,
+        n_bits,
+        &mut Sha256Transcript::new(b"other"),
+    ));
+
+    // Not enough generators for 4 values
+    assert!(!verify(
+        &global_params,
+        &[
+            commitments[0],
+            commitments[1],
+            commitments[0],
+            commitments[1]
+        ],
+        &proof,
+        n_bits,
+        &mut Sha256Transcript::new(LABEL),
+    ));
+}
+
+curve_tests!(bn254: ark_bn254::G1Projective, pallas: PallasProjective, vesta: VestaProjective; [
+    test_range_proof,
+    test_range_proof_boundaries,
+    test_aggregated_range_proof,
+    test_range_proof_rejects_tampering,
+]);
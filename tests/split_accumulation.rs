@@ -0,0 +1,133 @@
+use ark_ec::CurveGroup;
+use ark_std::{One, UniformRand};
+
+use pcs::common::polynomial::Polynomial;
+use pcs::common::transcript::Sha256Transcript;
+use pcs::curves::pasta::{PallasProjective, VestaProjective};
+use pcs::curves::IpaCurve;
+use pcs::ipa::{
+    setup::GlobalIpaParams,
+    split_accumulation::{accumulate, claim, decide, verify_accumulation, SplitAccumulator},
+};
+use rand::thread_rng;
+
+#[macro_use]
+mod helpers;
+
+const LABEL: &[u8] = b"split_accumulation";
+
+fn random_claim<P: CurveGroup>(
+    global_params: &GlobalIpaParams<P>,
+    degree: usize,
+) -> SplitAccumulator<P> {
+    let mut rng = thread_rng();
+    let poly = Polynomial::from_random_coefficients(degree);
+    claim(global_params, &poly, &P::ScalarField::rand(&mut rng)).unwrap()
+}
+
+fn test_split_accumulation_100_claims<P: IpaCurve>() {
+    let degree = 63;
+    let n_claims = 100;
+    let global_params = P::ipa_setup(degree);
+
+    let mut accumulator = random_claim(&global_params, degree);
+    for _ in 1..n_claims {
+        let new_claim = random_claim(&global_params, degree);
+        let inputs = [accumulator, new_claim];
+        let (new_accumulator, proof) =
+            accumulate(&global_params, &inputs, &mut Sha256Transcript::new(LABEL))
+                .expect("Error accumulating claims");
+
+        let result = verify_accumulation(
+            &[inputs[0].instance.clone(), inputs[1].instance.clone()],
+            &new_accumulator.instance,
+            &proof,
+            &mut Sha256Transcript::new(LABEL),
+        );
+        assert!(result, "Split accumulation verification failed");
+        accumulator = new_accumulator;
+    }
+
+    assert_eq!(accumulator.witness.degree(), degree);
+    assert!(decide(&global_params, &accumulator), "Decider failed");
+}
+
+fn test_split_accumulation_batch<P: IpaCurve>() {
+    let global_params = P::ipa_setup(31);
+    let inputs: Vec<SplitAccumulator<P>> = [31, 7, 0, 20, 31]
+        .iter()
+        .map(|degree| random_claim(&global_params, *degree))
+        .collect();
+    let instances: Vec<_> = inputs.iter().map(|input| input.instance.clone()).collect();
+
+    let (accumulator, proof) =
+        accumulate(&global_params, &inputs, &mut Sha256Transcript::new(LABEL)).unwrap();
+
+    assert!(verify_accumulation(
+        &instances,
+        &accumulator.instance,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+    assert!(decide(&global_params, &accumulator));
+}
+
+fn test_split_accumulation_rejects_false_claims<P: IpaCurve>() {
+    let degree = 15;
+    let global_params = P::ipa_setup(degree);
+    let inputs = [
+        random_claim(&global_params, degree),
+        random_claim(&global_params, degree),
+    ];
+    let instances = [inputs[0].instance.clone(), inputs[1].instance.clone()];
+    let (accumulator, proof) =
+        accumulate(&global_params, &inputs, &mut Sha256Transcript::new(LABEL)).unwrap();
+
+    let mut false_inputs = inputs.clone();
+    false_inputs[1].instance.v_value += P::ScalarField::one();
+    assert!(accumulate(
+        &global_params,
+        &false_inputs,
+        &mut Sha256Transcript::new(LABEL)
+    )
+    .is_err());
+
+    let mut false_instances = instances.clone();
+    false_instances[1].v_value += P::ScalarField::one();
+    assert!(!verify_accumulation(
+        &false_instances,
+        &accumulator.instance,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.evaluations[0] += P::ScalarField::one();
+    assert!(!verify_accumulation(
+        &instances,
+        &accumulator.instance,
+        &wrong_proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    assert!(!verify_accumulation(
+        &instances[..1],
+        &accumulator.instance,
+        &proof,
+        &mut Sha256Transcript::new(LABEL)
+    ));
+
+    // The accumulation verifier doesnt see the witness, a wrong one is only caught by the decider
+    let mut wrong_accumulator = accumulator.clone();
+    wrong_accumulator.witness = Polynomial::from_random_coefficients(degree);
+    assert!(!decide(&global_params, &wrong_accumulator));
+    let mut wrong_accumulator = accumulator;
+    wrong_accumulator.instance.v_value += P::ScalarField::one();
+    assert!(!decide(&global_params, &wrong_accumulator));
+}
+
+curve_tests!(bn254: ark_bn254::G1Projective, pallas: PallasProjective, vesta: VestaProjective; [
+    test_split_accumulation_100_claims,
+    test_split_accumulation_batch,
+    test_split_accumulation_rejects_false_claims,
+]);
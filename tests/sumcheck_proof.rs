@@ -0,0 +1,156 @@
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::PrimeField;
+use ark_std::One;
+
+use pcs::common::multilinear::MultilinearPolynomial;
+use pcs::common::transcript::Sha256Transcript;
+use pcs::curves::pasta::{PallasProjective, VestaProjective};
+use pcs::curves::IpaCurve;
+use pcs::ipa::hyrax;
+use pcs::mlkzg::{self, setup::GlobalMlKzgParams};
+use pcs::sumcheck::{open::prove, verify::verify, SumCheckProof};
+
+#[macro_use]
+mod helpers;
+
+fn random_polynomials<F: PrimeField>(
+    num_vars: usize,
+    degree: usize,
+) -> (Vec<MultilinearPolynomial<F>>, F) {
+    let polynomials: Vec<MultilinearPolynomial<F>> = (0..degree)
+        .map(|_| MultilinearPolynomial::from_random_evaluations(num_vars))
+        .collect();
+    let sum = (0..1 << num_vars)
+        .map(|b| {
+            polynomials
+                .iter()
+                .map(|p| p.evaluations()[b])
+                .product::<F>()
+        })
+        .sum();
+    (polynomials, sum)
+}
+
+fn test_sumcheck_mlkzg<P: Pairing>() {
+    let (num_vars, degree) = (6, 3);
+    let (polynomials, sum) = random_polynomials::<P::ScalarField>(num_vars, degree);
+    let global_params = GlobalMlKzgParams::<P>::new(num_vars);
+    let commitments: Vec<P::G1Affine> = polynomials
+        .iter()
+        .map(|p| mlkzg::commit::commit(&global_params, p.evaluations()))
+        .collect::<Result<_, String>>()
+        .expect("Error commiting Polynomial");
+
+    let (proof, point) = prove(&polynomials, &sum, &mut Sha256Transcript::new(b"sumcheck"))
+        .expect("Error proving sum");
+
+    let verifier_point = verify(
+        num_vars,
+        degree,
+        &sum,
+        &proof,
+        &mut Sha256Transcript::new(b"sumcheck"),
+    )
+    .expect("Sum-check verification failed");
+    assert_eq!(verifier_point, point);
+
+    for ((polynomial, commitment), value) in polynomials
+        .iter()
+        .zip(&commitments)
+        .zip(&proof.final_evaluations)
+    {
+        let (proofs, eval_r) =
+            mlkzg::open::evaluation_proof(&global_params, polynomial.evaluations(), &point)
+                .expect("Error evaluatiing polynomial proof");
+        assert_eq!(eval_r, *value);
+        assert!(mlkzg::verify::verify(
+            &global_params,
+            commitment,
+            &proofs,
+            &verifier_point,
+            value
+        ));
+    }
+}
+
+fn test_sumcheck_hyrax<P: IpaCurve>() {
+    let (num_vars, degree) = (7, 2);
+    let (polynomials, sum) = random_polynomials::<P::ScalarField>(num_vars, degree);
+    let (_, n_columns) = hyrax::matrix_dimensions(num_vars);
+    let global_params = P::ipa_setup(n_columns - 1);
+
+    let (proof, point) = prove(&polynomials, &sum, &mut Sha256Transcript::new(b"sumcheck"))
+        .expect("Error proving sum");
+
+    let verifier_point = verify(
+        num_vars,
+        degree,
+        &sum,
+        &proof,
+        &mut Sha256Transcript::new(b"sumcheck"),
+    )
+    .expect("Sum-check verification failed");
+
+    for (polynomial, value) in polynomials.iter().zip(&proof.final_evaluations) {
+        let row_commitments = hyrax::commit(&global_params, polynomial.evaluations())
+            .expect("Error commiting Polynomial");
+        let (hyrax_proof, eval_r) =
+            hyrax::evaluation_proof(&global_params, polynomial.evaluations(), &point)
+                .expect("Error evaluatiing polynomial proof");
+        assert_eq!(eval_r, *value);
+        assert!(hyrax::verify(
+            &global_params,
+            &row_commitments,
+            &hyrax_proof,
+            &verifier_point,
+            value
+        ));
+    }
+}
+
+fn test_sumcheck_invalid<P: CurveGroup>() {
+    let (num_vars, degree) = (5, 3);
+    let (polynomials, sum) = random_polynomials::<P::ScalarField>(num_vars, degree);
+    let wrong_sum = sum + P::ScalarField::one();
+
+    assert!(prove(
+        &polynomials,
+        &wrong_sum,
+        &mut Sha256Transcript::new(b"sumcheck")
+    )
+    .is_err());
+
+    let (proof, _) = prove(&polynomials, &sum, &mut Sha256Transcript::new(b"sumcheck"))
+        .expect("Error proving sum");
+    let verify_proof = |sum: &P::ScalarField, proof: &SumCheckProof<P::ScalarField>| {
+        verify(
+            num_vars,
+            degree,
+            sum,
+            proof,
+            &mut Sha256Transcript::new(b"sumcheck"),
+        )
+    };
+    assert!(verify_proof(&sum, &proof).is_some());
+    assert!(verify_proof(&wrong_sum, &proof).is_none());
+
+    let mut wrong_round = proof.clone();
+    wrong_round.round_evaluations[2][0] += P::ScalarField::one();
+    wrong_round.round_evaluations[2][1] -= P::ScalarField::one();
+    assert!(verify_proof(&sum, &wrong_round).is_none());
+
+    let mut wrong_final = proof.clone();
+    wrong_final.final_evaluations[1] += P::ScalarField::one();
+    assert!(verify_proof(&sum, &wrong_final).is_none());
+
+    let mut missing_round = proof;
+    missing_round.round_evaluations.pop();
+    assert!(verify_proof(&sum, &missing_round).is_none());
+}
+
+curve_tests!(bn254: ark_bn254::Bn254, bls12_381: ark_bls12_381::Bls12_381; [test_sumcheck_mlkzg]);
+
+curve_tests!(ipa_bn254: ark_bn254::G1Projective, pallas: PallasProjective, vesta: VestaProjective; [
+    test_sumcheck_hyrax,
+    test_sumcheck_invalid,
+]);
@@ -0,0 +1,226 @@
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use ark_std::One;
+use rand::{rngs::StdRng, thread_rng, Rng, SeedableRng};
+
+use pcs::verkle::{
+    open::prove,
+    setup::GlobalVerkleParams,
+    tree::{VerkleKey, VerkleTree},
+    verify::verify,
+};
+
+#[macro_use]
+mod helpers;
+
+fn random_entries<F: PrimeField>(n: usize) -> Vec<(VerkleKey, F)> {
+    let mut rng = thread_rng();
+    (0..n)
+        .map(|_| (rng.gen::<VerkleKey>(), F::rand(&mut rng)))
+        .collect()
+}
+
+fn test_verkle_membership_proof<P: Pairing>() {
+    let global_params = GlobalVerkleParams::<P>::new();
+    let mut tree = VerkleTree::new();
+    let mut entries = random_entries(40);
+    // Keys sharing a two byte prefix are pushed down to depth 3
+    let mut key = entries[0].0;
+    key[2] ^= 1;
+    entries.push((key, P::ScalarField::from(7u64)));
+
+    for (key, value) in &entries {
+        tree.insert(&global_params, *key, *value).unwrap();
+    }
+
+    let keys: Vec<VerkleKey> = entries.iter().map(|(key, _)| *key).collect();
+    let values: Vec<Option<P::ScalarField>> =
+        entries.iter().map(|(_, value)| Some(*value)).collect();
+    let proof = prove(&global_params, &tree, &keys).expect("Error computing verkle proof");
+
+    assert_eq!(tree.get(&key), Some(P::ScalarField::from(7u64)));
+    assert_eq!(proof.paths[0].commitments.len(), 2);
+    assert!(verify(&global_params, &tree.root(), &keys, &values, &proof));
+
+    let mut wrong_values = values.clone();
+    wrong_values[3] = Some(values[3].unwrap() + P::ScalarField::one());
+    assert!(!verify(
+        &global_params,
+        &tree.root(),
+        &keys,
+        &wrong_values,
+        &proof
+    ));
+
+    let mut wrong_values = values;
+    wrong_values[3] = None;
+    assert!(!verify(
+        &global_params,
+        &tree.root(),
+        &keys,
+        &wrong_values,
+        &proof
+    ));
+}
+
+fn test_verkle_non_membership_proof<P: Pairing>() {
+    let global_params = GlobalVerkleParams::<P>::new();
+    let mut tree = VerkleTree::new();
+
+    // Non membership in the empty tree
+    let missing = [3u8; 32];
+    let proof = prove(&global_params, &tree, &[missing]).unwrap();
+    assert!(verify(
+        &global_params,
+        &tree.root(),
+        &[missing],
+        &[None],
+        &proof
+    ));
+
+    let entries = random_entries(20);
+    for (key, value) in &entries {
+        tree.insert(&global_params, *key, *value).unwrap();
+    }
+
+    // Ends in a leaf with another key sharing the first byte, and in an empty slot
+    let mut sharing_prefix = entries[0].0;
+    sharing_prefix[31] ^= 1;
+    let mut empty_slot = [0u8; 32];
+    empty_slot[0] = (0..=255u8)
+        .find(|byte| entries.iter().all(|(key, _)| key[0] != *byte))
+        .unwrap();
+    let keys = [sharing_prefix, empty_slot];
+
+    let proof = prove(&global_params, &tree, &keys).unwrap();
+    assert_eq!(proof.paths[0].leaf, Some(entries[0]));
+    assert_eq!(proof.paths[1].leaf, None);
+    assert!(verify(
+        &global_params,
+        &tree.root(),
+        &keys,
+        &[None, None],
+        &proof
+    ));
+    assert!(!verify(
+        &global_params,
+        &tree.root(),
+        &keys,
+        &[Some(entries[0].1), None],
+        &proof
+    ));
+
+    // An existing key cant be proven absent
+    let proof = prove(&global_params, &tree, &[entries[1].0]).unwrap();
+    assert!(!verify(
+        &global_params,
+        &tree.root(),
+        &[entries[1].0],
+        &[None],
+        &proof
+    ));
+}
+
+fn test_verkle_insert_update<P: Pairing>() {
+    let global_params = GlobalVerkleParams::<P>::new();
+    let entries = random_entries(30);
+
+    let mut tree = VerkleTree::new();
+    for (key, value) in &entries {
+        tree.insert(&global_params, *key, *value).unwrap();
+    }
+    let mut reversed_tree = VerkleTree::new();
+    for (key, value) in entries.iter().rev() {
+        reversed_tree.insert(&global_params, *key, *value).unwrap();
+    }
+    // The commitment only depends on the stored key-values
+    assert_eq!(tree.root(), reversed_tree.root());
+
+    let old_root = tree.root();
+    let (key, _) = entries[5];
+    let new_value = P::ScalarField::from(42u64);
+    tree.update(&global_params, key, new_value).unwrap();
+    assert_ne!(tree.root(), old_root);
+    assert_eq!(tree.get(&key), Some(new_value));
+
+    let proof = prove(&global_params, &tree, &[key]).unwrap();
+    assert!(verify(
+        &global_params,
+        &tree.root(),
+        &[key],
+        &[Some(new_value)],
+        &proof
+    ));
+    assert!(!verify(
+        &global_params,
+        &old_root,
+        &[key],
+        &[Some(new_value)],
+        &proof
+    ));
+
+    assert!(tree
+        .update(&global_params, [255u8; 32], P::ScalarField::one())
+        .is_err());
+}
+
+fn test_verkle_tampered_proof<P: Pairing>() {
+    let global_params = GlobalVerkleParams::<P>::new();
+    let mut tree = VerkleTree::new();
+    let mut entries = random_entries(10);
+    let mut key = entries[0].0;
+    key[1] ^= 1;
+    entries.push((key, P::ScalarField::one()));
+    for (key, value) in &entries {
+        tree.insert(&global_params, *key, *value).unwrap();
+    }
+    let keys = [entries[0].0, entries[4].0];
+    let values = [Some(entries[0].1), Some(entries[4].1)];
+    let proof = prove(&global_params, &tree, &keys).unwrap();
+    assert!(verify(&global_params, &tree.root(), &keys, &values, &proof));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.multiproof.proof = wrong_proof.multiproof.commitment_d;
+    assert!(!verify(
+        &global_params,
+        &tree.root(),
+        &keys,
+        &values,
+        &wrong_proof
+    ));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.paths[0].commitments[0] = tree.root();
+    assert!(!verify(
+        &global_params,
+        &tree.root(),
+        &keys,
+        &values,
+        &wrong_proof
+    ));
+
+    assert!(!verify(
+        &global_params,
+        &tree.root(),
+        &keys[..1],
+        &values[..1],
+        &proof
+    ));
+}
+
+fn test_verkle_setup_from_seed<P: Pairing>() {
+    let setup = |seed: u64| GlobalVerkleParams::<P>::new_with_rng(&mut StdRng::seed_from_u64(seed));
+
+    assert_eq!(setup(7), setup(7));
+    assert_ne!(setup(7).lagrange_g1, setup(8).lagrange_g1);
+}
+
+curve_tests!(bn254: Bn254, bls12_381: Bls12_381; [
+    test_verkle_membership_proof,
+    test_verkle_non_membership_proof,
+    test_verkle_insert_update,
+    test_verkle_tampered_proof,
+    test_verkle_setup_from_seed,
+]);
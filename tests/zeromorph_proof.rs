@@ -0,0 +1,151 @@
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::AffineRepr;
+use ark_std::{One, UniformRand, Zero};
+
+use pcs::curves::KzgCurve;
+use pcs::kzg::zeromorph::{commit, evaluation_proof, verify};
+use rand::thread_rng;
+
+#[macro_use]
+mod helpers;
+use helpers::*;
+
+fn test_zeromorph_proof<P: KzgCurve>() {
+    let mut rng = thread_rng();
+    let num_vars = 7;
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let point: Vec<P::ScalarField> = (0..num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = P::kzg_setup((1 << num_vars) - 1);
+
+    let commitment = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proof, eval_u) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(&global_params, &commitment, &proof, &point, &eval_u);
+
+    assert_eq!(eval_u, evaluate_multilinear(&evaluations, &point));
+    assert!(result, "Zeromorph commitment verification failed");
+}
+
+fn test_zeromorph_proof_larger_setup<P: KzgCurve>() {
+    let mut rng = thread_rng();
+    let num_vars = 5;
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let point: Vec<P::ScalarField> = (0..num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = P::kzg_setup(100);
+
+    let commitment = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proof, eval_u) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(&global_params, &commitment, &proof, &point, &eval_u);
+
+    assert!(result, "Zeromorph commitment verification failed");
+}
+
+fn test_zeromorph_zero_polynomial<P: KzgCurve>() {
+    let mut rng = thread_rng();
+    let num_vars = 4;
+
+    let evaluations = vec![P::ScalarField::zero(); 1 << num_vars];
+    let point: Vec<P::ScalarField> = (0..num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = P::kzg_setup((1 << num_vars) - 1);
+
+    let commitment = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proof, eval_u) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    assert!(eval_u.is_zero());
+    assert!(verify(&global_params, &commitment, &proof, &point, &eval_u));
+}
+
+fn test_zeromorph_wrong_evaluation<P: KzgCurve>() {
+    let mut rng = thread_rng();
+    let num_vars = 5;
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let point: Vec<P::ScalarField> = (0..num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = P::kzg_setup((1 << num_vars) - 1);
+
+    let commitment = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proof, eval_u) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    let result = verify(
+        &global_params,
+        &commitment,
+        &proof,
+        &point,
+        &(eval_u + P::ScalarField::one()),
+    );
+
+    assert!(!result, "Zeromorph commitment verification should fail");
+}
+
+fn test_zeromorph_tampered_proof<P: KzgCurve>() {
+    let mut rng = thread_rng();
+    let num_vars = 5;
+
+    let evaluations: Vec<P::ScalarField> = (0..1 << num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let point: Vec<P::ScalarField> = (0..num_vars)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let global_params = P::kzg_setup((1 << num_vars) - 1);
+
+    let commitment = commit(&global_params, &evaluations).expect("Error commiting Polynomial");
+
+    let (proof, eval_u) = evaluation_proof(&global_params, &evaluations, &point)
+        .expect("Error evaluatiing polynomial proof");
+
+    // The challenges are bound to every commitment, so changing any of them changes y, x and z
+    let mut wrong_proof = proof.clone();
+    wrong_proof.commitments_q[0] = (wrong_proof.commitments_q[0] + P::G1Affine::generator()).into();
+    assert!(!verify(
+        &global_params,
+        &commitment,
+        &wrong_proof,
+        &point,
+        &eval_u
+    ));
+
+    let mut wrong_proof = proof;
+    wrong_proof.commitment_q_hat = (wrong_proof.commitment_q_hat + P::G1Affine::generator()).into();
+    assert!(!verify(
+        &global_params,
+        &commitment,
+        &wrong_proof,
+        &point,
+        &eval_u
+    ));
+}
+
+curve_tests!(bn254: Bn254, bls12_381: Bls12_381; [
+    test_zeromorph_proof,
+    test_zeromorph_proof_larger_setup,
+    test_zeromorph_zero_polynomial,
+    test_zeromorph_wrong_evaluation,
+    test_zeromorph_tampered_proof,
+]);